@@ -0,0 +1,498 @@
+// Anthropic OAuth 로그인 (Claude Pro/Max 구독)
+// PKCE + 로컬 loopback 리다이렉트로 인증 코드를 받아 토큰으로 교환하고,
+// 결과를 oauth 인증 프로필로 저장합니다. 백그라운드에서 만료 전에 갱신합니다.
+// 엔드포인트는 상수로 고정합니다. 디버그 빌드에서만 환경변수로 mock 인증 서버를 지정할 수 있고,
+// 지정한 값은 저장하지 않습니다 (refresh token이 다른 주소로 전송되지 않도록).
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use once_cell::sync::Lazy;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::Emitter;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+use crate::auth_profiles;
+
+const DEFAULT_AUTHORIZE_URL: &str = "https://claude.ai/oauth/authorize";
+const DEFAULT_TOKEN_URL: &str = "https://console.anthropic.com/v1/oauth/token";
+const DEFAULT_CLIENT_ID: &str = "9d1c250a-e61b-44d9-88ed-5944d1962f5e";
+const SCOPES: &str = "org:create_api_key user:profile user:inference";
+
+/// 브라우저 로그인 대기 시간
+const LOGIN_TIMEOUT: Duration = Duration::from_secs(300);
+/// 만료 이만큼 전에 갱신
+const REFRESH_MARGIN_MS: i64 = 10 * 60 * 1000;
+const REFRESH_CHECK_INTERVAL: Duration = Duration::from_secs(5 * 60);
+/// 연속 갱신 실패 시 재시도 간격 상한 (5분부터 두 배씩)
+const MAX_REFRESH_BACKOFF_MS: i64 = 6 * 60 * 60 * 1000;
+/// 이 횟수만큼 연속 실패하면 자동 갱신 중단 (다시 로그인하거나 수동 갱신 필요)
+const MAX_REFRESH_FAILURES: u32 = 5;
+
+/// 프론트엔드로 보내는 이벤트 이름
+const EVENT_NAME: &str = "anthropic-oauth";
+
+/// 진행 중인 로그인 취소용
+static CANCEL_LOGIN: Lazy<Mutex<Option<tokio::sync::oneshot::Sender<()>>>> =
+    Lazy::new(|| Mutex::new(None));
+
+/// 프로필별 연속 갱신 실패 (실패 횟수, 다음 시도 시각 ms)
+static REFRESH_FAILURES: Lazy<Mutex<HashMap<String, (u32, i64)>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// OAuth 엔드포인트
+#[derive(Debug, Clone)]
+struct OAuthEndpoints {
+    authorize_url: String,
+    token_url: String,
+    client_id: String,
+}
+
+impl OAuthEndpoints {
+    /// 기본 엔드포인트. 디버그 빌드에서만 환경변수로 교체 가능 (mock 서버 테스트용, 저장하지 않음)
+    /// MOLDCLAW_ANTHROPIC_OAUTH_AUTHORIZE_URL / _TOKEN_URL / _CLIENT_ID
+    fn current() -> Self {
+        #[cfg(debug_assertions)]
+        let env = |name: &str, default: &str| std::env::var(name).unwrap_or_else(|_| default.to_string());
+        #[cfg(not(debug_assertions))]
+        let env = |_: &str, default: &str| default.to_string();
+        Self {
+            authorize_url: env("MOLDCLAW_ANTHROPIC_OAUTH_AUTHORIZE_URL", DEFAULT_AUTHORIZE_URL),
+            token_url: env("MOLDCLAW_ANTHROPIC_OAUTH_TOKEN_URL", DEFAULT_TOKEN_URL),
+            client_id: env("MOLDCLAW_ANTHROPIC_OAUTH_CLIENT_ID", DEFAULT_CLIENT_ID),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OAuthStart {
+    pub auth_url: String,
+    pub redirect_uri: String,
+    pub profile_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OAuthEvent {
+    /// success | error | cancelled | refreshed
+    status: String,
+    profile_id: String,
+    message: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    refresh_token: Option<String>,
+    expires_in: Option<i64>,
+}
+
+fn random_urlsafe(len: usize) -> String {
+    let mut bytes = vec![0u8; len];
+    rand::thread_rng().fill(&mut bytes[..]);
+    URL_SAFE_NO_PAD.encode(bytes)
+}
+
+/// PKCE code_verifier / code_challenge(S256)
+fn pkce_pair() -> (String, String) {
+    let verifier = random_urlsafe(32);
+    let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()));
+    (verifier, challenge)
+}
+
+fn now_ms() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+fn emit(app: &tauri::AppHandle, status: &str, profile_id: &str, message: Option<String>) {
+    let _ = app.emit(
+        EVENT_NAME,
+        OAuthEvent {
+            status: status.to_string(),
+            profile_id: profile_id.to_string(),
+            message,
+        },
+    );
+}
+
+/// 토큰 엔드포인트 호출
+async fn request_token(token_url: &str, body: Value) -> Result<TokenResponse, String> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()
+        .map_err(|e| format!("HTTP 클라이언트 생성 실패: {}", e))?;
+    let response = client
+        .post(token_url)
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("토큰 요청 실패: {}", e))?;
+
+    let status = response.status();
+    let text = response.text().await.unwrap_or_default();
+    if !status.is_success() {
+        return Err(format!("토큰 요청 실패 (HTTP {}): {}", status.as_u16(), crate::truncate_message(&text, 200)));
+    }
+    serde_json::from_str(&text).map_err(|e| format!("토큰 응답 파싱 실패: {}", e))
+}
+
+/// 토큰을 oauth 프로필 항목으로 변환
+fn token_entry(tokens: &TokenResponse, previous_refresh: Option<&str>) -> Result<Value, String> {
+    let refresh = tokens
+        .refresh_token
+        .as_deref()
+        .or(previous_refresh)
+        .ok_or("refresh token이 없습니다")?;
+    let expires = now_ms() + tokens.expires_in.unwrap_or(3600) * 1000;
+    Ok(json!({
+        "type": "oauth",
+        "provider": "anthropic",
+        "access": tokens.access_token,
+        "refresh": refresh,
+        "expires": expires,
+    }))
+}
+
+/// 리다이렉트 요청 1건 처리: (code, state) 반환
+async fn accept_callback(listener: &TcpListener) -> Result<(String, String), String> {
+    loop {
+        let (mut stream, _) = listener.accept().await.map_err(|e| format!("콜백 수신 실패: {}", e))?;
+        let mut buf = vec![0u8; 8192];
+        let n = stream.read(&mut buf).await.unwrap_or(0);
+        let request = String::from_utf8_lossy(&buf[..n]).to_string();
+
+        // "GET /callback?code=...&state=... HTTP/1.1"
+        let path = request.lines().next().and_then(|l| l.split_whitespace().nth(1)).unwrap_or("");
+        if !path.starts_with("/callback") {
+            // favicon 등 다른 요청은 무시
+            let _ = stream.write_all(b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\n\r\n").await;
+            continue;
+        }
+
+        let url = reqwest::Url::parse(&format!("http://localhost{}", path))
+            .map_err(|e| format!("콜백 URL 파싱 실패: {}", e))?;
+        let query: HashMap<String, String> = url.query_pairs().into_owned().collect();
+
+        let (title, ok) = match (query.get("code"), query.get("error")) {
+            (Some(_), _) => ("로그인 완료", true),
+            _ => ("로그인 실패", false),
+        };
+        let html = format!(
+            "<html><head><meta charset=\"utf-8\"></head><body style=\"font-family:sans-serif;text-align:center;padding-top:80px\"><h2>{}</h2><p>moldClaw로 돌아가세요. 이 창은 닫아도 됩니다.</p></body></html>",
+            title
+        );
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/html; charset=utf-8\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            html.len(),
+            html
+        );
+        let _ = stream.write_all(response.as_bytes()).await;
+
+        if !ok {
+            let reason = query
+                .get("error_description")
+                .or_else(|| query.get("error"))
+                .cloned()
+                .unwrap_or_else(|| "인증 코드가 없습니다".to_string());
+            return Err(format!("로그인 거부됨: {}", reason));
+        }
+        // 일부 응답은 "code#state" 형식
+        let raw_code = query.get("code").cloned().unwrap_or_default();
+        let (code, hash_state) = match raw_code.split_once('#') {
+            Some((c, s)) => (c.to_string(), Some(s.to_string())),
+            None => (raw_code, None),
+        };
+        let state = query.get("state").cloned().or(hash_state).unwrap_or_default();
+        return Ok((code, state));
+    }
+}
+
+/// 콜백 대기 → 토큰 교환 → 저장
+async fn complete_login(
+    listener: TcpListener,
+    endpoints: OAuthEndpoints,
+    redirect_uri: String,
+    verifier: String,
+    expected_state: String,
+    profile_id: String,
+) -> Result<(), String> {
+    let (code, state) = tokio::time::timeout(LOGIN_TIMEOUT, accept_callback(&listener))
+        .await
+        .map_err(|_| "로그인 시간이 초과되었습니다".to_string())??;
+    if state != expected_state {
+        return Err("state 값이 일치하지 않습니다 (요청 위조 가능성)".to_string());
+    }
+
+    let tokens = request_token(
+        &endpoints.token_url,
+        json!({
+            "grant_type": "authorization_code",
+            "code": code,
+            "state": state,
+            "client_id": endpoints.client_id,
+            "redirect_uri": redirect_uri,
+            "code_verifier": verifier,
+        }),
+    )
+    .await?;
+
+    // 처음 로그인할 때만 설정 파일(auth.profiles / auth.order)에 프로필 등록
+    auth_profiles::store_profile("anthropic", &profile_id, token_entry(&tokens, None)?, "oauth")?;
+    clear_refresh_failures(&profile_id);
+    Ok(())
+}
+
+/// oauth 프로필 토큰 갱신
+pub async fn refresh_profile(profile_id: &str) -> Result<(), String> {
+    let profiles = auth_profiles::stored_profiles();
+    let entry = profiles
+        .get(profile_id)
+        .filter(|p| p.get("type").and_then(|v| v.as_str()) == Some("oauth"))
+        .ok_or_else(|| format!("{}은(는) OAuth 프로필이 아닙니다", profile_id))?;
+    let refresh = entry
+        .get("refresh")
+        .and_then(|v| v.as_str())
+        .ok_or("refresh token이 없습니다")?
+        .to_string();
+
+    let endpoints = OAuthEndpoints::current();
+    let tokens = request_token(
+        &endpoints.token_url,
+        json!({
+            "grant_type": "refresh_token",
+            "refresh_token": refresh,
+            "client_id": endpoints.client_id,
+        }),
+    )
+    .await?;
+    // 갱신은 비밀 값 저장소만 수정 (openclaw.json은 다시 쓰지 않음)
+    auth_profiles::store_secret(profile_id, &token_entry(&tokens, Some(&refresh))?)?;
+    clear_refresh_failures(profile_id);
+    Ok(())
+}
+
+fn clear_refresh_failures(profile_id: &str) {
+    if let Ok(mut failures) = REFRESH_FAILURES.lock() {
+        failures.remove(profile_id);
+    }
+}
+
+/// 갱신 실패 기록 → (연속 실패 횟수, 자동 갱신 중단 여부)
+fn record_refresh_failure(profile_id: &str) -> (u32, bool) {
+    let mut failures = REFRESH_FAILURES.lock().unwrap_or_else(|e| e.into_inner());
+    let entry = failures.entry(profile_id.to_string()).or_insert((0, 0));
+    entry.0 += 1;
+    let backoff = (REFRESH_CHECK_INTERVAL.as_millis() as i64)
+        .saturating_mul(1 << entry.0.min(16))
+        .min(MAX_REFRESH_BACKOFF_MS);
+    entry.1 = now_ms() + backoff;
+    (entry.0, entry.0 >= MAX_REFRESH_FAILURES)
+}
+
+/// 자동 갱신을 시도할 차례인지 (실패 후 대기 중이거나 중단된 프로필은 건너뜀)
+fn refresh_allowed(profile_id: &str) -> bool {
+    let failures = REFRESH_FAILURES.lock().unwrap_or_else(|e| e.into_inner());
+    failures
+        .get(profile_id)
+        .is_none_or(|(count, next_ms)| *count < MAX_REFRESH_FAILURES && now_ms() >= *next_ms)
+}
+
+/// 만료가 가까운 Anthropic OAuth 프로필 갱신 (백그라운드)
+pub fn start_refresh_loop(app: tauri::AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            let due: Vec<String> = auth_profiles::stored_profiles()
+                .into_iter()
+                .filter(|(_, p)| {
+                    p.get("type").and_then(|v| v.as_str()) == Some("oauth")
+                        && p.get("provider").and_then(|v| v.as_str()) == Some("anthropic")
+                        && p.get("expires")
+                            .and_then(|v| v.as_i64())
+                            .is_some_and(|exp| exp - now_ms() < REFRESH_MARGIN_MS)
+                })
+                .map(|(id, _)| id)
+                .filter(|id| refresh_allowed(id))
+                .collect();
+
+            for profile_id in due {
+                match refresh_profile(&profile_id).await {
+                    Ok(()) => emit(&app, "refreshed", &profile_id, None),
+                    Err(e) => {
+                        eprintln!("OAuth 토큰 갱신 실패 ({}): {}", profile_id, e);
+                        // 첫 실패와 자동 갱신을 멈출 때만 알림 (그 사이는 간격을 늘려 재시도)
+                        match record_refresh_failure(&profile_id) {
+                            (1, _) => emit(&app, "error", &profile_id, Some(e)),
+                            (_, true) => emit(
+                                &app,
+                                "error",
+                                &profile_id,
+                                Some(format!("토큰 갱신이 계속 실패해 자동 갱신을 중단했습니다. 다시 로그인하세요: {}", e)),
+                            ),
+                            _ => {}
+                        }
+                    }
+                }
+            }
+            tokio::time::sleep(REFRESH_CHECK_INTERVAL).await;
+        }
+    });
+}
+
+// ===== Tauri 커맨드 =====
+
+/// OAuth 로그인 시작: 인증 URL 반환 (UI에서 브라우저로 열기)
+/// 완료/실패는 "anthropic-oauth" 이벤트로 전달
+#[tauri::command]
+pub async fn start_anthropic_oauth(
+    app: tauri::AppHandle,
+    profile_name: Option<String>,
+) -> Result<OAuthStart, String> {
+    let profile_id = auth_profiles::profile_id("anthropic", profile_name.as_deref().unwrap_or("oauth"))?;
+    let endpoints = OAuthEndpoints::current();
+
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .map_err(|e| format!("로컬 콜백 서버 시작 실패: {}", e))?;
+    let port = listener
+        .local_addr()
+        .map_err(|e| format!("로컬 포트 확인 실패: {}", e))?
+        .port();
+    let redirect_uri = format!("http://localhost:{}/callback", port);
+
+    let (verifier, challenge) = pkce_pair();
+    let state = random_urlsafe(24);
+    let mut auth_url = reqwest::Url::parse(&endpoints.authorize_url)
+        .map_err(|e| format!("잘못된 인증 URL입니다: {}", e))?;
+    auth_url
+        .query_pairs_mut()
+        .append_pair("code", "true")
+        .append_pair("client_id", &endpoints.client_id)
+        .append_pair("response_type", "code")
+        .append_pair("redirect_uri", &redirect_uri)
+        .append_pair("scope", SCOPES)
+        .append_pair("code_challenge", &challenge)
+        .append_pair("code_challenge_method", "S256")
+        .append_pair("state", &state);
+
+    // 이전 로그인 대기 중이면 취소
+    let (cancel_tx, cancel_rx) = tokio::sync::oneshot::channel();
+    if let Some(previous) = CANCEL_LOGIN.lock().map_err(|e| e.to_string())?.replace(cancel_tx) {
+        let _ = previous.send(());
+    }
+
+    let task_profile = profile_id.clone();
+    let task_redirect = redirect_uri.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::select! {
+            result = complete_login(listener, endpoints, task_redirect, verifier, state, task_profile.clone()) => {
+                match result {
+                    Ok(()) => emit(&app, "success", &task_profile, None),
+                    Err(e) => emit(&app, "error", &task_profile, Some(e)),
+                }
+            }
+            _ = cancel_rx => emit(&app, "cancelled", &task_profile, None),
+        }
+    });
+
+    Ok(OAuthStart {
+        auth_url: auth_url.to_string(),
+        redirect_uri,
+        profile_id,
+    })
+}
+
+/// 진행 중인 OAuth 로그인 취소
+#[tauri::command]
+pub fn cancel_anthropic_oauth() -> Result<(), String> {
+    if let Some(tx) = CANCEL_LOGIN.lock().map_err(|e| e.to_string())?.take() {
+        let _ = tx.send(());
+    }
+    Ok(())
+}
+
+/// OAuth 프로필 토큰 즉시 갱신
+#[tauri::command]
+pub async fn refresh_anthropic_oauth(profile_id: String) -> Result<(), String> {
+    refresh_profile(&profile_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pkce_challenge_matches_verifier() {
+        let (verifier, challenge) = pkce_pair();
+        assert_eq!(verifier.len(), 43);
+        assert_eq!(challenge, URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes())));
+    }
+
+    #[tokio::test]
+    async fn request_token_exchanges_code() {
+        let mut server = mockito::Server::new_async().await;
+        let token = server
+            .mock("POST", "/v1/oauth/token")
+            .match_header("content-type", "application/json")
+            .match_body(mockito::Matcher::PartialJson(json!({
+                "grant_type": "authorization_code",
+                "code": "auth-code",
+                "code_verifier": "verifier",
+            })))
+            .with_status(200)
+            .with_body(r#"{"access_token":"sk-ant-oat01-access","refresh_token":"sk-ant-ort01-refresh","expires_in":28800}"#)
+            .create_async()
+            .await;
+
+        let tokens = request_token(
+            &format!("{}/v1/oauth/token", server.url()),
+            json!({
+                "grant_type": "authorization_code",
+                "code": "auth-code",
+                "state": "state",
+                "client_id": "client",
+                "redirect_uri": "http://localhost:54545/callback",
+                "code_verifier": "verifier",
+            }),
+        )
+        .await
+        .unwrap();
+        assert_eq!(tokens.access_token, "sk-ant-oat01-access");
+        assert_eq!(tokens.refresh_token.as_deref(), Some("sk-ant-ort01-refresh"));
+        assert_eq!(tokens.expires_in, Some(28800));
+        token.assert_async().await;
+
+        let entry = token_entry(&tokens, None).unwrap();
+        assert_eq!(entry["type"], "oauth");
+        assert_eq!(entry["refresh"], "sk-ant-ort01-refresh");
+    }
+
+    #[tokio::test]
+    async fn request_token_reports_http_errors() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/v1/oauth/token")
+            .with_status(400)
+            .with_body(r#"{"error":"invalid_grant"}"#)
+            .create_async()
+            .await;
+
+        let error = request_token(&format!("{}/v1/oauth/token", server.url()), json!({ "grant_type": "refresh_token" }))
+            .await
+            .unwrap_err();
+        assert!(error.contains("HTTP 400"), "{}", error);
+        assert!(error.contains("invalid_grant"), "{}", error);
+    }
+
+    #[test]
+    fn token_entry_keeps_previous_refresh_token() {
+        let tokens = TokenResponse { access_token: "a".to_string(), refresh_token: None, expires_in: None };
+        assert_eq!(token_entry(&tokens, Some("old")).unwrap()["refresh"], "old");
+        assert!(token_entry(&tokens, None).is_err());
+    }
+}
@@ -0,0 +1,502 @@
+// 인증 프로필 관리 (프로바이더당 여러 키/토큰)
+// - 프로필 메타데이터: openclaw.json auth.profiles.<id> = { provider, mode }
+// - 비밀 값: ~/.openclaw/agents/<agentId>/agent/auth-profiles.json (모든 에이전트에 같은 값 저장)
+// - 전역 failover 순서: auth.order.<provider>
+// - 에이전트별 우선 프로필: 해당 에이전트 auth-profiles.json 의 order.<provider>
+// Rate limit 등으로 한 프로필이 실패하면 Gateway가 순서대로 다음 프로필을 사용합니다.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::openclaw::{
+    detect_auth_mode, get_moldclaw_state_dir, get_openclaw_dir, pointer_escape, read_existing_config,
+    set_nested_value, write_config,
+};
+use crate::provider_api::{self, KeyStatus};
+
+/// 목록 조회 기준 에이전트 (에이전트가 하나도 없을 때도 저장되는 곳)
+const DEFAULT_AGENT: &str = "main";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ValidationRecord {
+    at_ms: i64,
+    status: KeyStatus,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthProfileInfo {
+    pub id: String,
+    pub provider: String,
+    /// api_key | token | oauth
+    pub mode: String,
+    pub masked_secret: Option<String>,
+    /// 전역 failover 순서 (0부터, 순서에 없으면 None)
+    pub order_index: Option<usize>,
+    /// 이 프로필을 우선 사용하는 에이전트
+    pub active_for: Vec<String>,
+    pub last_validated_ms: Option<i64>,
+    pub last_validation_status: Option<KeyStatus>,
+}
+
+fn agent_store_path(agent_id: &str) -> PathBuf {
+    get_openclaw_dir()
+        .join("agents")
+        .join(agent_id)
+        .join("agent")
+        .join("auth-profiles.json")
+}
+
+fn validation_path() -> PathBuf {
+    get_moldclaw_state_dir().join("auth-validation.json")
+}
+
+fn read_json(path: &PathBuf) -> Value {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_else(|| json!({}))
+}
+
+pub(crate) fn write_json(path: &PathBuf, value: &Value) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| format!("디렉토리 생성 실패: {}", e))?;
+    }
+    let content = serde_json::to_string_pretty(value).map_err(|e| format!("JSON 직렬화 실패: {}", e))?;
+
+    // 비밀 값이 들어있으므로 소유자만 읽기 가능하게 (새 파일은 처음부터 0600으로 생성)
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+        options.mode(0o600);
+        if path.exists() {
+            let _ = std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600));
+        }
+    }
+    options
+        .open(path)
+        .and_then(|mut f| f.write_all(content.as_bytes()))
+        .map_err(|e| format!("{} 저장 실패: {}", path.display(), e))
+}
+
+fn load_store(agent_id: &str) -> Value {
+    let mut store = read_json(&agent_store_path(agent_id));
+    if store.get("version").is_none() {
+        set_nested_value(&mut store, &["version"], json!(1));
+    }
+    if store.get("profiles").is_none() {
+        set_nested_value(&mut store, &["profiles"], json!({}));
+    }
+    store
+}
+
+fn load_validations() -> HashMap<String, ValidationRecord> {
+    std::fs::read_to_string(validation_path())
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn mask_secret(secret: &str) -> String {
+    let chars: Vec<char> = secret.chars().collect();
+    if chars.len() <= 12 {
+        return "••••".to_string();
+    }
+    let head: String = chars[..6].iter().collect();
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("{}…{}", head, tail)
+}
+
+/// 저장된 비밀 값 (api_key: key, token: token, oauth: access)
+fn profile_secret(entry: &Value) -> Option<String> {
+    ["key", "token", "access"]
+        .iter()
+        .find_map(|k| entry.get(*k).and_then(|v| v.as_str()))
+        .map(|s| s.to_string())
+}
+
+/// 에이전트 디렉토리 목록
+fn agent_ids() -> Vec<String> {
+    let mut ids: Vec<String> = std::fs::read_dir(get_openclaw_dir().join("agents"))
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.path().is_dir())
+                .map(|e| e.file_name().to_string_lossy().to_string())
+                .collect()
+        })
+        .unwrap_or_default();
+    if !ids.iter().any(|id| id == DEFAULT_AGENT) {
+        ids.push(DEFAULT_AGENT.to_string());
+    }
+    ids
+}
+
+fn order_of(config: &Value, provider: &str) -> Vec<String> {
+    config
+        .pointer(&format!("/auth/order/{}", pointer_escape(provider)))
+        .and_then(|o| o.as_array())
+        .map(|list| list.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect())
+        .unwrap_or_default()
+}
+
+/// 프로바이더 이름 검증 (프로필 ID "<provider>:<name>"의 앞부분이 되므로 ':' '/' 등 불가)
+fn validate_provider(provider: &str) -> Result<(), String> {
+    let valid = !provider.is_empty()
+        && provider
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '_' | '.'));
+    if !valid {
+        return Err(format!("잘못된 프로바이더 이름입니다: {}", provider));
+    }
+    Ok(())
+}
+
+pub(crate) fn profile_id(provider: &str, name: &str) -> Result<String, String> {
+    validate_provider(provider)?;
+    let name = name.trim();
+    if name.is_empty() || name.contains(':') || name.contains('/') {
+        return Err(format!("잘못된 프로필 이름입니다: {}", name));
+    }
+    Ok(format!("{}:{}", provider, name))
+}
+
+/// 설정(auth.profiles) 또는 비밀 값 저장소에 있는 프로필인지
+fn profile_exists(config: &Value, id: &str) -> bool {
+    config.pointer("/auth/profiles").and_then(|p| p.get(id)).is_some()
+        || load_store(DEFAULT_AGENT).pointer("/profiles").and_then(|p| p.get(id)).is_some()
+}
+
+/// 프로필 ID에서 프로바이더 추출 ("anthropic:team" → "anthropic")
+fn provider_of(id: &str) -> Result<&str, String> {
+    id.split_once(':')
+        .map(|(p, _)| p)
+        .filter(|p| validate_provider(p).is_ok())
+        .ok_or_else(|| format!("잘못된 프로필 ID입니다: {}", id))
+}
+
+// ===== Tauri 커맨드 =====
+
+/// 인증 프로필 목록 (provider 지정 시 해당 프로바이더만)
+#[tauri::command]
+pub fn list_auth_profiles(provider: Option<String>) -> Vec<AuthProfileInfo> {
+    let config = read_existing_config();
+    let store = load_store(DEFAULT_AGENT);
+    let validations = load_validations();
+
+    // 에이전트별 우선 프로필 (order.<provider>의 첫 항목)
+    let agent_orders: Vec<(String, Value)> = agent_ids()
+        .into_iter()
+        .map(|id| {
+            let order = read_json(&agent_store_path(&id)).get("order").cloned().unwrap_or(Value::Null);
+            (id, order)
+        })
+        .collect();
+
+    let mut ids: Vec<String> = config
+        .pointer("/auth/profiles")
+        .and_then(|p| p.as_object())
+        .map(|p| p.keys().cloned().collect())
+        .unwrap_or_default();
+    if let Some(stored) = store.get("profiles").and_then(|p| p.as_object()) {
+        for id in stored.keys() {
+            if !ids.contains(id) {
+                ids.push(id.clone());
+            }
+        }
+    }
+
+    ids.into_iter()
+        .filter_map(|id| {
+            let meta = config.pointer("/auth/profiles").and_then(|p| p.get(&id));
+            let stored = store.get("profiles").and_then(|p| p.get(&id));
+            let profile_provider = meta
+                .or(stored)
+                .and_then(|m| m.get("provider"))
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_string())
+                .or_else(|| provider_of(&id).ok().map(|s| s.to_string()))?;
+            if provider.as_deref().is_some_and(|p| p != profile_provider) {
+                return None;
+            }
+
+            // 기본 프로필은 models.providers.<p>.apiKey 를 사용
+            let secret = stored.and_then(profile_secret).or_else(|| {
+                config
+                    .pointer(&format!("/models/providers/{}/apiKey", pointer_escape(&profile_provider)))
+                    .and_then(|v| v.as_str())
+                    .filter(|_| id.ends_with(":default"))
+                    .map(|s| s.to_string())
+            });
+            let mode = match stored.and_then(|s| s.get("type")).and_then(|v| v.as_str()) {
+                Some("oauth") => "oauth".to_string(),
+                _ => secret
+                    .as_deref()
+                    .map(|s| detect_auth_mode(&profile_provider, s).to_string())
+                    .or_else(|| meta.and_then(|m| m.get("mode")).and_then(|v| v.as_str()).map(|s| s.to_string()))
+                    .unwrap_or_else(|| "api_key".to_string()),
+            };
+            let active_for = agent_orders
+                .iter()
+                .filter(|(_, order)| {
+                    order.get(&profile_provider).and_then(|o| o.get(0)).and_then(|v| v.as_str())
+                        == Some(id.as_str())
+                })
+                .map(|(agent, _)| agent.clone())
+                .collect();
+            let validation = validations.get(&id);
+
+            Some(AuthProfileInfo {
+                order_index: order_of(&config, &profile_provider).iter().position(|o| *o == id),
+                masked_secret: secret.as_deref().map(mask_secret),
+                provider: profile_provider,
+                mode,
+                active_for,
+                last_validated_ms: validation.map(|v| v.at_ms),
+                last_validation_status: validation.map(|v| v.status),
+                id,
+            })
+        })
+        .collect()
+}
+
+/// 비밀 값만 모든 에이전트 저장소에 기록 (설정 파일은 건드리지 않음)
+pub(crate) fn store_secret(id: &str, entry: &Value) -> Result<(), String> {
+    for agent in agent_ids() {
+        let mut store = load_store(&agent);
+        set_nested_value(&mut store, &["profiles", id], entry.clone());
+        write_json(&agent_store_path(&agent), &store)?;
+    }
+    Ok(())
+}
+
+/// 프로필 저장 (비밀 값 + 메타데이터 + failover 순서 끝에 추가)
+pub(crate) fn store_profile(provider: &str, id: &str, entry: Value, mode: &str) -> Result<(), String> {
+    store_secret(id, &entry)?;
+
+    let mut config = read_existing_config();
+    set_nested_value(&mut config, &["auth", "profiles", id, "provider"], json!(provider));
+    set_nested_value(&mut config, &["auth", "profiles", id, "mode"], json!(mode));
+
+    // 순서가 없으면 기존 프로필들로 먼저 구성
+    let mut order = order_of(&config, provider);
+    if order.is_empty() {
+        if let Some(profiles) = config.pointer("/auth/profiles").and_then(|p| p.as_object()) {
+            order = profiles
+                .iter()
+                .filter(|(pid, p)| *pid != id && p.get("provider").and_then(|v| v.as_str()) == Some(provider))
+                .map(|(pid, _)| pid.clone())
+                .collect();
+        }
+    }
+    if !order.iter().any(|o| o == id) {
+        order.push(id.to_string());
+    }
+    set_nested_value(&mut config, &["auth", "order", provider], json!(order));
+    write_config(&config)
+}
+
+/// 저장된 프로필 항목 조회 (기본 에이전트 저장소)
+pub(crate) fn stored_profiles() -> serde_json::Map<String, Value> {
+    load_store(DEFAULT_AGENT)
+        .get("profiles")
+        .and_then(|p| p.as_object())
+        .cloned()
+        .unwrap_or_default()
+}
+
+/// 인증 프로필 추가/수정 (id = "<provider>:<name>", failover 순서 끝에 추가)
+#[tauri::command]
+pub fn add_auth_profile(provider: String, name: String, secret: String) -> Result<String, String> {
+    let provider = provider.trim().to_string();
+    let id = profile_id(&provider, &name)?;
+    let secret = secret.trim();
+    if secret.is_empty() {
+        return Err("API 키 또는 토큰이 비어 있습니다".to_string());
+    }
+    let mode = detect_auth_mode(&provider, secret);
+    let entry = if mode == "token" {
+        json!({ "type": "token", "provider": provider, "token": secret })
+    } else {
+        json!({ "type": "api_key", "provider": provider, "key": secret })
+    };
+    store_profile(&provider, &id, entry, mode)?;
+    Ok(id)
+}
+
+/// 인증 프로필 삭제 (설정, 비밀 값, 모든 순서 목록에서 제거)
+#[tauri::command]
+pub fn remove_auth_profile(profile_id: String) -> Result<(), String> {
+    let provider = provider_of(&profile_id)?.to_string();
+
+    let mut config = read_existing_config();
+    if let Some(profiles) = config.pointer_mut("/auth/profiles").and_then(|p| p.as_object_mut()) {
+        profiles.remove(&profile_id);
+    }
+    let order: Vec<String> = order_of(&config, &provider)
+        .into_iter()
+        .filter(|id| *id != profile_id)
+        .collect();
+    if config.pointer(&format!("/auth/order/{}", pointer_escape(&provider))).is_some() {
+        set_nested_value(&mut config, &["auth", "order", &provider], json!(order));
+    }
+    write_config(&config)?;
+
+    for agent in agent_ids() {
+        let path = agent_store_path(&agent);
+        if !path.exists() {
+            continue;
+        }
+        let mut store = read_json(&path);
+        if let Some(profiles) = store.get_mut("profiles").and_then(|p| p.as_object_mut()) {
+            profiles.remove(&profile_id);
+        }
+        if let Some(list) = store
+            .pointer_mut(&format!("/order/{}", pointer_escape(&provider)))
+            .and_then(|o| o.as_array_mut())
+        {
+            list.retain(|v| v.as_str() != Some(profile_id.as_str()));
+        }
+        write_json(&path, &store)?;
+    }
+    Ok(())
+}
+
+/// 전역 failover 순서 설정 (앞에서부터 시도)
+#[tauri::command]
+pub fn set_auth_profile_order(provider: String, order: Vec<String>) -> Result<(), String> {
+    let mut config = read_existing_config();
+    for (i, id) in order.iter().enumerate() {
+        if provider_of(id)? != provider {
+            return Err(format!("{} 프로필은 {} 프로바이더가 아닙니다", id, provider));
+        }
+        if !profile_exists(&config, id) {
+            return Err(format!("인증 프로필을 찾을 수 없습니다: {}", id));
+        }
+        if order[..i].contains(id) {
+            return Err(format!("{} 프로필이 순서에 두 번 들어 있습니다", id));
+        }
+    }
+    set_nested_value(&mut config, &["auth", "order", &provider], json!(order));
+    write_config(&config)
+}
+
+/// 에이전트가 우선 사용할 프로필 지정 (나머지는 전역 순서대로 failover)
+#[tauri::command]
+pub fn set_active_auth_profile(agent_id: Option<String>, profile_id: String) -> Result<(), String> {
+    let agent_id = agent_id.unwrap_or_else(|| DEFAULT_AGENT.to_string());
+    // 경로에 쓰이므로 실제 에이전트 디렉토리만 허용 (../ 등으로 밖에 쓰거나 없는 에이전트를 만들지 않음)
+    if !agent_ids().contains(&agent_id) {
+        return Err(format!("에이전트를 찾을 수 없습니다: {}", agent_id));
+    }
+    let provider = provider_of(&profile_id)?.to_string();
+    let config = read_existing_config();
+    if !profile_exists(&config, &profile_id) {
+        return Err(format!("인증 프로필을 찾을 수 없습니다: {}", profile_id));
+    }
+
+    let mut order = vec![profile_id.clone()];
+    order.extend(order_of(&config, &provider).into_iter().filter(|id| *id != profile_id));
+
+    let path = agent_store_path(&agent_id);
+    let mut store = load_store(&agent_id);
+    set_nested_value(&mut store, &["order", &provider], json!(order));
+    write_json(&path, &store)
+}
+
+/// 프로필 키 검증 후 결과 기록
+#[tauri::command]
+pub async fn validate_auth_profile(profile_id: String) -> Result<provider_api::KeyValidation, String> {
+    let provider = provider_of(&profile_id)?.to_string();
+    let store = load_store(DEFAULT_AGENT);
+    let secret = store
+        .pointer("/profiles")
+        .and_then(|p| p.get(&profile_id))
+        .and_then(profile_secret)
+        .or_else(|| {
+            profile_id
+                .ends_with(":default")
+                .then(|| provider_api::configured_api_key(&provider))
+                .flatten()
+        })
+        .ok_or_else(|| format!("{} 프로필의 키를 찾을 수 없습니다", profile_id))?;
+
+    let validation = provider_api::validate_key(&provider, &secret, None).await;
+
+    let mut records = load_validations();
+    records.insert(
+        profile_id,
+        ValidationRecord {
+            at_ms: chrono::Utc::now().timestamp_millis(),
+            status: validation.status,
+        },
+    );
+    let records = serde_json::to_value(&records).map_err(|e| format!("JSON 직렬화 실패: {}", e))?;
+    write_json(&validation_path(), &records)?;
+
+    Ok(validation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_ids_need_clean_provider_and_name() {
+        assert_eq!(profile_id("anthropic", " team ").unwrap(), "anthropic:team");
+        assert_eq!(profile_id("kimi-coding", "work").unwrap(), "kimi-coding:work");
+        assert!(profile_id("anthropic", "a:b").is_err());
+        assert!(profile_id("anthropic", "a/b").is_err());
+        assert!(profile_id("anthropic", " ").is_err());
+        assert!(profile_id("open:ai", "team").is_err());
+        assert!(profile_id("../x", "team").is_err());
+        assert!(profile_id("", "team").is_err());
+    }
+
+    #[test]
+    fn provider_of_reads_the_id_prefix() {
+        assert_eq!(provider_of("anthropic:team").unwrap(), "anthropic");
+        assert_eq!(provider_of("openai:a:b").unwrap(), "openai");
+        assert!(provider_of("anthropic").is_err());
+        assert!(provider_of(":team").is_err());
+        assert!(provider_of("Bad/Provider:team").is_err());
+    }
+
+    #[test]
+    fn secrets_are_masked() {
+        assert_eq!(mask_secret("short-key"), "••••");
+        assert_eq!(mask_secret("sk-ant-api03-abcdefghijkl"), "sk-ant…ijkl");
+        assert_eq!(profile_secret(&json!({ "type": "api_key", "key": "k" })).as_deref(), Some("k"));
+        assert_eq!(profile_secret(&json!({ "type": "token", "token": "t" })).as_deref(), Some("t"));
+        assert_eq!(profile_secret(&json!({ "type": "oauth", "access": "a", "refresh": "r" })).as_deref(), Some("a"));
+        assert_eq!(profile_secret(&json!({ "type": "oauth" })), None);
+    }
+
+    #[test]
+    fn unknown_agents_are_rejected() {
+        let result = set_active_auth_profile(Some("../outside".to_string()), "anthropic:team".to_string());
+        assert!(result.unwrap_err().contains("에이전트를 찾을 수 없습니다"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn write_json_is_owner_only() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("agent").join("auth-profiles.json");
+        write_json(&path, &json!({ "version": 1 })).unwrap();
+        assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+
+        // 기존 파일 권한도 좁힘
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+        write_json(&path, &json!({ "version": 2 })).unwrap();
+        assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        assert_eq!(read_json(&path)["version"], 2);
+    }
+}
@@ -0,0 +1,473 @@
+// 지출 한도 (프로바이더/채널별 일간·월간 예산)
+// 사용량 집계(usage)와 모델 카탈로그 가격으로 현재 기간 지출을 계산하고,
+// 임계값을 넘으면 "budget-alert" 이벤트를 보냅니다.
+// 한도 초과 시 선택적으로 채널을 멈추거나 더 저렴한 모델로 전환합니다.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::time::Duration;
+use tauri::Emitter;
+
+use crate::openclaw::{
+    self, get_moldclaw_state_dir, pointer_escape, read_existing_config, set_nested_value, write_config,
+};
+use crate::usage::{self, UsageRecord};
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(5 * 60);
+const ALERT_EVENT: &str = "budget-alert";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BudgetScope {
+    Provider,
+    Channel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BudgetPeriod {
+    Daily,
+    Monthly,
+}
+
+/// 한도 초과 시 동작
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum HardStop {
+    /// 대상 채널 비활성화 (scope=channel)
+    DisableChannel,
+    /// 기본 모델을 지정한 모델로 전환 ("provider/model")
+    SwitchModel { model: String },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Budget {
+    pub id: String,
+    pub scope: BudgetScope,
+    /// 프로바이더 이름 또는 채널 이름
+    pub target: String,
+    pub period: BudgetPeriod,
+    pub limit_usd: f64,
+    /// 알림 임계값 (한도 대비 %)
+    #[serde(default = "default_thresholds")]
+    pub alert_thresholds: Vec<u32>,
+    #[serde(default)]
+    pub hard_stop: Option<HardStop>,
+}
+
+fn default_thresholds() -> Vec<u32> {
+    vec![50, 80, 100]
+}
+
+/// 알림/차단 진행 상태 (기간이 바뀌면 초기화)
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BudgetRuntime {
+    period_key: String,
+    alerted: Vec<u32>,
+    /// 차단 적용 시 되돌리기 위한 변경 목록
+    stopped: Option<Value>,
+    /// 이번 기간에 차단을 수동 해제했는지 (해제 후 재차단 방지)
+    #[serde(default)]
+    resumed: bool,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BudgetFile {
+    #[serde(default)]
+    budgets: Vec<Budget>,
+    #[serde(default)]
+    runtime: HashMap<String, BudgetRuntime>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BudgetStatus {
+    pub budget: Budget,
+    pub period_key: String,
+    pub spent_usd: f64,
+    pub percent: f64,
+    pub hard_stop_applied: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BudgetAlert {
+    budget_id: String,
+    scope: BudgetScope,
+    target: String,
+    period: BudgetPeriod,
+    threshold: u32,
+    spent_usd: f64,
+    limit_usd: f64,
+    /// 이번 알림에서 차단 동작이 적용되었는지
+    hard_stop_applied: bool,
+}
+
+fn budgets_path() -> PathBuf {
+    get_moldclaw_state_dir().join("budgets.json")
+}
+
+fn load_file() -> BudgetFile {
+    std::fs::read_to_string(budgets_path())
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save_file(file: &BudgetFile) -> Result<(), String> {
+    std::fs::create_dir_all(get_moldclaw_state_dir())
+        .map_err(|e| format!("상태 디렉토리 생성 실패: {}", e))?;
+    let json = serde_json::to_string_pretty(file).map_err(|e| format!("예산 직렬화 실패: {}", e))?;
+    std::fs::write(budgets_path(), json).map_err(|e| format!("예산 저장 실패: {}", e))
+}
+
+/// 현재 기간 키와 시작 시각(ms, 로컬 시간 기준)
+fn current_period(period: BudgetPeriod) -> (String, i64) {
+    use chrono::{Datelike, Local, TimeZone};
+    let now = Local::now();
+    let start = match period {
+        BudgetPeriod::Daily => now.date_naive(),
+        BudgetPeriod::Monthly => now.date_naive().with_day(1).unwrap_or(now.date_naive()),
+    };
+    let start_ms = start
+        .and_hms_opt(0, 0, 0)
+        .and_then(|t| Local.from_local_datetime(&t).earliest())
+        .map(|t| t.timestamp_millis())
+        .unwrap_or(0);
+    let key = match period {
+        BudgetPeriod::Daily => start.format("%Y-%m-%d").to_string(),
+        BudgetPeriod::Monthly => start.format("%Y-%m").to_string(),
+    };
+    (key, start_ms)
+}
+
+fn spent(records: &[UsageRecord], budget: &Budget, since_ms: i64) -> f64 {
+    records
+        .iter()
+        .filter(|r| r.timestamp_ms >= since_ms)
+        .filter(|r| match budget.scope {
+            BudgetScope::Provider => r.provider == budget.target,
+            BudgetScope::Channel => r.channel == budget.target,
+        })
+        .map(|r| r.cost_usd)
+        .sum()
+}
+
+/// 이번 확인에서 새로 알릴 임계값 (오름차순, 이미 알린 값 제외)
+fn due_thresholds(thresholds: &[u32], alerted: &[u32], percent: f64) -> Vec<u32> {
+    let mut due: Vec<u32> = thresholds
+        .iter()
+        .copied()
+        .filter(|t| percent >= *t as f64 && !alerted.contains(t))
+        .collect();
+    due.sort_unstable();
+    due.dedup();
+    due
+}
+
+/// 차단으로 바꾼 설정 값 하나 (JSON pointer, 이전 값, 적용한 값)
+/// 이전 값이 null이면 원래 없던 키
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StopChange {
+    pointer: String,
+    previous: Value,
+    applied: Value,
+}
+
+/// pointer 위치에 값 설정 (null이면 키 삭제, 부모가 없으면 건너뜀)
+fn set_pointer(config: &mut Value, pointer: &str, value: Value) {
+    let Some((parent, key)) = pointer.rsplit_once('/') else {
+        return;
+    };
+    let key = key.replace("~1", "/").replace("~0", "~");
+    if let Some(map) = config.pointer_mut(parent).and_then(|p| p.as_object_mut()) {
+        if value.is_null() {
+            map.remove(&key);
+        } else {
+            map.insert(key, value);
+        }
+    }
+}
+
+/// 변경 목록 적용 후 저장, 되돌리기용 값으로 반환
+fn apply_changes(mut config: Value, changes: Vec<StopChange>) -> Result<Value, String> {
+    for change in &changes {
+        set_pointer(&mut config, &change.pointer, change.applied.clone());
+    }
+    write_config(&config)?;
+    serde_json::to_value(changes).map_err(|e| format!("차단 상태 직렬화 실패: {}", e))
+}
+
+/// 채널 중지 (enabled 키가 없는 WhatsApp은 DM/그룹 정책을 disabled로)
+fn disable_channel(channel: &str) -> Result<Value, String> {
+    let config = read_existing_config();
+    let base = format!("/channels/{}", pointer_escape(channel));
+    let section = config
+        .pointer(&base)
+        .cloned()
+        .ok_or_else(|| format!("{} 채널이 설정되어 있지 않습니다", channel))?;
+
+    let targets: &[(&str, Value)] = if channel == "whatsapp" {
+        &[("dmPolicy", json!("disabled")), ("groupPolicy", json!("disabled"))]
+    } else {
+        &[("enabled", json!(false))]
+    };
+    let changes = targets
+        .iter()
+        .map(|(key, applied)| StopChange {
+            pointer: format!("{}/{}", base, key),
+            previous: section.get(*key).cloned().unwrap_or(Value::Null),
+            applied: applied.clone(),
+        })
+        .collect();
+    apply_changes(config, changes)
+}
+
+/// 지출이 계속되는 모델 설정을 모두 지정한 모델로 전환
+/// - agents.defaults.model: primary 교체, fallbacks 비움
+/// - 에이전트별 model, heartbeat.model, subagents.model: 설정된 경우에만 교체
+///
+/// imageModel과 cron 작업의 모델은 용도가 달라 그대로 둡니다.
+fn switch_model(model_ref: &str) -> Result<Value, String> {
+    let mut config = read_existing_config();
+    openclaw::ensure_model_ref(&mut config, model_ref)?;
+    if config.pointer("/agents/defaults").is_none() {
+        set_nested_value(&mut config, &["agents", "defaults"], json!({}));
+    }
+
+    let chain = json!({ "primary": model_ref, "fallbacks": [] });
+    let mut changes = vec![StopChange {
+        pointer: "/agents/defaults/model".to_string(),
+        previous: config.pointer("/agents/defaults/model").cloned().unwrap_or(Value::Null),
+        applied: chain.clone(),
+    }];
+
+    let agent_count = config
+        .pointer("/agents/list")
+        .and_then(|l| l.as_array())
+        .map(|l| l.len())
+        .unwrap_or(0);
+    let bases = std::iter::once("/agents/defaults".to_string())
+        .chain((0..agent_count).map(|i| format!("/agents/list/{}", i)));
+    for base in bases {
+        let is_defaults = base == "/agents/defaults";
+        for (field, applied) in [
+            ("/model", &chain),
+            ("/heartbeat/model", &json!(model_ref)),
+            ("/subagents/model", &json!(model_ref)),
+        ] {
+            if is_defaults && field == "/model" {
+                continue;
+            }
+            let pointer = format!("{}{}", base, field);
+            if let Some(previous) = config.pointer(&pointer).filter(|v| *v != applied) {
+                changes.push(StopChange { pointer, previous: previous.clone(), applied: applied.clone() });
+            }
+        }
+    }
+    apply_changes(config, changes)
+}
+
+/// 차단 되돌리기
+/// 차단 후 사용자가 직접 바꾼 값(적용한 값과 다른 값)은 건드리지 않음
+fn undo_stop(previous: &Value) -> Result<(), String> {
+    let changes: Vec<StopChange> = serde_json::from_value(previous.clone()).unwrap_or_default();
+    if changes.is_empty() {
+        return Ok(());
+    }
+    let mut config = read_existing_config();
+    for change in changes {
+        if config.pointer(&change.pointer) == Some(&change.applied) {
+            set_pointer(&mut config, &change.pointer, change.previous);
+        }
+    }
+    write_config(&config)
+}
+
+fn emit_alert(app: Option<&tauri::AppHandle>, budget: &Budget, threshold: u32, spent_usd: f64, applied: bool) {
+    if let Some(app) = app {
+        let _ = app.emit(
+            ALERT_EVENT,
+            BudgetAlert {
+                budget_id: budget.id.clone(),
+                scope: budget.scope,
+                target: budget.target.clone(),
+                period: budget.period,
+                threshold,
+                spent_usd,
+                limit_usd: budget.limit_usd,
+                hard_stop_applied: applied,
+            },
+        );
+    }
+}
+
+/// 모든 예산 평가: 임계값 알림 + 한도 초과 차단
+pub async fn evaluate(app: Option<&tauri::AppHandle>) -> Result<Vec<BudgetStatus>, String> {
+    let mut file = load_file();
+    if file.budgets.is_empty() {
+        return Ok(vec![]);
+    }
+
+    // 월 시작부터 읽으면 일간/월간 모두 계산 가능
+    let (_, month_start) = current_period(BudgetPeriod::Monthly);
+    let (_, day_start) = current_period(BudgetPeriod::Daily);
+    let records = usage::collect_usage(Some(month_start.min(day_start)));
+
+    let mut statuses = Vec::new();
+    for budget in file.budgets.clone() {
+        let (period_key, start_ms) = current_period(budget.period);
+        let spent_usd = spent(&records, &budget, start_ms);
+        let percent = if budget.limit_usd > 0.0 { spent_usd / budget.limit_usd * 100.0 } else { 0.0 };
+
+        let runtime = file.runtime.entry(budget.id.clone()).or_default();
+        if runtime.period_key != period_key {
+            // 새 기간: 이전 기간의 차단은 자동 해제
+            if let Some(previous) = runtime.stopped.take() {
+                if let Err(e) = undo_stop(&previous) {
+                    eprintln!("예산 차단 해제 실패 ({}): {}", budget.id, e);
+                }
+            }
+            *runtime = BudgetRuntime { period_key: period_key.clone(), ..Default::default() };
+        }
+
+        // 한도 초과 차단은 알림 임계값과 별개로 판단
+        // (실패하면 기록하지 않고 다음 확인 때 다시 시도, 수동 해제한 기간에는 적용하지 않음)
+        let mut applied = false;
+        if percent >= 100.0 && runtime.stopped.is_none() && !runtime.resumed {
+            let result = match &budget.hard_stop {
+                Some(HardStop::DisableChannel) if budget.scope == BudgetScope::Channel => {
+                    Some(disable_channel(&budget.target))
+                }
+                Some(HardStop::SwitchModel { model }) => Some(switch_model(model)),
+                _ => None,
+            };
+            match result {
+                Some(Ok(previous)) => {
+                    runtime.stopped = Some(previous);
+                    applied = true;
+                }
+                Some(Err(e)) => eprintln!("예산 차단 적용 실패 ({}), 다음 확인 때 재시도: {}", budget.id, e),
+                None => {}
+            }
+        }
+
+        let mut stop_reported = false;
+        for threshold in due_thresholds(&budget.alert_thresholds, &runtime.alerted, percent) {
+            runtime.alerted.push(threshold);
+            let report_stop = applied && threshold >= 100 && !stop_reported;
+            stop_reported |= report_stop;
+            emit_alert(app, &budget, threshold, spent_usd, report_stop);
+        }
+        if applied && !stop_reported {
+            // 100% 이상 임계값이 없거나 이미 알린 경우에도 차단 사실은 알림
+            emit_alert(app, &budget, 100, spent_usd, true);
+        }
+
+        statuses.push(BudgetStatus {
+            hard_stop_applied: runtime.stopped.is_some(),
+            budget,
+            period_key,
+            spent_usd,
+            percent,
+        });
+    }
+
+    save_file(&file)?;
+    Ok(statuses)
+}
+
+/// 백그라운드 예산 확인 루프
+pub fn start_budget_loop(app: tauri::AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            if let Err(e) = evaluate(Some(&app)).await {
+                eprintln!("예산 확인 실패: {}", e);
+            }
+            tokio::time::sleep(CHECK_INTERVAL).await;
+        }
+    });
+}
+
+// ===== Tauri 커맨드 =====
+
+/// 예산 목록
+#[tauri::command]
+pub fn get_budgets() -> Vec<Budget> {
+    load_file().budgets
+}
+
+/// 예산 목록 저장 (삭제된 예산의 진행 상태는 정리)
+#[tauri::command]
+pub fn set_budgets(budgets: Vec<Budget>) -> Result<(), String> {
+    for b in &budgets {
+        if b.id.trim().is_empty() || b.target.trim().is_empty() {
+            return Err("예산 ID와 대상은 비워둘 수 없습니다".to_string());
+        }
+        if b.limit_usd <= 0.0 {
+            return Err(format!("{} 예산 한도는 0보다 커야 합니다", b.id));
+        }
+        if matches!(b.hard_stop, Some(HardStop::DisableChannel)) && b.scope != BudgetScope::Channel {
+            return Err(format!("{}: 채널 중지는 채널 예산에만 사용할 수 있습니다", b.id));
+        }
+    }
+    let mut file = load_file();
+    file.runtime.retain(|id, _| budgets.iter().any(|b| b.id == *id));
+    file.budgets = budgets;
+    save_file(&file)
+}
+
+/// 예산별 현재 지출 (평가 포함)
+#[tauri::command]
+pub async fn get_budget_status(app: tauri::AppHandle) -> Result<Vec<BudgetStatus>, String> {
+    evaluate(Some(&app)).await
+}
+
+/// 한도 초과로 적용된 차단 수동 해제 (이번 기간에는 다시 차단하지 않음)
+#[tauri::command]
+pub async fn resume_budget(budget_id: String) -> Result<(), String> {
+    let mut file = load_file();
+    let runtime = file
+        .runtime
+        .get_mut(&budget_id)
+        .ok_or_else(|| format!("예산을 찾을 수 없습니다: {}", budget_id))?;
+    if let Some(previous) = runtime.stopped.take() {
+        undo_stop(&previous)?;
+    }
+    runtime.resumed = true;
+    save_file(&file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn due_thresholds_in_order_without_repeats() {
+        assert_eq!(due_thresholds(&[100, 50, 80], &[], 85.0), vec![50, 80]);
+        assert_eq!(due_thresholds(&[100, 50, 80], &[50], 85.0), vec![80]);
+        assert_eq!(due_thresholds(&[50, 80, 100], &[50, 80], 100.0), vec![100]);
+        assert_eq!(due_thresholds(&[80, 80], &[], 120.0), vec![80]);
+        assert!(due_thresholds(&[50, 80, 100], &[], 49.9).is_empty());
+        assert!(due_thresholds(&[], &[], 150.0).is_empty());
+    }
+
+    #[test]
+    fn set_pointer_sets_and_removes_keys() {
+        let mut config = json!({ "channels": { "telegram": { "enabled": true }, "a/b": {} } });
+        set_pointer(&mut config, "/channels/telegram/enabled", json!(false));
+        assert_eq!(config["channels"]["telegram"]["enabled"], false);
+        set_pointer(&mut config, "/channels/telegram/enabled", Value::Null);
+        assert!(config["channels"]["telegram"].get("enabled").is_none());
+        set_pointer(&mut config, "/channels/a~1b/x", json!(1));
+        assert_eq!(config["channels"]["a/b"]["x"], 1);
+        // 부모가 없으면 건너뜀
+        set_pointer(&mut config, "/channels/discord/enabled", json!(false));
+        assert!(config["channels"].get("discord").is_none());
+    }
+}
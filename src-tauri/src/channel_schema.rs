@@ -0,0 +1,686 @@
+// 채널별 필수 입력 스키마 및 플러그인 채널 온보딩 (Signal, Matrix, LINE, iMessage)
+// - get_channel_schema: 채널마다 필요한 필드(토큰, 홈서버, 전화번호 등)와 형식
+// - configure_channel: 필드 검증 → 플러그인 활성화 → 정책 + 접속 정보 저장
+// - verify_channel_setup: Matrix/LINE은 API, Signal/iMessage는 로컬 CLI로 확인
+// - start_signal_link: signal-cli link로 기기 연결 URI(sgnl://linkdevice) 발급
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tauri::Emitter;
+
+use crate::channel_api::{self, BotIdentity, TokenStatus, TokenVerification};
+use crate::openclaw::{
+    self, channel_section_mut, enable_channel_plugin, normalize_account_id, read_existing_config,
+    run_openclaw_command, set_nested_value, write_config, MASKED_SECRET,
+};
+
+const SIGNAL_LINK_EVENT: &str = "signal-link";
+/// signal-cli가 연결 URI를 출력할 때까지 기다리는 시간
+const SIGNAL_URI_TIMEOUT: Duration = Duration::from_secs(30);
+/// 기기 연결(QR 스캔) 대기 시간
+const SIGNAL_LINK_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// 진행 중인 signal-cli link 프로세스
+static SIGNAL_LINK: Mutex<Option<Child>> = Mutex::new(None);
+/// 연결 시도 번호 (이전 시도의 대기 스레드가 새 프로세스를 건드리지 않도록)
+static SIGNAL_LINK_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelField {
+    /// 채널 섹션 안의 키 (예: "homeserver", "channelAccessToken")
+    pub key: &'static str,
+    pub label: &'static str,
+    /// text | secret | url | phone | path
+    pub kind: &'static str,
+    pub required: bool,
+    pub placeholder: Option<&'static str>,
+    pub help: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelSchema {
+    pub channel: &'static str,
+    pub label: &'static str,
+    /// 별도 설치가 필요한 OpenClaw 플러그인 패키지
+    pub plugin_package: Option<&'static str>,
+    /// 지원 OS (비어 있으면 모든 OS)
+    pub platforms: Vec<&'static str>,
+    /// DM 정책이 dm.policy / dm.allowFrom 아래에 있는지
+    pub nested_dm: bool,
+    pub fields: Vec<ChannelField>,
+}
+
+fn field(
+    key: &'static str,
+    label: &'static str,
+    kind: &'static str,
+    required: bool,
+    placeholder: Option<&'static str>,
+    help: Option<&'static str>,
+) -> ChannelField {
+    ChannelField { key, label, kind, required, placeholder, help }
+}
+
+fn schema(channel: &'static str) -> Option<ChannelSchema> {
+    let (label, plugin_package, platforms, fields) = match channel {
+        "telegram" => ("Telegram", None, vec![], vec![
+            field("botToken", "봇 토큰", "secret", true, Some("123456789:ABC..."), Some("@BotFather에서 /newbot으로 발급")),
+        ]),
+        "discord" => ("Discord", None, vec![], vec![
+            field("token", "봇 토큰", "secret", true, None, Some("Developer Portal → Bot → Reset Token")),
+        ]),
+        "whatsapp" => ("WhatsApp", None, vec![], vec![]),
+        "slack" => ("Slack", None, vec![], vec![
+            field("botToken", "Bot 토큰", "secret", true, Some("xoxb-..."), Some("OAuth & Permissions → Bot User OAuth Token")),
+            field("appToken", "App 토큰", "secret", true, Some("xapp-..."), Some("Basic Information → App-Level Tokens (connections:write)")),
+        ]),
+        "googlechat" => ("Google Chat", None, vec![], vec![
+            field("serviceAccountFile", "서비스 계정 JSON", "path", true, None, Some("Google Cloud 콘솔에서 발급한 서비스 계정 키 파일")),
+        ]),
+        "mattermost" => ("Mattermost", None, vec![], vec![
+            field("baseUrl", "서버 주소", "url", true, Some("https://chat.example.com"), None),
+            field("botToken", "봇 토큰", "secret", true, None, Some("통합 → 봇 계정에서 발급")),
+        ]),
+        "signal" => ("Signal", None, vec![], vec![
+            field("account", "전화번호", "phone", true, Some("+821012345678"), Some("signal-cli로 연결(link)하거나 등록한 번호 (E.164 형식)")),
+            field("cliPath", "signal-cli 경로", "path", false, Some("signal-cli"), Some("PATH에 있으면 비워두세요")),
+            field("httpUrl", "signal-cli 데몬 주소", "url", false, Some("http://127.0.0.1:8080"), Some("이미 실행 중인 데몬을 사용할 때만 입력")),
+        ]),
+        "matrix" => ("Matrix", Some("@openclaw/matrix"), vec![], vec![
+            field("homeserver", "홈서버 주소", "url", true, Some("https://matrix.org"), None),
+            field("userId", "봇 사용자 ID", "text", true, Some("@bot:matrix.org"), None),
+            field("accessToken", "액세스 토큰", "secret", true, Some("syt_..."), Some("Element → 설정 → 도움말 및 정보 → 액세스 토큰")),
+        ]),
+        "line" => ("LINE", Some("@openclaw/line"), vec![], vec![
+            field("channelAccessToken", "채널 액세스 토큰", "secret", true, None, Some("LINE Developers → Messaging API → Channel access token")),
+            field("channelSecret", "채널 시크릿", "secret", true, None, Some("LINE Developers → Basic settings → Channel secret")),
+        ]),
+        "imessage" => ("iMessage", None, vec!["macos"], vec![
+            field("cliPath", "imsg 경로", "path", false, Some("imsg"), Some("brew install steipete/tap/imsg")),
+            field("dbPath", "Messages DB 경로", "path", false, Some("~/Library/Messages/chat.db"), Some("터미널/앱에 전체 디스크 접근 권한 필요")),
+        ]),
+        _ => return None,
+    };
+    Some(ChannelSchema {
+        channel,
+        label,
+        plugin_package,
+        platforms,
+        nested_dm: openclaw::dm_setting_path(channel, "policy").len() > 1,
+        fields,
+    })
+}
+
+/// 채널의 대표 자격 증명 키 (add_channel_to_config의 token 인자가 저장되는 위치)
+pub fn secret_field(channel: &str) -> Option<&'static str> {
+    match channel {
+        "telegram" | "slack" | "mattermost" => Some("botToken"),
+        "discord" => Some("token"),
+        "signal" => Some("account"),
+        "matrix" => Some("accessToken"),
+        "line" => Some("channelAccessToken"),
+        _ => None,
+    }
+}
+
+fn find_schema(channel: &str) -> Result<ChannelSchema, String> {
+    openclaw::ACCOUNT_CHANNELS
+        .iter()
+        .find(|c| **c == channel)
+        .and_then(|c| schema(c))
+        .ok_or_else(|| format!("지원하지 않는 채널: {}", channel))
+}
+
+fn current_platform() -> &'static str {
+    if cfg!(target_os = "macos") {
+        "macos"
+    } else if cfg!(windows) {
+        "windows"
+    } else {
+        "linux"
+    }
+}
+
+/// 필드 값 형식 검증
+fn validate_field(channel: &str, f: &ChannelField, value: &str) -> Result<(), String> {
+    let ok = match f.kind {
+        "url" => reqwest::Url::parse(value).is_ok_and(|u| u.scheme() == "http" || u.scheme() == "https"),
+        "phone" => value
+            .strip_prefix('+')
+            .is_some_and(|d| (7..=15).contains(&d.len()) && d.chars().all(|c| c.is_ascii_digit())),
+        _ => true,
+    };
+    if !ok {
+        return Err(format!("{} 형식이 올바르지 않습니다: {}", f.label, value));
+    }
+    if channel == "matrix" && f.key == "userId" && !(value.starts_with('@') && value.contains(':')) {
+        return Err(format!("Matrix 사용자 ID는 @이름:서버 형식이어야 합니다: {}", value));
+    }
+    Ok(())
+}
+
+/// 입력 필드 정리: 스키마에 없는 키 거부, 마스킹/빈 값은 저장된 값 유지
+fn collect_fields(
+    schema: &ChannelSchema,
+    fields: &HashMap<String, String>,
+    config: &Value,
+    account_id: Option<&str>,
+) -> Result<HashMap<&'static str, String>, String> {
+    if let Some(unknown) = fields.keys().find(|k| !schema.fields.iter().any(|f| f.key == k.as_str())) {
+        return Err(format!("{} 채널에 없는 필드입니다: {}", schema.label, unknown));
+    }
+    let mut pointer = format!("/channels/{}", schema.channel);
+    if let Some(id) = account_id {
+        pointer.push_str(&format!("/accounts/{}", id));
+    }
+    let stored = |key: &str| {
+        config
+            .pointer(&format!("{}/{}", pointer, key))
+            .and_then(|v| v.as_str())
+            .filter(|v| !v.is_empty())
+            .map(|v| v.to_string())
+    };
+
+    let mut values = HashMap::new();
+    for f in &schema.fields {
+        let input = fields
+            .get(f.key)
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty() && *v != MASKED_SECRET);
+        match input {
+            Some(v) if schema.channel == "mattermost" && f.key == "baseUrl" => {
+                values.insert(f.key, crate::mattermost::normalize_base_url(v)?);
+            }
+            Some(v) => {
+                validate_field(schema.channel, f, v)?;
+                values.insert(f.key, v.to_string());
+            }
+            None if f.required && stored(f.key).is_none() => {
+                return Err(format!("{} 입력이 필요합니다", f.label));
+            }
+            None => {}
+        }
+    }
+    Ok(values)
+}
+
+/// 외부 플러그인 채널은 설치 후 활성화
+fn ensure_plugin(schema: &ChannelSchema) -> Result<(), String> {
+    if enable_channel_plugin(schema.channel).is_ok() {
+        return Ok(());
+    }
+    let package = schema
+        .plugin_package
+        .ok_or_else(|| format!("{} 플러그인을 활성화할 수 없습니다", schema.label))?;
+    run_openclaw_command(&["plugins", "install", package])
+        .map_err(|e| format!("{} 플러그인 설치 실패: {}", package, e))?;
+    enable_channel_plugin(schema.channel)
+}
+
+// ===== 로컬 CLI 확인 =====
+
+fn cli_output(program: &str, args: &[&str]) -> Result<String, String> {
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|e| format!("{} 실행 실패: {}", program, e))?;
+    let text = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    if output.status.success() {
+        Ok(text)
+    } else {
+        Err(crate::truncate_message(text.trim(), 200))
+    }
+}
+
+fn verify_signal(values: &HashMap<&str, String>) -> TokenVerification {
+    let cli = values.get("cliPath").map(|s| s.as_str()).unwrap_or("signal-cli");
+    let Some(account) = values.get("account") else {
+        return TokenVerification::new("signal", TokenStatus::InvalidToken, None, "전화번호 입력이 필요합니다");
+    };
+    if let Err(e) = cli_output(cli, &["--version"]) {
+        return TokenVerification::new(
+            "signal",
+            TokenStatus::NetworkError,
+            None,
+            format!("signal-cli를 찾을 수 없습니다: {}", e),
+        );
+    }
+    match cli_output(cli, &["listAccounts"]) {
+        Ok(list) if list.contains(account.as_str()) => {
+            let bot = BotIdentity {
+                id: account.clone(),
+                name: account.clone(),
+                username: None,
+                team: None,
+            };
+            TokenVerification {
+                bot: Some(bot),
+                ..TokenVerification::new("signal", TokenStatus::Valid, None, format!("{} 계정이 연결되어 있습니다", account))
+            }
+        }
+        Ok(_) => TokenVerification::new(
+            "signal",
+            TokenStatus::InvalidToken,
+            None,
+            format!("{} 계정이 signal-cli에 연결되어 있지 않습니다. 기기 연결을 먼저 진행하세요", account),
+        ),
+        Err(e) => TokenVerification::new("signal", TokenStatus::NetworkError, None, e),
+    }
+}
+
+fn verify_imessage(values: &HashMap<&str, String>) -> TokenVerification {
+    if current_platform() != "macos" {
+        return TokenVerification::new("imessage", TokenStatus::NetworkError, None, "iMessage는 macOS에서만 사용할 수 있습니다");
+    }
+    let cli = values.get("cliPath").map(|s| s.as_str()).unwrap_or("imsg");
+    if let Err(e) = cli_output(cli, &["--help"]) {
+        return TokenVerification::new("imessage", TokenStatus::NetworkError, None, format!("imsg를 찾을 수 없습니다: {}", e));
+    }
+    let db_path = values
+        .get("dbPath")
+        .map(|p| p.to_string())
+        .unwrap_or_else(|| "~/Library/Messages/chat.db".to_string());
+    let db_path = match db_path.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir().map(|h| h.join(rest)).unwrap_or_default(),
+        None => std::path::PathBuf::from(&db_path),
+    };
+    match std::fs::File::open(&db_path) {
+        Ok(_) => TokenVerification::new("imessage", TokenStatus::Valid, None, "Messages DB에 접근할 수 있습니다"),
+        Err(e) => TokenVerification::new(
+            "imessage",
+            TokenStatus::InvalidToken,
+            None,
+            format!("Messages DB를 열 수 없습니다 (전체 디스크 접근 권한 필요): {}", e),
+        ),
+    }
+}
+
+async fn verify_setup(
+    schema: &ChannelSchema,
+    values: &HashMap<&'static str, String>,
+    account_id: Option<&str>,
+) -> TokenVerification {
+    let token = secret_field(schema.channel)
+        .and_then(|k| values.get(k))
+        .map(|s| s.as_str());
+    match schema.channel {
+        "signal" => verify_signal(values),
+        "imessage" => verify_imessage(values),
+        "googlechat" => match values.get("serviceAccountFile") {
+            Some(path) => crate::googlechat::verify_key_file(path).await,
+            None => TokenVerification::new(
+                schema.channel,
+                TokenStatus::InvalidToken,
+                None,
+                "서비스 계정 JSON 파일을 선택하세요",
+            ),
+        },
+        "whatsapp" => TokenVerification::new(
+            schema.channel,
+            TokenStatus::NetworkError,
+            None,
+            format!("{}은(는) 별도의 연결 확인을 사용하세요", schema.label),
+        ),
+        channel => {
+            let base_url = values.get("homeserver").or(values.get("baseUrl")).map(|s| s.as_str());
+            channel_api::verify_token(channel, token, None, account_id, base_url).await
+        }
+    }
+}
+
+// ===== Tauri 커맨드 =====
+
+/// 채널 입력 스키마 (channel을 생략하면 지원하는 모든 채널)
+#[tauri::command]
+pub fn get_channel_schema(channel: Option<String>) -> Result<Vec<ChannelSchema>, String> {
+    match channel {
+        Some(ch) => Ok(vec![find_schema(&ch)?]),
+        None => Ok(openclaw::ACCOUNT_CHANNELS.iter().filter_map(|c| schema(c)).collect()),
+    }
+}
+
+/// 채널 접속 정보 확인 (저장 전 입력값 또는 저장된 값)
+#[tauri::command]
+pub async fn verify_channel_setup(
+    channel: String,
+    fields: HashMap<String, String>,
+    account_id: Option<String>,
+) -> Result<TokenVerification, String> {
+    let schema = find_schema(&channel)?;
+    let account_id = normalize_account_id(account_id.as_deref())?;
+    let config = read_existing_config();
+    let mut values = collect_fields(&schema, &fields, &config, account_id.as_deref())?;
+
+    // 입력하지 않은 값은 저장된 설정에서 채움
+    for f in &schema.fields {
+        if values.contains_key(f.key) {
+            continue;
+        }
+        let mut pointer = format!("/channels/{}", channel);
+        if let Some(id) = account_id.as_deref() {
+            pointer.push_str(&format!("/accounts/{}", id));
+        }
+        if let Some(v) = config.pointer(&format!("{}/{}", pointer, f.key)).and_then(|v| v.as_str()) {
+            values.insert(f.key, v.to_string());
+        }
+    }
+    Ok(verify_setup(&schema, &values, account_id.as_deref()).await)
+}
+
+/// 스키마 기반 채널 설정 (플러그인 활성화 + 정책 + 접속 정보 저장)
+/// validate=true면 저장 전에 접속 정보를 확인하고, 실패하면 저장하지 않음 (네트워크 오류는 허용)
+#[allow(clippy::too_many_arguments)]
+#[tauri::command]
+pub async fn configure_channel(
+    channel: String,
+    fields: HashMap<String, String>,
+    dm_policy: String,
+    allow_from: Vec<String>,
+    group_policy: String,
+    group_allow_from: Vec<String>,
+    require_mention: Option<bool>,
+    account_id: Option<String>,
+    validate: Option<bool>,
+) -> Result<(), String> {
+    let schema = find_schema(&channel)?;
+    if !schema.platforms.is_empty() && !schema.platforms.contains(&current_platform()) {
+        return Err(format!("{}은(는) {}에서만 사용할 수 있습니다", schema.label, schema.platforms.join(", ")));
+    }
+    let account_id = normalize_account_id(account_id.as_deref())?;
+    let values = collect_fields(&schema, &fields, &read_existing_config(), account_id.as_deref())?;
+
+    if validate.unwrap_or(false) {
+        let verification = verify_setup(&schema, &values, account_id.as_deref()).await;
+        match verification.status {
+            TokenStatus::Valid => {}
+            TokenStatus::NetworkError => {
+                eprintln!("채널 설정 확인 건너뜀 ({}): {}", channel, verification.message);
+            }
+            _ => return Err(format!("접속 정보가 유효하지 않습니다: {}", verification.message)),
+        }
+    }
+
+    ensure_plugin(&schema)?;
+    // 정책과 접속 정보를 같은 설정 값에 반영해 한 번에 저장
+    let mut config = read_existing_config();
+    openclaw::apply_channel_settings(
+        &mut config,
+        &channel,
+        "",
+        &dm_policy,
+        &allow_from,
+        &group_policy,
+        &group_allow_from,
+        require_mention.unwrap_or(true),
+        account_id.as_deref(),
+    )?;
+    let section = channel_section_mut(&mut config, &channel, account_id.as_deref())?;
+    for (key, value) in values {
+        set_nested_value(section, &[key], json!(value));
+    }
+    write_config(&config)
+}
+
+fn is_current_signal_link(generation: u64) -> bool {
+    SIGNAL_LINK_GENERATION.load(Ordering::SeqCst) == generation
+}
+
+/// 진행 중인 프로세스가 이 시도의 것이면 종료 후 회수
+fn stop_signal_link(generation: u64) {
+    if let Ok(mut guard) = SIGNAL_LINK.lock() {
+        if is_current_signal_link(generation) {
+            if let Some(mut child) = guard.take() {
+                let _ = child.kill();
+                let _ = child.wait();
+            }
+        }
+    }
+}
+
+/// Signal 기기 연결 시작: signal-cli link가 출력한 sgnl:// URI 반환 (QR로 표시)
+/// 연결 완료/실패는 "signal-link" 이벤트로 전달
+#[tauri::command]
+pub async fn start_signal_link(
+    app: tauri::AppHandle,
+    cli_path: Option<String>,
+    device_name: Option<String>,
+) -> Result<String, String> {
+    // 이전 시도의 대기 스레드가 새 프로세스를 건드리지 않도록 번호를 먼저 올림
+    let generation = SIGNAL_LINK_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    cancel_signal_link()?;
+
+    let cli = cli_path.filter(|p| !p.trim().is_empty()).unwrap_or_else(|| "signal-cli".to_string());
+    let name = device_name.unwrap_or_else(|| "OpenClaw".to_string());
+    let mut child = Command::new(&cli)
+        .args(["link", "-n", &name])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("signal-cli 실행 실패 ({}): {}", cli, e))?;
+    let stdout = child.stdout.take().ok_or("signal-cli 출력을 읽을 수 없습니다")?;
+    let stderr = child.stderr.take().ok_or("signal-cli 오류 출력을 읽을 수 없습니다")?;
+
+    // stderr: 파이프가 차서 멈추지 않도록 계속 읽고 마지막 줄은 실패 메시지로 사용
+    let last_error: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
+    {
+        let last_error = last_error.clone();
+        std::thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                let line = line.trim();
+                if !line.is_empty() {
+                    if let Ok(mut last) = last_error.lock() {
+                        *last = Some(line.to_string());
+                    }
+                }
+            }
+        });
+    }
+
+    // stdout: 연결 URI (sgnl://linkdevice?... 또는 구버전 tsdevice:/?...) 전달 후에도 끝까지 읽음
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    std::thread::spawn(move || {
+        let mut tx = Some(tx);
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            let line = line.trim();
+            if line.starts_with("sgnl://") || line.starts_with("tsdevice:") {
+                if let Some(tx) = tx.take() {
+                    let _ = tx.send(line.to_string());
+                }
+            }
+        }
+    });
+
+    {
+        let mut guard = SIGNAL_LINK.lock().map_err(|e| e.to_string())?;
+        if !is_current_signal_link(generation) {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err("새 Signal 연결 시도로 대체되었습니다".to_string());
+        }
+        *guard = Some(child);
+    }
+
+    let last_error_message = move |fallback: &str| {
+        last_error
+            .lock()
+            .ok()
+            .and_then(|m| m.clone())
+            .unwrap_or_else(|| fallback.to_string())
+    };
+    let uri = match tokio::time::timeout(SIGNAL_URI_TIMEOUT, rx).await {
+        Ok(Ok(uri)) => uri,
+        _ => {
+            stop_signal_link(generation);
+            return Err(format!(
+                "signal-cli가 연결 URI를 출력하지 않았습니다: {}",
+                last_error_message("응답 없음")
+            ));
+        }
+    };
+
+    // 연결 완료 대기 (QR 스캔 후 signal-cli가 종료됨)
+    std::thread::spawn(move || {
+        let started = std::time::Instant::now();
+        loop {
+            std::thread::sleep(Duration::from_millis(500));
+            let mut guard = match SIGNAL_LINK.lock() {
+                Ok(g) => g,
+                Err(_) => return,
+            };
+            if !is_current_signal_link(generation) {
+                // 새 연결 시도로 대체됨
+                return;
+            }
+            let Some(child) = guard.as_mut() else {
+                // 취소됨
+                let _ = app.emit(SIGNAL_LINK_EVENT, json!({ "status": "cancelled" }));
+                return;
+            };
+            let status = match child.try_wait() {
+                Ok(Some(status)) => Some(status.success()),
+                Ok(None) if started.elapsed() > SIGNAL_LINK_TIMEOUT => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    Some(false)
+                }
+                Ok(None) => None,
+                Err(_) => Some(false),
+            };
+            if let Some(success) = status {
+                *guard = None;
+                drop(guard);
+                let payload = if success {
+                    json!({ "status": "linked" })
+                } else {
+                    let message = last_error_message("Signal 기기 연결에 실패했거나 시간이 초과되었습니다");
+                    json!({ "status": "error", "message": message })
+                };
+                let _ = app.emit(SIGNAL_LINK_EVENT, payload);
+                return;
+            }
+        }
+    });
+
+    Ok(uri)
+}
+
+/// 진행 중인 Signal 기기 연결 취소
+#[tauri::command]
+pub fn cancel_signal_link() -> Result<(), String> {
+    if let Some(mut child) = SIGNAL_LINK.lock().map_err(|e| e.to_string())?.take() {
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    fn field_of(channel: &'static str, key: &str) -> ChannelField {
+        schema(channel).unwrap().fields.into_iter().find(|f| f.key == key).unwrap()
+    }
+
+    #[test]
+    fn field_values_are_checked_by_kind() {
+        let homeserver = field_of("matrix", "homeserver");
+        assert!(validate_field("matrix", &homeserver, "https://matrix.org").is_ok());
+        assert!(validate_field("matrix", &homeserver, "ftp://matrix.org").is_err());
+        assert!(validate_field("matrix", &homeserver, "matrix.org").is_err());
+
+        let account = field_of("signal", "account");
+        assert!(validate_field("signal", &account, "+821012345678").is_ok());
+        assert!(validate_field("signal", &account, "01012345678").is_err());
+        assert!(validate_field("signal", &account, "+82-10-1234").is_err());
+
+        let user_id = field_of("matrix", "userId");
+        assert!(validate_field("matrix", &user_id, "@bot:matrix.org").is_ok());
+        assert!(validate_field("matrix", &user_id, "bot").is_err());
+    }
+
+    #[test]
+    fn collect_fields_rejects_unknown_keys_and_requires_missing_values() {
+        let schema = schema("line").unwrap();
+        let empty = json!({});
+        assert!(collect_fields(&schema, &fields(&[("token", "x")]), &empty, None).is_err());
+        assert!(collect_fields(&schema, &fields(&[("channelAccessToken", "abc")]), &empty, None).is_err());
+
+        let values = collect_fields(
+            &schema,
+            &fields(&[("channelAccessToken", " abc "), ("channelSecret", "def")]),
+            &empty,
+            None,
+        )
+        .unwrap();
+        assert_eq!(values.get("channelAccessToken").map(String::as_str), Some("abc"));
+        assert_eq!(values.get("channelSecret").map(String::as_str), Some("def"));
+    }
+
+    #[test]
+    fn collect_fields_keeps_stored_secrets_for_masked_input() {
+        let schema = schema("line").unwrap();
+        let config = json!({
+            "channels": { "line": { "accounts": { "work": { "channelAccessToken": "abc", "channelSecret": "def" } } } }
+        });
+        let input = fields(&[("channelAccessToken", MASKED_SECRET), ("channelSecret", "")]);
+        let values = collect_fields(&schema, &input, &config, Some("work")).unwrap();
+        assert!(values.is_empty());
+        // 다른 계정에는 저장된 값이 없으므로 입력 필요
+        assert!(collect_fields(&schema, &input, &config, None).is_err());
+    }
+
+    #[test]
+    fn mattermost_base_url_is_normalized() {
+        let schema = schema("mattermost").unwrap();
+        let values = collect_fields(
+            &schema,
+            &fields(&[("baseUrl", "chat.example.com"), ("botToken", "tok")]),
+            &json!({}),
+            None,
+        )
+        .unwrap();
+        assert!(values["baseUrl"].starts_with("https://chat.example.com"));
+    }
+
+    #[test]
+    fn policy_and_credentials_land_in_one_config_value() {
+        let mut config = json!({ "gateway": {}, "agents": {}, "tools": {} });
+        openclaw::apply_channel_settings(
+            &mut config,
+            "line",
+            "",
+            "pairing",
+            &[],
+            "allowlist",
+            &[],
+            true,
+            Some("work"),
+        )
+        .unwrap();
+        let section = channel_section_mut(&mut config, "line", Some("work")).unwrap();
+        set_nested_value(section, &["channelAccessToken"], json!("abc"));
+
+        let account = config.pointer("/channels/line/accounts/work").unwrap();
+        assert_eq!(account["channelAccessToken"], json!("abc"));
+        assert_eq!(account["dmPolicy"], json!("pairing"));
+        assert_eq!(account["groupPolicy"], json!("allowlist"));
+    }
+}
@@ -0,0 +1,393 @@
+// 채널 테스트 메시지 (종단 간 전달 확인)
+// 토큰 → 게이트웨이 → 모델 → 응답 경로를 단계(hop)별로 확인하고 지연 시간을 기록합니다.
+// 1. gateway: 게이트웨이 포트 접속
+// 2. channel: `openclaw message send`로 허용 목록의 상대에게 메시지 전달 (플랫폼 수신 확인까지 대기)
+// 3. model:   `openclaw agent --deliver`로 모델 응답을 생성해 같은 상대에게 전달
+// 테스트 중 cache-trace.jsonl에 기록된 오류도 함께 보고합니다.
+
+use serde::Serialize;
+use serde_json::Value;
+use std::io::{Read, Seek, SeekFrom};
+use std::process::Stdio;
+use std::time::{Duration, Instant};
+
+use crate::openclaw::{get_gateway_port, get_openclaw_dir, normalize_account_id, ACCOUNT_CHANNELS};
+use crate::pairing::same_peer;
+
+const GATEWAY_CONNECT_TIMEOUT: Duration = Duration::from_secs(2);
+const SEND_TIMEOUT: Duration = Duration::from_secs(60);
+/// 모델 응답은 느릴 수 있으므로 넉넉하게
+const AGENT_TIMEOUT: Duration = Duration::from_secs(180);
+const DEFAULT_TEST_TEXT: &str = "moldClaw 테스트 메시지입니다. 이 메시지가 보이면 채널 연결이 정상입니다.";
+const DEFAULT_MODEL_PROMPT: &str = "연결 테스트입니다. '모델 응답 정상'이라고 한 줄로만 답하세요.";
+/// 보고할 로그 오류 최대 개수
+const MAX_TRACE_ERRORS: usize = 20;
+/// 로그 파일에서 읽을 최대 크기 (끝에서부터)
+const MAX_TRACE_BYTES: u64 = 2 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HopResult {
+    /// "gateway" | "channel" | "model"
+    pub hop: &'static str,
+    /// "ok" | "failed" | "skipped"
+    pub status: &'static str,
+    pub latency_ms: Option<u64>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceError {
+    pub timestamp: String,
+    pub stage: Option<String>,
+    pub session_key: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestMessageReport {
+    pub channel: String,
+    pub peer: String,
+    pub account_id: Option<String>,
+    /// 실행한 모든 단계가 성공했는지
+    pub ok: bool,
+    pub hops: Vec<HopResult>,
+    /// 플랫폼이 돌려준 메시지 ID (전달 확인)
+    pub message_id: Option<String>,
+    /// 모델 응답 미리보기
+    pub reply_preview: Option<String>,
+    pub trace_errors: Vec<TraceError>,
+    pub total_ms: u64,
+}
+
+impl HopResult {
+    fn ok(hop: &'static str, latency: Duration, message: impl Into<String>) -> Self {
+        HopResult { hop, status: "ok", latency_ms: Some(latency.as_millis() as u64), message: message.into() }
+    }
+
+    fn failed(hop: &'static str, latency: Option<Duration>, message: impl Into<String>) -> Self {
+        HopResult {
+            hop,
+            status: "failed",
+            latency_ms: latency.map(|l| l.as_millis() as u64),
+            message: message.into(),
+        }
+    }
+
+    fn skipped(hop: &'static str, message: impl Into<String>) -> Self {
+        HopResult { hop, status: "skipped", latency_ms: None, message: message.into() }
+    }
+}
+
+/// openclaw CLI 명령 (콘솔 창 없이, 출력은 파이프)
+/// Windows는 cmd /C로 한 줄을 만들지 않고 openclaw.cmd를 직접 실행해
+/// 표준 라이브러리가 인자마다 배치 파일 규칙으로 따옴표/이스케이프를 처리하게 함
+/// (메시지 본문의 공백, &, |, ^, " 등이 그대로 전달되고 안전하게 처리할 수 없으면 실행 거부)
+fn cli_command(args: &[String]) -> tokio::process::Command {
+    #[cfg(windows)]
+    let cmd = {
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        let mut cmd = tokio::process::Command::new("openclaw.cmd");
+        cmd.args(args).creation_flags(CREATE_NO_WINDOW);
+        cmd
+    };
+
+    #[cfg(target_os = "macos")]
+    let cmd = {
+        let mut cmd = tokio::process::Command::new("openclaw");
+        cmd.args(args).env("PATH", crate::openclaw::get_macos_path());
+        cmd
+    };
+
+    #[cfg(all(not(windows), not(target_os = "macos")))]
+    let cmd = {
+        let mut cmd = tokio::process::Command::new("openclaw");
+        cmd.args(args);
+        cmd
+    };
+
+    cmd
+}
+
+/// CLI 실행 (시간 초과 시 프로세스 종료)
+async fn run_cli_timed(args: Vec<String>, timeout: Duration) -> (Result<String, String>, Duration) {
+    let started = Instant::now();
+    let child = cli_command(&args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        // 시간 초과로 future가 버려지면 프로세스도 종료 (회수는 tokio가 처리)
+        .kill_on_drop(true)
+        .spawn();
+    let result = match child {
+        Err(e) => Err(format!("openclaw 실행 실패: {}", e)),
+        Ok(child) => match tokio::time::timeout(timeout, child.wait_with_output()).await {
+            Ok(Ok(output)) if output.status.success() => {
+                Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+            }
+            Ok(Ok(output)) => {
+                let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+                let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+                Err(if stderr.is_empty() { stdout } else { stderr })
+            }
+            Ok(Err(e)) => Err(format!("실행 오류: {}", e)),
+            Err(_) => Err(format!("{}초 안에 응답이 없어 중단했습니다", timeout.as_secs())),
+        },
+    };
+    (result, started.elapsed())
+}
+
+/// CLI 출력에서 JSON 부분만 파싱 (앞쪽 안내 문구 무시)
+fn parse_json_output(output: &str) -> Option<Value> {
+    let start = output.find('{')?;
+    serde_json::from_str(&output[start..]).ok()
+}
+
+fn first_str(v: &Value, pointers: &[&str]) -> Option<String> {
+    pointers.iter().find_map(|p| {
+        v.pointer(p).and_then(|s| match s {
+            Value::String(s) if !s.is_empty() => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        })
+    })
+}
+
+/// 로그 파일 끝부분만 읽기 (최대 max_bytes, 잘린 첫 줄은 버림)
+fn read_tail(path: &std::path::Path, max_bytes: u64) -> Option<String> {
+    let mut file = std::fs::File::open(path).ok()?;
+    let len = file.metadata().ok()?.len();
+    let start = len.saturating_sub(max_bytes);
+    file.seek(SeekFrom::Start(start)).ok()?;
+    let mut buf = Vec::new();
+    file.read_to_end(&mut buf).ok()?;
+    let content = String::from_utf8_lossy(&buf).into_owned();
+    if start == 0 {
+        return Some(content);
+    }
+    Some(content.split_once('\n').map(|(_, rest)| rest.to_string()).unwrap_or_default())
+}
+
+/// 테스트 시작 이후 cache-trace.jsonl에 기록된 오류
+fn trace_errors_since(since: chrono::DateTime<chrono::Utc>) -> Vec<TraceError> {
+    let log_path = get_openclaw_dir().join("logs").join("cache-trace.jsonl");
+    let Some(content) = read_tail(&log_path, MAX_TRACE_BYTES) else {
+        return Vec::new();
+    };
+    trace_errors_in(&content, since)
+}
+
+/// 트레이스 로그 내용에서 since 이후의 오류 추출
+fn trace_errors_in(content: &str, since: chrono::DateTime<chrono::Utc>) -> Vec<TraceError> {
+    let mut errors = Vec::new();
+    // 최신 줄부터 읽다가 테스트 시작 전 기록을 만나면 중단 (파싱할 수 없거나 ts가 없는 줄은 건너뜀)
+    for line in content.lines().rev() {
+        let Ok(entry) = serde_json::from_str::<Value>(line) else {
+            continue;
+        };
+        let Some(timestamp) = entry.get("ts").and_then(|v| v.as_str()).map(String::from) else {
+            continue;
+        };
+        let Ok(at) = chrono::DateTime::parse_from_rfc3339(&timestamp) else {
+            continue;
+        };
+        if at < since {
+            break;
+        }
+        let stage = entry.get("stage").and_then(|v| v.as_str()).map(String::from);
+        let Some(message) = entry
+            .get("errorMessage")
+            .and_then(|v| v.as_str())
+            .map(String::from)
+            .or_else(|| stage.as_ref().filter(|s| s.contains("error")).cloned())
+        else {
+            continue;
+        };
+        errors.push(TraceError {
+            timestamp,
+            stage,
+            session_key: entry.get("sessionKey").and_then(|v| v.as_str()).map(String::from),
+            message: crate::truncate_message(&message, 300),
+        });
+        if errors.len() >= MAX_TRACE_ERRORS {
+            break;
+        }
+    }
+    errors.reverse();
+    errors
+}
+
+/// 상대가 허용 목록에 있는지 (allowFrom에 "*"가 있으면 모두 허용)
+fn ensure_allowlisted(channel: &str, peer: &str, account_id: Option<&str>) -> Result<(), String> {
+    let entries = crate::pairing::get_allowlist(channel.to_string(), account_id.map(String::from))?;
+    if entries.iter().any(|e| e.id == "*" || same_peer(channel, &e.id, peer)) {
+        Ok(())
+    } else {
+        Err(format!(
+            "{}은(는) {} 허용 목록에 없습니다. 허용 목록에 추가한 상대에게만 테스트 메시지를 보낼 수 있습니다",
+            peer, channel
+        ))
+    }
+}
+
+/// 테스트 메시지 전송 + 단계별 확인
+/// include_model=false면 모델 단계를 건너뛰고 채널 전달만 확인
+#[tauri::command]
+pub async fn send_test_message(
+    channel: String,
+    peer: String,
+    account_id: Option<String>,
+    text: Option<String>,
+    include_model: Option<bool>,
+) -> Result<TestMessageReport, String> {
+    if !ACCOUNT_CHANNELS.contains(&channel.as_str()) {
+        return Err(format!("지원하지 않는 채널입니다: {}", channel));
+    }
+    let peer = peer.trim().to_string();
+    if peer.is_empty() {
+        return Err("받는 사람을 선택하세요".to_string());
+    }
+    let account_id = normalize_account_id(account_id.as_deref())?;
+    ensure_allowlisted(&channel, &peer, account_id.as_deref())?;
+
+    let started = Instant::now();
+    let since = chrono::Utc::now();
+    let mut hops = Vec::new();
+    let mut message_id = None;
+    let mut reply_preview = None;
+
+    // 1. 게이트웨이
+    let port = get_gateway_port();
+    let connect = tokio::time::timeout(
+        GATEWAY_CONNECT_TIMEOUT,
+        tokio::net::TcpStream::connect(("127.0.0.1", port)),
+    )
+    .await;
+    let gateway_up = matches!(connect, Ok(Ok(_)));
+    hops.push(if gateway_up {
+        HopResult::ok("gateway", started.elapsed(), format!("게이트웨이 응답 (포트 {})", port))
+    } else {
+        HopResult::failed("gateway", None, format!("게이트웨이가 실행 중이 아닙니다 (포트 {})", port))
+    });
+
+    // 2. 채널 전달
+    let mut delivered = false;
+    if gateway_up {
+        let mut args: Vec<String> = ["message", "send", "--channel", &channel, "--target", &peer, "--json"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let text = text
+            .filter(|t| !t.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_TEST_TEXT.to_string());
+        args.extend(["--message".to_string(), text]);
+        if let Some(id) = &account_id {
+            args.extend(["--account".to_string(), id.clone()]);
+        }
+        let (result, latency) = run_cli_timed(args, SEND_TIMEOUT).await;
+        hops.push(match result {
+            Ok(output) => {
+                delivered = true;
+                let json = parse_json_output(&output).unwrap_or(Value::Null);
+                message_id = first_str(&json, &["/messageId", "/result/messageId", "/payload/messageId", "/id"]);
+                let message = match &message_id {
+                    Some(id) => format!("전달 확인 (메시지 ID {})", id),
+                    None => "전달 요청 완료".to_string(),
+                };
+                HopResult::ok("channel", latency, message)
+            }
+            Err(e) => HopResult::failed("channel", Some(latency), e),
+        });
+    } else {
+        hops.push(HopResult::skipped("channel", "게이트웨이가 없어 건너뜀"));
+    }
+
+    // 3. 모델 응답 → 같은 상대에게 전달
+    if !include_model.unwrap_or(true) {
+        hops.push(HopResult::skipped("model", "모델 단계 제외"));
+    } else if delivered {
+        let mut args: Vec<String> = ["agent", "--channel", &channel, "--to", &peer, "--deliver", "--json"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        args.extend(["--message".to_string(), DEFAULT_MODEL_PROMPT.to_string()]);
+        if let Some(id) = &account_id {
+            args.extend(["--reply-account".to_string(), id.clone()]);
+        }
+        let (result, latency) = run_cli_timed(args, AGENT_TIMEOUT).await;
+        hops.push(match result {
+            Ok(output) => {
+                let json = parse_json_output(&output).unwrap_or(Value::Null);
+                reply_preview = first_str(&json, &["/result/payloads/0/text", "/payloads/0/text", "/reply", "/text"])
+                    .map(|t| crate::truncate_message(&t, 200));
+                HopResult::ok("model", latency, "모델 응답 생성 및 전달 완료")
+            }
+            Err(e) => HopResult::failed("model", Some(latency), e),
+        });
+    } else {
+        hops.push(HopResult::skipped("model", "채널 전달이 실패해 건너뜀"));
+    }
+
+    let trace_errors = trace_errors_since(since);
+    Ok(TestMessageReport {
+        ok: hops.iter().all(|h| h.status != "failed"),
+        channel,
+        peer,
+        account_id,
+        hops,
+        message_id,
+        reply_preview,
+        trace_errors,
+        total_ms: started.elapsed().as_millis() as u64,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_json_output_skips_leading_notices() {
+        let output = "Config warnings: plugin foo disabled\n{\"ok\":true,\"messageId\":\"42\"}";
+        let v = parse_json_output(output).unwrap();
+        assert_eq!(first_str(&v, &["/messageId"]).as_deref(), Some("42"));
+        assert_eq!(parse_json_output("no json here"), None);
+        assert_eq!(parse_json_output("{ broken"), None);
+        assert_eq!(first_str(&serde_json::json!({ "id": 7, "empty": "" }), &["/empty", "/id"]).as_deref(), Some("7"));
+    }
+
+    #[test]
+    fn read_tail_drops_the_cut_first_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("trace.jsonl");
+        std::fs::write(&path, "first line\nsecond\nthird\n").unwrap();
+        assert_eq!(read_tail(&path, 1024).as_deref(), Some("first line\nsecond\nthird\n"));
+        // 끝에서 10바이트: "ond\nthird\n"에서 잘린 "ond" 줄은 버림
+        assert_eq!(read_tail(&path, 10).as_deref(), Some("third\n"));
+        assert_eq!(read_tail(&dir.path().join("missing.jsonl"), 10), None);
+    }
+
+    #[test]
+    fn trace_errors_stop_at_entries_before_the_test() {
+        let since = chrono::DateTime::parse_from_rfc3339("2026-01-01T00:00:10Z").unwrap().with_timezone(&chrono::Utc);
+        let content = [
+            r#"{"ts":"2026-01-01T00:00:05Z","stage":"model:error","errorMessage":"old failure"}"#,
+            r#"{"ts":"2026-01-01T00:00:11Z","stage":"model:request"}"#,
+            "not json",
+            r#"{"stage":"model:error","errorMessage":"no timestamp"}"#,
+            r#"{"ts":"2026-01-01T00:00:12Z","stage":"model:error","sessionKey":"agent:main:main","errorMessage":"401 invalid x-api-key"}"#,
+            r#"{"ts":"2026-01-01T00:00:13Z","stage":"delivery:error"}"#,
+        ]
+        .join("\n");
+
+        let errors = trace_errors_in(&content, since);
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].message, "401 invalid x-api-key");
+        assert_eq!(errors[0].session_key.as_deref(), Some("agent:main:main"));
+        // errorMessage가 없으면 stage 이름을 메시지로 사용
+        assert_eq!(errors[1].message, "delivery:error");
+        assert!(trace_errors_in("", since).is_empty());
+    }
+}
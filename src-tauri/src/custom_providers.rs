@@ -0,0 +1,362 @@
+// 사용자 정의 모델 프로바이더
+// OpenAI 호환 서버나 로컬 모델 서버(Ollama, LM Studio, vLLM)를
+// models.providers.<name> 에 직접 등록합니다.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+use crate::model_catalog;
+use crate::openclaw::{
+    model_refs_in_use, pointer_escape, read_existing_config, set_nested_value, upsert_provider_model, write_config,
+};
+
+/// 로컬 서버 탐색 타임아웃 (서버가 없으면 연결 거부로 즉시 끝남)
+const DISCOVERY_TIMEOUT: Duration = Duration::from_millis(1500);
+
+/// OpenClaw가 지원하는 API 형식
+const SUPPORTED_APIS: &[&str] = &[
+    "openai-completions",
+    "openai-responses",
+    "anthropic-messages",
+    "google-generative-ai",
+];
+
+/// 키가 필요 없는 로컬 서버용 자리표시 키 (OpenAI SDK는 빈 키를 거부)
+const LOCAL_PLACEHOLDER_KEY: &str = "local";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomModelInput {
+    pub id: String,
+    pub name: Option<String>,
+    pub context_window: Option<u64>,
+    pub max_tokens: Option<u64>,
+    pub reasoning: Option<bool>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomProvider {
+    pub name: String,
+    pub base_url: String,
+    pub api: String,
+    pub has_api_key: bool,
+    pub models: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscoveredServer {
+    /// ollama | lmstudio | vllm
+    pub kind: String,
+    /// 등록 시 사용할 기본 프로바이더 이름
+    pub suggested_name: String,
+    pub base_url: String,
+    pub api: String,
+    pub models: Vec<String>,
+}
+
+/// 프로바이더 이름 검증 (소문자, 숫자, -, _)
+fn validate_name(name: &str) -> Result<(), String> {
+    let valid = !name.is_empty()
+        && name.len() <= 64
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+    if !valid {
+        return Err(format!(
+            "프로바이더 이름은 영문 소문자, 숫자, -, _ 만 사용할 수 있습니다: {}",
+            name
+        ));
+    }
+    if model_catalog::find_provider(name).is_some() {
+        return Err(format!("{}은(는) 기본 제공 프로바이더 이름입니다. 다른 이름을 사용하세요", name));
+    }
+    Ok(())
+}
+
+fn validate_base_url(base_url: &str) -> Result<String, String> {
+    let url = reqwest::Url::parse(base_url.trim())
+        .map_err(|e| format!("잘못된 URL입니다 ({}): {}", base_url, e))?;
+    if url.scheme() != "http" && url.scheme() != "https" {
+        return Err(format!("http 또는 https URL이어야 합니다: {}", base_url));
+    }
+    Ok(url.as_str().trim_end_matches('/').to_string())
+}
+
+fn model_entry(input: &CustomModelInput) -> Value {
+    json!({
+        "id": input.id,
+        "name": input.name.clone().unwrap_or_else(|| input.id.clone()),
+        "reasoning": input.reasoning.unwrap_or(false),
+        "input": ["text"],
+        "cost": { "input": 0, "output": 0, "cacheRead": 0, "cacheWrite": 0 },
+        "contextWindow": input.context_window.unwrap_or(32768),
+        "maxTokens": input.max_tokens.unwrap_or(8192)
+    })
+}
+
+/// 카탈로그에 없는 (사용자 정의) 프로바이더 목록
+pub fn list_custom() -> Vec<CustomProvider> {
+    let config = read_existing_config();
+    let Some(providers) = config.pointer("/models/providers").and_then(|p| p.as_object()) else {
+        return vec![];
+    };
+
+    providers
+        .iter()
+        .filter(|(name, _)| model_catalog::find_provider(name).is_none())
+        .map(|(name, p)| {
+            let str_field = |k: &str| p.get(k).and_then(|v| v.as_str()).unwrap_or("").to_string();
+            CustomProvider {
+                name: name.clone(),
+                base_url: str_field("baseUrl"),
+                api: str_field("api"),
+                has_api_key: p
+                    .get("apiKey")
+                    .and_then(|v| v.as_str())
+                    .is_some_and(|k| !k.is_empty() && k != LOCAL_PLACEHOLDER_KEY),
+                models: p
+                    .get("models")
+                    .and_then(|m| m.as_array())
+                    .map(|list| {
+                        list.iter()
+                            .filter_map(|m| m.get("id").and_then(|v| v.as_str()).map(|s| s.to_string()))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+            }
+        })
+        .collect()
+}
+
+// ===== 로컬 서버 탐색 =====
+
+async fn get_json(client: &reqwest::Client, url: &str) -> Option<Value> {
+    let response = client.get(url).send().await.ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+    response.json().await.ok()
+}
+
+fn openai_model_ids(body: &Value) -> Vec<String> {
+    body.get("data")
+        .and_then(|d| d.as_array())
+        .map(|list| {
+            list.iter()
+                .filter_map(|m| m.get("id").and_then(|v| v.as_str()).map(|s| s.to_string()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+async fn discover_ollama(client: &reqwest::Client) -> Option<DiscoveredServer> {
+    let body = get_json(client, "http://127.0.0.1:11434/api/tags").await?;
+    let models = body
+        .get("models")?
+        .as_array()?
+        .iter()
+        .filter_map(|m| m.get("name").and_then(|v| v.as_str()).map(|s| s.to_string()))
+        .collect();
+    Some(DiscoveredServer {
+        kind: "ollama".to_string(),
+        suggested_name: "ollama".to_string(),
+        // Ollama OpenAI 호환 엔드포인트
+        base_url: "http://127.0.0.1:11434/v1".to_string(),
+        api: "openai-completions".to_string(),
+        models,
+    })
+}
+
+async fn discover_openai_compatible(
+    client: &reqwest::Client,
+    kind: &str,
+    base_url: &str,
+) -> Option<DiscoveredServer> {
+    let body = get_json(client, &format!("{}/models", base_url)).await?;
+    Some(DiscoveredServer {
+        kind: kind.to_string(),
+        suggested_name: kind.to_string(),
+        base_url: base_url.to_string(),
+        api: "openai-completions".to_string(),
+        models: openai_model_ids(&body),
+    })
+}
+
+// ===== Tauri 커맨드 =====
+
+/// 사용자 정의 프로바이더 등록/수정 (기존 모델 목록은 유지, 같은 id만 갱신)
+#[tauri::command]
+pub fn add_custom_provider(
+    name: String,
+    base_url: String,
+    api: String,
+    api_key: Option<String>,
+    models: Vec<CustomModelInput>,
+    set_primary: Option<bool>,
+) -> Result<(), String> {
+    let name = name.trim().to_string();
+    validate_name(&name)?;
+    let base_url = validate_base_url(&base_url)?;
+    if !SUPPORTED_APIS.contains(&api.as_str()) {
+        return Err(format!(
+            "지원하지 않는 API 형식입니다: {} (지원: {})",
+            api,
+            SUPPORTED_APIS.join(", ")
+        ));
+    }
+    if models.iter().any(|m| m.id.trim().is_empty()) {
+        return Err("모델 ID가 비어 있습니다".to_string());
+    }
+
+    let mut config = read_existing_config();
+    set_nested_value(&mut config, &["models", "providers", &name, "baseUrl"], json!(base_url));
+    set_nested_value(&mut config, &["models", "providers", &name, "api"], json!(api));
+
+    match api_key.as_deref().map(str::trim).filter(|k| !k.is_empty()) {
+        Some(key) => {
+            set_nested_value(&mut config, &["models", "providers", &name, "apiKey"], json!(key));
+        }
+        None => {
+            // 키를 비워두면 기존 키 유지, 처음 등록이면 자리표시 키 사용
+            if config.pointer(&format!("/models/providers/{}/apiKey", pointer_escape(&name))).is_none() {
+                set_nested_value(
+                    &mut config,
+                    &["models", "providers", &name, "apiKey"],
+                    json!(LOCAL_PLACEHOLDER_KEY),
+                );
+            }
+        }
+    }
+
+    if config.pointer(&format!("/models/providers/{}/models", pointer_escape(&name))).is_none() {
+        set_nested_value(&mut config, &["models", "providers", &name, "models"], json!([]));
+    }
+    for model in &models {
+        upsert_provider_model(&mut config, &name, model_entry(model));
+    }
+
+    if set_primary.unwrap_or(false) {
+        let first = models.first().ok_or("기본 모델로 설정할 모델이 없습니다")?;
+        set_nested_value(
+            &mut config,
+            &["agents", "defaults", "model", "primary"],
+            json!(format!("{}/{}", name, first.id)),
+        );
+    }
+
+    write_config(&config)
+}
+
+/// 사용자 정의 프로바이더 목록
+#[tauri::command]
+pub fn list_custom_providers() -> Vec<CustomProvider> {
+    list_custom()
+}
+
+/// 사용자 정의 프로바이더 삭제 (에이전트 설정에서 사용 중이면 거부)
+#[tauri::command]
+pub fn remove_custom_provider(name: String) -> Result<(), String> {
+    if model_catalog::find_provider(&name).is_some() {
+        return Err(format!("{}은(는) 기본 제공 프로바이더입니다", name));
+    }
+    let mut config = read_existing_config();
+
+    if model_refs_in_use(&config)
+        .iter()
+        .any(|r| r.split_once('/').is_some_and(|(provider, _)| provider == name))
+    {
+        return Err(format!("{} 프로바이더의 모델이 에이전트 설정에서 사용 중입니다", name));
+    }
+
+    if let Some(providers) = config
+        .pointer_mut("/models/providers")
+        .and_then(|p| p.as_object_mut())
+    {
+        providers.remove(&name);
+    }
+    write_config(&config)
+}
+
+/// 로컬 모델 서버 탐색 (Ollama :11434, LM Studio :1234, vLLM :8000)
+#[tauri::command]
+pub async fn discover_local_providers() -> Result<Vec<DiscoveredServer>, String> {
+    let client = reqwest::Client::builder()
+        .timeout(DISCOVERY_TIMEOUT)
+        .build()
+        .map_err(|e| format!("HTTP 클라이언트 생성 실패: {}", e))?;
+
+    let (ollama, lmstudio, vllm) = tokio::join!(
+        discover_ollama(&client),
+        discover_openai_compatible(&client, "lmstudio", "http://127.0.0.1:1234/v1"),
+        discover_openai_compatible(&client, "vllm", "http://127.0.0.1:8000/v1"),
+    );
+    Ok([ollama, lmstudio, vllm].into_iter().flatten().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn provider_names_are_restricted() {
+        assert!(validate_name("my-vllm_2").is_ok());
+        assert!(validate_name("").is_err());
+        assert!(validate_name("My Server").is_err());
+        assert!(validate_name("a/b").is_err());
+        assert!(validate_name(&"x".repeat(65)).is_err());
+        // 기본 제공 프로바이더 이름은 사용할 수 없음
+        assert!(validate_name("openai").is_err());
+    }
+
+    #[test]
+    fn base_url_is_normalized() {
+        assert_eq!(validate_base_url(" http://127.0.0.1:8000/v1/ ").unwrap(), "http://127.0.0.1:8000/v1");
+        assert_eq!(validate_base_url("https://llm.example.com").unwrap(), "https://llm.example.com");
+        assert!(validate_base_url("127.0.0.1:8000").is_err());
+        assert!(validate_base_url("ftp://llm.example.com").is_err());
+        assert!(validate_base_url("not a url").is_err());
+    }
+
+    #[test]
+    fn model_entry_defaults() {
+        let entry = model_entry(&CustomModelInput {
+            id: "qwen3:8b".to_string(),
+            name: None,
+            context_window: None,
+            max_tokens: Some(4096),
+            reasoning: Some(true),
+        });
+        assert_eq!(entry["name"], "qwen3:8b");
+        assert_eq!(entry["reasoning"], true);
+        assert_eq!(entry["contextWindow"], 32768);
+        assert_eq!(entry["maxTokens"], 4096);
+        assert_eq!(entry["cost"]["input"], 0);
+    }
+
+    #[tokio::test]
+    async fn discovers_openai_compatible_server() {
+        let mut server = mockito::Server::new_async().await;
+        let models = server
+            .mock("GET", "/v1/models")
+            .with_status(200)
+            .with_body(r#"{"object":"list","data":[{"id":"qwen2.5-7b-instruct"},{"id":"llama-3.1-8b"},{"object":"model"}]}"#)
+            .create_async()
+            .await;
+        let client = reqwest::Client::new();
+        let base_url = format!("{}/v1", server.url());
+
+        let found = discover_openai_compatible(&client, "lmstudio", &base_url).await.unwrap();
+        assert_eq!(found.kind, "lmstudio");
+        assert_eq!(found.base_url, base_url);
+        assert_eq!(found.models, vec!["qwen2.5-7b-instruct".to_string(), "llama-3.1-8b".to_string()]);
+        models.assert_async().await;
+
+        // 응답이 실패하면 서버가 없는 것으로 처리
+        server.mock("GET", "/broken/models").with_status(500).create_async().await;
+        let broken = format!("{}/broken", server.url());
+        assert!(discover_openai_compatible(&client, "vllm", &broken).await.is_none());
+    }
+}
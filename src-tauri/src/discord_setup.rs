@@ -0,0 +1,312 @@
+// Discord 봇 초대 URL / Intent 확인
+// - 토큰으로 애플리케이션 ID 조회 (applications/@me, 연결 실패 시에만 토큰 첫 부분에서 추출)
+// - OpenClaw에 필요한 권한 비트셋 + scope로 OAuth2 초대 URL 생성
+// - Message Content Intent가 켜져 있는지 확인 (꺼져 있으면 메시지 본문이 비어 도착함)
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use serde::Serialize;
+
+use crate::channel_api::{self, DISCORD_API_BASE};
+use crate::openclaw::{normalize_account_id, MASKED_SECRET};
+use crate::provider_api::http_client;
+
+const DISCORD_AUTHORIZE_URL: &str = "https://discord.com/oauth2/authorize";
+const INVITE_SCOPES: &[&str] = &["bot", "applications.commands"];
+
+/// 초대 시 요청할 권한 (이름, 비트)
+const BOT_PERMISSIONS: &[(&str, u64)] = &[
+    ("Add Reactions", 1 << 6),
+    ("View Channels", 1 << 10),
+    ("Send Messages", 1 << 11),
+    ("Embed Links", 1 << 14),
+    ("Attach Files", 1 << 15),
+    ("Read Message History", 1 << 16),
+    ("Use External Emojis", 1 << 18),
+    ("Use Application Commands", 1 << 31),
+    ("Create Public Threads", 1 << 35),
+    ("Send Messages in Threads", 1 << 38),
+];
+
+// 애플리케이션 flags (Privileged Gateway Intents)
+const FLAG_GATEWAY_PRESENCE: u64 = 1 << 12;
+const FLAG_GATEWAY_PRESENCE_LIMITED: u64 = 1 << 13;
+const FLAG_GATEWAY_GUILD_MEMBERS: u64 = 1 << 14;
+const FLAG_GATEWAY_GUILD_MEMBERS_LIMITED: u64 = 1 << 15;
+const FLAG_GATEWAY_MESSAGE_CONTENT: u64 = 1 << 18;
+const FLAG_GATEWAY_MESSAGE_CONTENT_LIMITED: u64 = 1 << 19;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntentState {
+    Enabled,
+    /// 100개 미만 서버의 미인증 봇에서 켠 상태 (동작은 동일)
+    Limited,
+    Disabled,
+    /// API로 확인하지 못함
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordIntents {
+    pub message_content: IntentState,
+    pub server_members: IntentState,
+    pub presence: IntentState,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscordInvite {
+    pub application_id: String,
+    pub application_name: Option<String>,
+    /// 초대 링크 (권한 + scope 포함)
+    pub invite_url: String,
+    /// 권한 비트셋 (10진 문자열, 64비트 초과 대비)
+    pub permissions: String,
+    pub permission_names: Vec<&'static str>,
+    pub scopes: Vec<&'static str>,
+    pub intents: DiscordIntents,
+    /// Intent를 켜는 Developer Portal 페이지
+    pub bot_settings_url: String,
+    pub warnings: Vec<String>,
+}
+
+fn intent_state(flags: u64, enabled: u64, limited: u64) -> IntentState {
+    if flags & enabled != 0 {
+        IntentState::Enabled
+    } else if flags & limited != 0 {
+        IntentState::Limited
+    } else {
+        IntentState::Disabled
+    }
+}
+
+/// 토큰 첫 부분(base64)이 봇 사용자 ID = 애플리케이션 ID
+fn application_id_from_token(token: &str) -> Option<String> {
+    let first = token.split('.').next()?;
+    URL_SAFE_NO_PAD
+        .decode(first.trim_end_matches('='))
+        .ok()
+        .and_then(|id| String::from_utf8(id).ok())
+        .filter(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn permission_bits() -> u64 {
+    BOT_PERMISSIONS.iter().fold(0, |acc, (_, bit)| acc | bit)
+}
+
+fn invite_url(application_id: &str, guild_id: Option<&str>) -> Result<String, String> {
+    let permissions = permission_bits().to_string();
+    let scopes = INVITE_SCOPES.join(" ");
+    let mut params = vec![
+        ("client_id", application_id),
+        ("scope", scopes.as_str()),
+        ("permissions", permissions.as_str()),
+    ];
+    if let Some(guild) = guild_id {
+        params.push(("guild_id", guild));
+        params.push(("disable_guild_select", "true"));
+    }
+    reqwest::Url::parse_with_params(DISCORD_AUTHORIZE_URL, &params)
+        .map(|u| u.to_string())
+        .map_err(|e| e.to_string())
+}
+
+/// 애플리케이션 조회 결과: (ID, 이름, Intent 상태)
+type ApplicationLookup = (Option<String>, Option<String>, DiscordIntents);
+
+/// GET /applications/@me (봇 토큰으로 자신의 애플리케이션 조회)
+/// 토큰 거부 등 HTTP 오류는 Err, 연결 실패만 경고를 남기고 확인 불가(Unknown)로 진행
+async fn lookup_application(
+    token: &str,
+    base_url: Option<&str>,
+    warnings: &mut Vec<String>,
+) -> Result<ApplicationLookup, String> {
+    let url = format!("{}/api/v10/applications/@me", channel_api::api_base(base_url, DISCORD_API_BASE));
+    let request = http_client()?.get(url).header("Authorization", format!("Bot {}", token));
+    let app = match channel_api::send_json(request).await {
+        Ok((200, app)) => app,
+        Ok((401, _)) => return Err("Discord 봇 토큰이 유효하지 않습니다".to_string()),
+        Ok((status, body)) => {
+            return Err(format!(
+                "애플리케이션 조회 실패 (HTTP {}): {}",
+                status,
+                body.get("message").and_then(|m| m.as_str()).unwrap_or_default()
+            ))
+        }
+        Err(e) => {
+            warnings.push(format!("Discord {}", e));
+            let unknown = DiscordIntents {
+                message_content: IntentState::Unknown,
+                server_members: IntentState::Unknown,
+                presence: IntentState::Unknown,
+            };
+            return Ok((None, None, unknown));
+        }
+    };
+
+    let flags = app.get("flags").and_then(|f| f.as_u64()).unwrap_or(0);
+    let intents = DiscordIntents {
+        message_content: intent_state(flags, FLAG_GATEWAY_MESSAGE_CONTENT, FLAG_GATEWAY_MESSAGE_CONTENT_LIMITED),
+        server_members: intent_state(flags, FLAG_GATEWAY_GUILD_MEMBERS, FLAG_GATEWAY_GUILD_MEMBERS_LIMITED),
+        presence: intent_state(flags, FLAG_GATEWAY_PRESENCE, FLAG_GATEWAY_PRESENCE_LIMITED),
+    };
+    if app.pointer("/bot_public").and_then(|b| b.as_bool()) == Some(false) {
+        warnings.push("Public Bot이 꺼져 있어 애플리케이션 소유자만 봇을 초대할 수 있습니다".to_string());
+    }
+    if app.pointer("/bot_require_code_grant").and_then(|b| b.as_bool()) == Some(true) {
+        warnings.push("Requires OAuth2 Code Grant가 켜져 있으면 초대 링크가 동작하지 않습니다. Bot 탭에서 끄세요".to_string());
+    }
+    let id = app.get("id").and_then(|i| i.as_str()).map(String::from);
+    let name = app.get("name").and_then(|n| n.as_str()).map(String::from);
+    Ok((id, name, intents))
+}
+
+/// Discord 봇 초대 URL 생성 + Message Content Intent 확인
+/// token을 비우거나 마스킹 값이면 저장된 토큰 사용. guild_id를 주면 해당 서버가 미리 선택된 링크 생성
+#[tauri::command]
+pub async fn get_discord_invite(
+    token: Option<String>,
+    account_id: Option<String>,
+    guild_id: Option<String>,
+    base_url: Option<String>,
+) -> Result<DiscordInvite, String> {
+    let account_id = normalize_account_id(account_id.as_deref())?;
+    let token = token
+        .as_deref()
+        .map(str::trim)
+        .filter(|t| !t.is_empty() && *t != MASKED_SECRET)
+        .map(String::from)
+        .or_else(|| channel_api::stored_token("discord", None, account_id.as_deref()))
+        .ok_or("Discord 봇 토큰이 없습니다")?;
+    let token = token.strip_prefix("Bot ").unwrap_or(&token).to_string();
+    let guild_id = guild_id.map(|g| g.trim().to_string()).filter(|g| !g.is_empty());
+    if let Some(g) = &guild_id {
+        if !g.chars().all(|c| c.is_ascii_digit()) {
+            return Err(format!("서버(길드) ID는 숫자여야 합니다: {}", g));
+        }
+    }
+
+    let mut warnings = Vec::new();
+    let (application_id, application_name, intents) =
+        lookup_application(&token, base_url.as_deref(), &mut warnings).await?;
+    let application_id = application_id
+        .or_else(|| application_id_from_token(&token))
+        .ok_or("토큰에서 애플리케이션 ID를 찾을 수 없습니다. Discord 봇 토큰이 맞는지 확인하세요")?;
+
+    if intents.message_content == IntentState::Disabled {
+        warnings.push(
+            "Message Content Intent가 꺼져 있습니다. 봇이 메시지 내용을 읽지 못하므로 Developer Portal → Bot → Privileged Gateway Intents에서 켜세요"
+                .to_string(),
+        );
+    }
+
+    Ok(DiscordInvite {
+        invite_url: invite_url(&application_id, guild_id.as_deref())?,
+        bot_settings_url: format!("https://discord.com/developers/applications/{}/bot", application_id),
+        application_id,
+        application_name,
+        permissions: permission_bits().to_string(),
+        permission_names: BOT_PERMISSIONS.iter().map(|(name, _)| *name).collect(),
+        scopes: INVITE_SCOPES.to_vec(),
+        intents,
+        warnings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn application_id_is_read_from_the_token_prefix() {
+        let token = format!("{}.GabcDe.signature", URL_SAFE_NO_PAD.encode("123456789012345678"));
+        assert_eq!(application_id_from_token(&token).as_deref(), Some("123456789012345678"));
+        // 패딩이 붙은 형식도 허용
+        let padded = format!("{}==.x.y", URL_SAFE_NO_PAD.encode("1234567890123456789"));
+        assert_eq!(application_id_from_token(&padded).as_deref(), Some("1234567890123456789"));
+        assert_eq!(application_id_from_token(&format!("{}.x.y", URL_SAFE_NO_PAD.encode("bot"))), None);
+        assert_eq!(application_id_from_token("not a token"), None);
+        assert_eq!(application_id_from_token(""), None);
+    }
+
+    #[test]
+    fn permission_bits_combine_all_requested_permissions() {
+        let bits = permission_bits();
+        assert_eq!(bits.count_ones() as usize, BOT_PERMISSIONS.len());
+        assert_ne!(bits & (1 << 11), 0);
+        assert_ne!(bits & (1 << 38), 0);
+        // 관리자 권한은 요청하지 않음
+        assert_eq!(bits & (1 << 3), 0);
+
+        let url = invite_url("123", Some("456")).unwrap();
+        assert!(url.contains(&format!("permissions={}", bits)));
+        assert!(url.contains("guild_id=456"));
+        assert!(url.contains("scope=bot+applications.commands"));
+    }
+
+    #[test]
+    fn intent_state_prefers_enabled_over_limited() {
+        let (on, limited) = (FLAG_GATEWAY_MESSAGE_CONTENT, FLAG_GATEWAY_MESSAGE_CONTENT_LIMITED);
+        assert_eq!(intent_state(on, on, limited), IntentState::Enabled);
+        assert_eq!(intent_state(on | limited, on, limited), IntentState::Enabled);
+        assert_eq!(intent_state(limited, on, limited), IntentState::Limited);
+        assert_eq!(intent_state(FLAG_GATEWAY_PRESENCE, on, limited), IntentState::Disabled);
+    }
+
+    #[tokio::test]
+    async fn lookup_reads_application_flags() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/api/v10/applications/@me")
+            .match_header("authorization", "Bot tok")
+            .with_status(200)
+            .with_body(
+                serde_json::json!({
+                    "id": "123456789012345678",
+                    "name": "molt",
+                    "flags": FLAG_GATEWAY_MESSAGE_CONTENT_LIMITED,
+                    "bot_public": false,
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+        let mut warnings = Vec::new();
+        let (id, name, intents) = lookup_application("tok", Some(&server.url()), &mut warnings).await.unwrap();
+        mock.assert_async().await;
+        assert_eq!(id.as_deref(), Some("123456789012345678"));
+        assert_eq!(name.as_deref(), Some("molt"));
+        assert_eq!(intents.message_content, IntentState::Limited);
+        assert_eq!(intents.server_members, IntentState::Disabled);
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn rejected_tokens_fail_instead_of_falling_back() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v10/applications/@me")
+            .with_status(401)
+            .with_body(r#"{"message":"401: Unauthorized","code":0}"#)
+            .create_async()
+            .await;
+        let mut warnings = Vec::new();
+        assert!(lookup_application("tok", Some(&server.url()), &mut warnings).await.is_err());
+        assert!(warnings.is_empty());
+    }
+
+    #[tokio::test]
+    async fn unreachable_api_leaves_intents_unknown() {
+        // 닫힌 포트: 연결 실패만 경고로 남기고 진행
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let mut warnings = Vec::new();
+        let (id, _, intents) = lookup_application("tok", Some(&base), &mut warnings).await.unwrap();
+        assert_eq!(id, None);
+        assert_eq!(intents.message_content, IntentState::Unknown);
+        assert_eq!(warnings.len(), 1);
+    }
+}
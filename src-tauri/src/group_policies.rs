@@ -0,0 +1,387 @@
+// 그룹/길드별 정책 편집
+// 와일드카드("*") 외에 개별 그룹마다 멘션 필요 여부, 허용 사용자, 활성화, 시스템 프롬프트를 설정합니다.
+// 채널별 스키마:
+// - Telegram: channels.telegram.groups.<chatId>        { requireMention, allowFrom, enabled, systemPrompt }
+// - Discord:  channels.discord.guilds.<guildId>         { requireMention, users }
+//             channels.discord.guilds.<guildId>.channels.<channelId> { requireMention, users, allow, systemPrompt }
+// - Slack:    channels.slack.channels.<channelId>       { requireMention, users, enabled, systemPrompt }
+// - WhatsApp: channels.whatsapp.groups.<jid>            { requireMention }
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::openclaw::{
+    existing_channel_section_mut, normalize_account_id, read_existing_config, set_nested_value, write_config,
+};
+
+/// 그룹 추가/수정 입력 (None인 필드는 변경하지 않음)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupPolicyInput {
+    pub id: String,
+    /// Discord 채널인 경우 소속 길드 ID
+    pub guild_id: Option<String>,
+    pub require_mention: Option<bool>,
+    pub users: Option<Vec<String>>,
+    pub enabled: Option<bool>,
+    /// 빈 문자열이면 삭제
+    pub system_prompt: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupPolicyEntry {
+    pub channel: String,
+    /// group | guild | channel
+    pub kind: String,
+    pub id: String,
+    pub guild_id: Option<String>,
+    pub require_mention: Option<bool>,
+    pub users: Vec<String>,
+    pub enabled: Option<bool>,
+    pub system_prompt: Option<String>,
+}
+
+/// 항목 종류별 스키마
+struct GroupSchema {
+    kind: &'static str,
+    /// 허용 사용자 키 (없으면 미지원)
+    users_key: Option<&'static str>,
+    /// 활성화 키 (없으면 미지원)
+    enabled_key: Option<&'static str>,
+    system_prompt: bool,
+}
+
+fn schema_for(channel: &str, is_discord_channel: bool) -> Result<GroupSchema, String> {
+    let schema = match (channel, is_discord_channel) {
+        ("telegram", _) => GroupSchema {
+            kind: "group",
+            users_key: Some("allowFrom"),
+            enabled_key: Some("enabled"),
+            system_prompt: true,
+        },
+        ("discord", false) => GroupSchema {
+            kind: "guild",
+            users_key: Some("users"),
+            enabled_key: None,
+            system_prompt: false,
+        },
+        ("discord", true) => GroupSchema {
+            kind: "channel",
+            users_key: Some("users"),
+            enabled_key: Some("allow"),
+            system_prompt: true,
+        },
+        ("slack", _) => GroupSchema {
+            kind: "channel",
+            users_key: Some("users"),
+            enabled_key: Some("enabled"),
+            system_prompt: true,
+        },
+        ("whatsapp", _) => GroupSchema {
+            kind: "group",
+            users_key: None,
+            enabled_key: None,
+            system_prompt: false,
+        },
+        _ => return Err(format!("그룹별 설정을 지원하지 않는 채널입니다: {}", channel)),
+    };
+    Ok(schema)
+}
+
+/// 채널별 그룹 ID 형식 검증
+fn validate_group_id(channel: &str, kind: &str, id: &str) -> Result<(), String> {
+    let digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+    let valid = match (channel, kind) {
+        // 와일드카드는 Discord 채널을 제외한 모든 곳에서 허용
+        (_, k) if id == "*" => !(channel == "discord" && k == "channel"),
+        // 그룹/슈퍼그룹 chat ID는 음수 (-100...)
+        ("telegram", _) => id.strip_prefix('-').is_some_and(digits),
+        // Discord snowflake
+        ("discord", _) => digits(id) && (17..=20).contains(&id.len()),
+        // C(공개 채널) / G(비공개 채널) + 대문자/숫자
+        ("slack", _) => {
+            id.len() >= 9
+                && id.starts_with(['C', 'G'])
+                && id.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+        }
+        // 그룹 JID: <숫자>(-<숫자>)@g.us
+        ("whatsapp", _) => id
+            .strip_suffix("@g.us")
+            .is_some_and(|local| local.split('-').all(digits)),
+        _ => false,
+    };
+    if valid {
+        return Ok(());
+    }
+    let hint = match channel {
+        "telegram" => "Telegram 그룹 ID는 -100으로 시작하는 음수입니다 (예: -1001234567890)",
+        "discord" => "Discord ID는 17~20자리 숫자입니다 (개발자 모드에서 ID 복사)",
+        "slack" => "Slack 채널 ID는 C 또는 G로 시작합니다 (예: C0123456789)",
+        "whatsapp" => "WhatsApp 그룹 ID는 @g.us로 끝납니다 (예: 120363012345678901@g.us)",
+        _ => "",
+    };
+    Err(format!("잘못된 그룹 ID입니다: {} - {}", id, hint))
+}
+
+/// 채널 섹션 안의 그룹 맵 경로
+fn group_map_path(channel: &str, guild_id: Option<&str>) -> Vec<String> {
+    match (channel, guild_id) {
+        ("discord", Some(guild)) => vec!["guilds".into(), guild.into(), "channels".into()],
+        ("discord", None) => vec!["guilds".into()],
+        ("slack", _) => vec!["channels".into()],
+        _ => vec!["groups".into()],
+    }
+}
+
+fn str_list(v: Option<&Value>) -> Vec<String> {
+    v.and_then(|a| a.as_array())
+        .map(|arr| arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect())
+        .unwrap_or_default()
+}
+
+fn to_entry(channel: &str, schema: &GroupSchema, id: &str, guild_id: Option<&str>, value: &Value) -> GroupPolicyEntry {
+    GroupPolicyEntry {
+        channel: channel.to_string(),
+        kind: schema.kind.to_string(),
+        id: id.to_string(),
+        guild_id: guild_id.map(|g| g.to_string()),
+        require_mention: value.get("requireMention").and_then(|r| r.as_bool()),
+        users: str_list(schema.users_key.and_then(|k| value.get(k))),
+        enabled: schema.enabled_key.and_then(|k| value.get(k)).and_then(|e| e.as_bool()),
+        system_prompt: value.get("systemPrompt").and_then(|p| p.as_str()).map(|s| s.to_string()),
+    }
+}
+
+fn account_section(config: &Value, channel: &str, account_id: Option<&str>) -> Option<Value> {
+    let pointer = match account_id {
+        Some(id) => format!("/channels/{}/accounts/{}", channel, id),
+        None => format!("/channels/{}", channel),
+    };
+    config.pointer(&pointer).cloned()
+}
+
+/// 그룹 항목에 입력값 반영 (스키마에 없는 필드는 거부)
+fn apply_input(entry: &mut Value, schema: &GroupSchema, channel: &str, input: &GroupPolicyInput) -> Result<(), String> {
+    if let Some(require_mention) = input.require_mention {
+        set_nested_value(entry, &["requireMention"], json!(require_mention));
+    }
+    if let Some(users) = &input.users {
+        let key = schema
+            .users_key
+            .ok_or_else(|| format!("{} 그룹은 허용 사용자를 지원하지 않습니다 (groupAllowFrom 사용)", channel))?;
+        let users: Vec<String> = users
+            .iter()
+            .map(|u| u.trim().to_string())
+            .filter(|u| !u.is_empty())
+            .collect();
+        set_nested_value(entry, &[key], json!(users));
+    }
+    if let Some(enabled) = input.enabled {
+        let key = schema
+            .enabled_key
+            .ok_or_else(|| format!("{} {} 항목은 활성화 설정을 지원하지 않습니다", channel, schema.kind))?;
+        set_nested_value(entry, &[key], json!(enabled));
+    }
+    if let Some(prompt) = &input.system_prompt {
+        if !schema.system_prompt {
+            return Err(format!("{} {} 항목은 시스템 프롬프트를 지원하지 않습니다", channel, schema.kind));
+        }
+        if prompt.trim().is_empty() {
+            if let Some(obj) = entry.as_object_mut() {
+                obj.remove("systemPrompt");
+            }
+        } else {
+            set_nested_value(entry, &["systemPrompt"], json!(prompt));
+        }
+    }
+    Ok(())
+}
+
+/// 그룹 추가(create=true) 또는 수정(create=false)
+fn save_group(channel: &str, account_id: Option<&str>, input: GroupPolicyInput, create: bool) -> Result<GroupPolicyEntry, String> {
+    let mut config = read_existing_config();
+    let entry = put_group(&mut config, channel, account_id, &input, create)?;
+    write_config(&config)?;
+    Ok(entry)
+}
+
+/// 설정 값에 그룹 항목 반영 (저장은 호출자가 담당)
+fn put_group(
+    config: &mut Value,
+    channel: &str,
+    account_id: Option<&str>,
+    input: &GroupPolicyInput,
+    create: bool,
+) -> Result<GroupPolicyEntry, String> {
+    let account_id = normalize_account_id(account_id)?;
+    let id = input.id.trim().to_string();
+    let guild_id = input.guild_id.as_deref().map(str::trim).filter(|g| !g.is_empty());
+    if guild_id.is_some() && channel != "discord" {
+        return Err("guildId는 Discord에서만 사용할 수 있습니다".to_string());
+    }
+    let schema = schema_for(channel, guild_id.is_some())?;
+    if let Some(guild) = guild_id {
+        validate_group_id(channel, "guild", guild)?;
+    }
+    validate_group_id(channel, schema.kind, &id)?;
+
+    if config.pointer(&format!("/channels/{}", channel)).is_none() {
+        return Err(format!("{} 채널이 설정되어 있지 않습니다", channel));
+    }
+    let section = existing_channel_section_mut(config, channel, account_id.as_deref())?;
+
+    let mut path = group_map_path(channel, guild_id);
+    path.push(id.clone());
+    let pointer = format!("/{}", path.join("/"));
+    let exists = section.pointer(&pointer).is_some();
+    if create && exists {
+        return Err(format!("이미 등록된 {}입니다: {}", schema.kind, id));
+    }
+    if !create && !exists {
+        return Err(format!("등록되지 않은 {}입니다: {}", schema.kind, id));
+    }
+
+    let mut entry = section.pointer(&pointer).cloned().unwrap_or_else(|| json!({}));
+    apply_input(&mut entry, &schema, channel, input)?;
+    let path_refs: Vec<&str> = path.iter().map(|s| s.as_str()).collect();
+    set_nested_value(section, &path_refs, entry.clone());
+
+    Ok(to_entry(channel, &schema, &id, guild_id, &entry))
+}
+
+// ===== Tauri 커맨드 =====
+
+/// 그룹/길드별 설정 목록 (Discord는 길드와 길드 내 채널을 함께 반환)
+#[tauri::command]
+pub fn list_group_policies(channel: String, account_id: Option<String>) -> Result<Vec<GroupPolicyEntry>, String> {
+    let schema = schema_for(&channel, false)?;
+    let account_id = normalize_account_id(account_id.as_deref())?;
+    let Some(section) = account_section(&read_existing_config(), &channel, account_id.as_deref()) else {
+        return Ok(vec![]);
+    };
+
+    let map_pointer = format!("/{}", group_map_path(&channel, None).join("/"));
+    let mut entries = Vec::new();
+    for (id, value) in section.pointer(&map_pointer).and_then(|m| m.as_object()).into_iter().flatten() {
+        entries.push(to_entry(&channel, &schema, id, None, value));
+        if channel == "discord" {
+            let channel_schema = schema_for(&channel, true)?;
+            for (channel_id, channel_value) in value.get("channels").and_then(|c| c.as_object()).into_iter().flatten() {
+                entries.push(to_entry(&channel, &channel_schema, channel_id, Some(id), channel_value));
+            }
+        }
+    }
+    Ok(entries)
+}
+
+/// 그룹/길드/채널 추가
+#[tauri::command]
+pub fn add_group_policy(channel: String, policy: GroupPolicyInput, account_id: Option<String>) -> Result<GroupPolicyEntry, String> {
+    save_group(&channel, account_id.as_deref(), policy, true)
+}
+
+/// 그룹/길드/채널 설정 수정 (지정한 필드만 변경)
+#[tauri::command]
+pub fn update_group_policy(channel: String, policy: GroupPolicyInput, account_id: Option<String>) -> Result<GroupPolicyEntry, String> {
+    save_group(&channel, account_id.as_deref(), policy, false)
+}
+
+/// 그룹/길드/채널 삭제 (길드를 삭제하면 길드 내 채널 설정도 함께 삭제)
+#[tauri::command]
+pub fn remove_group_policy(
+    channel: String,
+    id: String,
+    guild_id: Option<String>,
+    account_id: Option<String>,
+) -> Result<(), String> {
+    schema_for(&channel, guild_id.is_some())?;
+    let account_id = normalize_account_id(account_id.as_deref())?;
+    let mut config = read_existing_config();
+    let section = existing_channel_section_mut(&mut config, &channel, account_id.as_deref())?;
+
+    let map_pointer = format!("/{}", group_map_path(&channel, guild_id.as_deref()).join("/"));
+    let removed = section
+        .pointer_mut(&map_pointer)
+        .and_then(|m| m.as_object_mut())
+        .and_then(|m| m.remove(id.trim()))
+        .is_some();
+    if !removed {
+        return Err(format!("등록되지 않은 항목입니다: {}", id));
+    }
+    write_config(&config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(id: &str, guild_id: Option<&str>) -> GroupPolicyInput {
+        GroupPolicyInput {
+            id: id.to_string(),
+            guild_id: guild_id.map(|g| g.to_string()),
+            require_mention: Some(true),
+            users: None,
+            enabled: None,
+            system_prompt: None,
+        }
+    }
+
+    #[test]
+    fn group_ids_follow_channel_formats() {
+        assert!(validate_group_id("telegram", "group", "-1001234567890").is_ok());
+        assert!(validate_group_id("telegram", "group", "1234567890").is_err());
+        assert!(validate_group_id("telegram", "group", "*").is_ok());
+        assert!(validate_group_id("discord", "guild", "123456789012345678").is_ok());
+        assert!(validate_group_id("discord", "guild", "12345").is_err());
+        // Discord 채널에는 와일드카드 불가
+        assert!(validate_group_id("discord", "channel", "*").is_err());
+        assert!(validate_group_id("slack", "channel", "C0123456789").is_ok());
+        assert!(validate_group_id("slack", "channel", "D0123456789").is_err());
+        assert!(validate_group_id("slack", "channel", "c0123456789").is_err());
+        assert!(validate_group_id("whatsapp", "group", "120363012345678901@g.us").is_ok());
+        assert!(validate_group_id("whatsapp", "group", "821012345678-1600000000@g.us").is_ok());
+        assert!(validate_group_id("whatsapp", "group", "821012345678@s.whatsapp.net").is_err());
+        assert!(validate_group_id("signal", "group", "abc").is_err());
+    }
+
+    #[test]
+    fn group_maps_live_under_channel_specific_keys() {
+        assert_eq!(group_map_path("telegram", None), vec!["groups"]);
+        assert_eq!(group_map_path("whatsapp", None), vec!["groups"]);
+        assert_eq!(group_map_path("slack", None), vec!["channels"]);
+        assert_eq!(group_map_path("discord", None), vec!["guilds"]);
+        assert_eq!(group_map_path("discord", Some("123")), vec!["guilds", "123", "channels"]);
+    }
+
+    #[test]
+    fn groups_are_saved_only_into_existing_accounts() {
+        let mut config = json!({
+            "channels": { "telegram": { "botToken": "1:abc", "accounts": { "work": { "botToken": "2:def" } } } }
+        });
+        put_group(&mut config, "telegram", Some("work"), &input("-1001234567890", None), true).unwrap();
+        assert_eq!(
+            config.pointer("/channels/telegram/accounts/work/groups/-1001234567890/requireMention"),
+            Some(&json!(true))
+        );
+        put_group(&mut config, "telegram", None, &input("-1009876543210", None), true).unwrap();
+        assert!(config.pointer("/channels/telegram/groups/-1009876543210").is_some());
+
+        let before = config.clone();
+        assert!(put_group(&mut config, "telegram", Some("wrok"), &input("-1001234567890", None), true).is_err());
+        assert!(put_group(&mut config, "telegram", Some("work"), &input("-1001234567890", None), true).is_err());
+        assert!(put_group(&mut config, "telegram", Some("work"), &input("-1005555555555", None), false).is_err());
+        assert_eq!(config, before);
+    }
+
+    #[test]
+    fn discord_channels_are_nested_under_their_guild() {
+        let mut config = json!({ "channels": { "discord": { "token": "x" } } });
+        let entry = put_group(&mut config, "discord", None, &input("223456789012345678", Some("123456789012345678")), true).unwrap();
+        assert_eq!(entry.kind, "channel");
+        assert!(config
+            .pointer("/channels/discord/guilds/123456789012345678/channels/223456789012345678")
+            .is_some());
+        assert!(put_group(&mut config, "telegram", None, &input("-1001234567890", Some("1")), true).is_err());
+    }
+}
@@ -1,6 +1,27 @@
 mod openclaw;
 mod skills;
 mod skill_definitions;
+mod skill_registry;
+mod sessions;
+mod retention;
+mod model_catalog;
+mod usage;
+mod provider_api;
+mod custom_providers;
+mod auth_profiles;
+mod anthropic_oauth;
+mod budgets;
+mod channel_api;
+mod pairing;
+mod group_policies;
+mod channel_schema;
+mod whatsapp_login;
+mod googlechat;
+mod slack_manifest;
+mod discord_setup;
+mod mattermost;
+mod channel_test;
+mod workspace_git;
 
 // Platform abstraction layer (new architecture for cross-platform support)
 // Use platform::get_platform() for new code
@@ -706,11 +727,24 @@ fn generate_gateway_token() -> String {
     openclaw::generate_gateway_token()
 }
 
+/// 반환: 키를 검증하지 못하고 저장한 경우 안내 문구
 #[tauri::command]
-async fn add_model_to_config(provider: String, model: String, api_key: String) -> Result<(), String> {
-    openclaw::add_model_to_config(&provider, &model, &api_key).await
+async fn add_model_to_config(
+    provider: String,
+    model: String,
+    api_key: String,
+    validate: Option<bool>,
+) -> Result<Option<String>, String> {
+    // validate=true면 저장 전에 프로바이더에 키 확인
+    let mut warning = None;
+    if validate.unwrap_or(false) && !api_key.is_empty() {
+        warning = provider_api::ensure_key_usable(&provider, &api_key).await?;
+    }
+    openclaw::add_model_to_config(&provider, &model, &api_key).await?;
+    Ok(warning)
 }
 
+#[allow(clippy::too_many_arguments)]
 #[tauri::command]
 async fn add_channel_to_config(
     channel: String,
@@ -720,8 +754,26 @@ async fn add_channel_to_config(
     group_policy: String,
     group_allow_from: Vec<String>,
     require_mention: bool,
+    account_id: Option<String>,
+    validate: Option<bool>,
 ) -> Result<(), String> {
-    openclaw::add_channel_to_config(&channel, &bot_token, &dm_policy, &allow_from, &group_policy, &group_allow_from, require_mention).await
+    // validate=true면 저장 전에 플랫폼에 토큰 확인
+    if validate.unwrap_or(false) && !bot_token.is_empty() {
+        channel_api::ensure_token_usable(&channel, &bot_token, account_id.as_deref()).await?;
+    }
+    openclaw::add_channel_to_config(&channel, &bot_token, &dm_policy, &allow_from, &group_policy, &group_allow_from, require_mention, account_id.as_deref()).await
+}
+
+/// 채널 계정 목록 (기본 계정 + accounts.*)
+#[tauri::command]
+fn list_channel_accounts(channel: String) -> Vec<serde_json::Value> {
+    openclaw::list_channel_accounts(&channel)
+}
+
+/// 채널 계정 삭제 (기본 계정은 비활성화)
+#[tauri::command]
+fn remove_channel_account(channel: String, account_id: String) -> Result<(), String> {
+    openclaw::remove_channel_account(&channel, &account_id)
 }
 
 // ===== 설정 (레거시 - 하위 호환성) =====
@@ -815,20 +867,23 @@ async fn start_whatsapp_pairing() -> Result<String, String> {
 
 /// WhatsApp QR 로그인 (터미널 창에서 QR 표시)
 #[tauri::command]
-async fn login_whatsapp() -> Result<String, String> {
-    openclaw::login_whatsapp().await
+async fn login_whatsapp(account_id: Option<String>) -> Result<String, String> {
+    openclaw::login_whatsapp(account_id.as_deref()).await
 }
 
 /// WhatsApp 로그인 터미널 열기 (비동기, 대기 안 함)
 #[tauri::command]
-fn open_whatsapp_login_terminal() -> Result<(), String> {
-    openclaw::open_whatsapp_login_terminal()
+fn open_whatsapp_login_terminal(account_id: Option<String>) -> Result<(), String> {
+    openclaw::open_whatsapp_login_terminal(account_id.as_deref())
 }
 
 /// WhatsApp 인증 상태 확인
 #[tauri::command]
-fn check_whatsapp_linked() -> bool {
-    openclaw::check_whatsapp_linked()
+fn check_whatsapp_linked(account_id: Option<String>) -> bool {
+    // 잘못된 계정 ID는 연결되지 않은 것으로 처리
+    openclaw::normalize_account_id(account_id.as_deref())
+        .map(|id| openclaw::check_whatsapp_linked(id.as_deref()))
+        .unwrap_or(false)
 }
 
 #[tauri::command]
@@ -1249,11 +1304,37 @@ fn get_integrations_config() -> serde_json::Value {
     openclaw::get_integrations_config()
 }
 
+#[tauri::command]
+fn get_model_chain(agent_id: Option<String>) -> Result<serde_json::Value, String> {
+    openclaw::get_model_chain(agent_id.as_deref())
+}
+
+#[tauri::command]
+fn add_provider_model(provider: String, model: String) -> Result<(), String> {
+    openclaw::add_provider_model(&provider, &model)
+}
+
+#[tauri::command]
+fn remove_provider_model(provider: String, model: String) -> Result<(), String> {
+    openclaw::remove_provider_model(&provider, &model)
+}
+
+#[tauri::command]
+fn set_model_fallbacks(agent_id: Option<String>, fallbacks: Vec<String>) -> Result<(), String> {
+    openclaw::set_model_fallbacks(agent_id.as_deref(), &fallbacks)
+}
+
+#[tauri::command]
+fn set_role_model(agent_id: Option<String>, role: String, model: Option<String>) -> Result<(), String> {
+    openclaw::set_role_model(agent_id.as_deref(), &role, model.as_deref())
+}
+
 #[tauri::command]
 async fn update_model_config(provider: String, model: String, api_key: String) -> Result<(), String> {
     openclaw::update_model_config(&provider, &model, &api_key).await
 }
 
+#[allow(clippy::too_many_arguments)]
 #[tauri::command]
 async fn update_messenger_config(
     channel: String,
@@ -1263,8 +1344,9 @@ async fn update_messenger_config(
     group_policy: String,
     group_allow_from: Vec<String>,
     require_mention: bool,
+    account_id: Option<String>,
 ) -> Result<(), String> {
-    openclaw::update_messenger_config(&channel, &token, &dm_policy, &allow_from, &group_policy, &group_allow_from, require_mention).await
+    openclaw::update_messenger_config(&channel, &token, &dm_policy, &allow_from, &group_policy, &group_allow_from, require_mention, account_id.as_deref()).await
 }
 
 #[tauri::command]
@@ -1304,9 +1386,19 @@ fn get_node_install_url() -> String {
     openclaw::get_node_install_url()
 }
 
+/// 반환: 키를 검증하지 못하고 저장한 경우 안내 문구
 #[tauri::command]
-async fn set_env_config(key: String, value: String) -> Result<(), String> {
-    openclaw::set_env_config(&key, &value).await
+async fn set_env_config(key: String, value: String, validate: Option<bool>) -> Result<Option<String>, String> {
+    // 프로바이더 API 키면 저장 전에 검증
+    let mut warning = None;
+    if let Some(provider) = provider_api::provider_for_env_key(&key) {
+        // 마스킹 값은 저장하지 않으므로 검증도 건너뜀
+        if validate.unwrap_or(false) && !value.is_empty() && value != openclaw::MASKED_SECRET {
+            warning = provider_api::ensure_key_usable(provider, &value).await?;
+        }
+    }
+    openclaw::set_env_config(&key, &value).await?;
+    Ok(warning)
 }
 
 #[tauri::command]
@@ -1575,6 +1667,9 @@ async fn get_workspace_files() -> Result<String, String> {
 /// 파일 열기
 #[tauri::command]
 async fn open_file(path: String) -> Result<(), String> {
+    // 버전 관리 중이면 편집 전 상태를 기록
+    workspace_git::snapshot_before_edit(&path);
+
     #[cfg(windows)]
     {
         std::process::Command::new("cmd")
@@ -1609,13 +1704,11 @@ async fn open_workspace_folder() -> Result<(), String> {
     open_file(workspace_path.to_string_lossy().to_string()).await
 }
 
-/// 대화 기록 조회 (openclaw sessions list 사용)
-/// 메시지 자르기
+/// 메시지 자르기 (문자 단위, UTF-8 경계 안전)
 fn truncate_message(msg: &str, max_len: usize) -> String {
-    if msg.len() > max_len {
-        format!("{}...", &msg[..max_len])
-    } else {
-        msg.to_string()
+    match msg.char_indices().nth(max_len) {
+        Some((idx, _)) => format!("{}...", &msg[..idx]),
+        None => msg.to_string(),
     }
 }
 
@@ -1783,10 +1876,38 @@ async fn get_channel_status() -> Result<String, String> {
 /// 사용량 통계 조회
 #[tauri::command]
 async fn get_usage_stats() -> Result<String, String> {
-    // TODO: 실제 사용량 통계 구현
+    let records = usage::collect_usage(None);
+    let report = usage::summarize(&records, None);
+    if report.totals.messages == 0 {
+        return Ok(serde_json::json!({
+            "usage": null,
+            "recentActivity": []
+        }).to_string());
+    }
+
+    // 최근 사용 모델/채널 활동 (최신순)
+    let recent_activity: Vec<String> = records
+        .iter()
+        .rev()
+        .take(10)
+        .map(|r| format!(
+            "{} · {} · {}/{} · {} 토큰",
+            format_timestamp_ms(r.timestamp_ms),
+            r.channel,
+            r.provider,
+            r.model,
+            r.total_tokens()
+        ))
+        .collect();
+
     Ok(serde_json::json!({
-        "usage": null,
-        "recentActivity": []
+        "usage": {
+            "tokensUsed": report.totals.total_tokens,
+            "messagesCount": report.totals.messages,
+            "lastActive": report.last_active_ms.map(format_timestamp_ms).unwrap_or_default(),
+            "costUsd": report.totals.cost_usd,
+        },
+        "recentActivity": recent_activity
     }).to_string())
 }
 
@@ -2290,6 +2411,57 @@ pub fn run() {
             generate_gateway_token,
             add_model_to_config,
             add_channel_to_config,
+            list_channel_accounts,
+            remove_channel_account,
+            channel_api::verify_channel_token,
+            // 페어링 요청 / 허용 목록
+            pairing::list_pairing_requests,
+            pairing::approve_pairing_request,
+            pairing::deny_pairing_request,
+            pairing::get_allowlist,
+            pairing::add_allowlist_entry,
+            pairing::remove_allowlist_entry,
+            pairing::set_allowlist_label,
+            // 그룹/길드별 정책
+            group_policies::list_group_policies,
+            group_policies::add_group_policy,
+            group_policies::update_group_policy,
+            group_policies::remove_group_policy,
+            // 채널 스키마 / 플러그인 채널 (Signal, Matrix, LINE, iMessage)
+            channel_schema::get_channel_schema,
+            channel_schema::verify_channel_setup,
+            channel_schema::configure_channel,
+            channel_schema::start_signal_link,
+            channel_schema::cancel_signal_link,
+            // WhatsApp 앱 내 QR 로그인 / 세션 관리
+            whatsapp_login::start_whatsapp_qr_login,
+            whatsapp_login::cancel_whatsapp_qr_login,
+            whatsapp_login::get_whatsapp_session,
+            whatsapp_login::list_whatsapp_sessions,
+            whatsapp_login::logout_whatsapp,
+            whatsapp_login::relink_whatsapp,
+            // Google Chat 서비스 계정
+            googlechat::validate_googlechat_service_account,
+            googlechat::import_googlechat_service_account,
+            googlechat::get_googlechat_service_account,
+            googlechat::test_googlechat_service_account,
+            googlechat::remove_googlechat_service_account,
+            // Slack 앱 매니페스트
+            slack_manifest::generate_slack_manifest,
+            slack_manifest::validate_slack_tokens,
+            // Discord 초대 URL / Intent
+            discord_setup::get_discord_invite,
+            // Mattermost 서버 확인
+            mattermost::normalize_mattermost_url,
+            mattermost::check_mattermost_server,
+            mattermost::set_mattermost_ca_cert,
+            // 채널 테스트 메시지
+            channel_test::send_test_message,
+            // 스킬 레지스트리
+            skill_registry::get_skill_registry_status,
+            skill_registry::validate_skill_registry,
+            skill_registry::set_skill_registry_remote,
+            skill_registry::refresh_skill_registry,
             // 설정 (레거시)
             configure_model,
             configure_gateway,
@@ -2325,6 +2497,12 @@ pub fn run() {
             get_config_summary,
             generate_token,
             is_onboarding_completed,
+            // 다중 모델 / fallback / 역할별 모델
+            get_model_chain,
+            add_provider_model,
+            remove_provider_model,
+            set_model_fallbacks,
+            set_role_model,
             // 유틸
             get_os_type,
             get_node_install_url,
@@ -2358,6 +2536,29 @@ pub fn run() {
             clear_gateway_logs,
             get_channel_status,
             get_usage_stats,
+            usage::get_usage_report,
+            budgets::get_budgets,
+            budgets::set_budgets,
+            budgets::get_budget_status,
+            budgets::resume_budget,
+            // 모델 카탈로그 / 프로바이더
+            model_catalog::get_model_catalog,
+            provider_api::validate_api_key,
+            provider_api::list_provider_models,
+            custom_providers::add_custom_provider,
+            custom_providers::list_custom_providers,
+            custom_providers::remove_custom_provider,
+            custom_providers::discover_local_providers,
+            // 인증 프로필
+            auth_profiles::list_auth_profiles,
+            auth_profiles::add_auth_profile,
+            auth_profiles::remove_auth_profile,
+            auth_profiles::set_auth_profile_order,
+            auth_profiles::set_active_auth_profile,
+            auth_profiles::validate_auth_profile,
+            anthropic_oauth::start_anthropic_oauth,
+            anthropic_oauth::cancel_anthropic_oauth,
+            anthropic_oauth::refresh_anthropic_oauth,
             // 특수 채널 설정
             set_slack_app_token,
             set_googlechat_service_account,
@@ -2393,16 +2594,40 @@ pub fn run() {
             skills::delete_camsnap_camera,
             skills::save_obsidian_vault,
             skills::get_obsidian_vault,
+            // 대화 기록
+            sessions::list_sessions,
+            sessions::get_session_messages,
+            sessions::search_sessions,
+            sessions::export_session,
+            sessions::delete_session,
+            // 데이터 보존 정책
+            retention::get_retention_policy,
+            retention::set_retention_policy,
+            retention::apply_retention_policy,
+            retention::wipe_peer_data,
+            // 워크스페이스 버전 관리
+            workspace_git::get_workspace_versioning_status,
+            workspace_git::enable_workspace_versioning,
+            workspace_git::disable_workspace_versioning,
+            workspace_git::commit_workspace_changes,
+            workspace_git::get_workspace_history,
+            workspace_git::get_workspace_diff,
+            workspace_git::restore_workspace_file,
+            workspace_git::restore_workspace_version,
             // 앱 삭제
             uninstall_moldclaw_only,
             uninstall_with_openclaw,
         ])
-        .setup(|_app| {
+        .setup(|app| {
             eprintln!("moldClaw 시작됨");
             #[cfg(windows)]
             eprintln!("winget 기반 설치 모드 (node-portable 번들 없음)");
             #[cfg(target_os = "macos")]
             eprintln!("macOS 모드 - PATH: {}", &get_macos_path()[..get_macos_path().len().min(120)]);
+            workspace_git::start_auto_commit_loop();
+            retention::start_retention_loop();
+            anthropic_oauth::start_refresh_loop(app.handle().clone());
+            budgets::start_budget_loop(app.handle().clone());
             Ok(())
         })
         .on_window_event(|_window, event| {
@@ -0,0 +1,422 @@
+// Mattermost 서버 주소 정규화 / 연결 확인
+// - normalize_base_url: 스킴 보완, 끝 슬래시 제거, 팀/채널/API 경로 제거 (서브패스 설치는 유지)
+// - check_mattermost_server: /api/v4/system/ping 으로 접속 + 서버 버전 호환성 확인
+// - 사설 인증서(self-signed) 서버: CA 인증서를 moldClaw 상태 디렉토리에 복사해 NODE_EXTRA_CA_CERTS로 게이트웨이에 전달
+//   (다른 곳에서 설정한 NODE_EXTRA_CA_CERTS는 덮어쓰거나 지우지 않음)
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::openclaw::{
+    get_moldclaw_state_dir, normalize_account_id, read_existing_config, set_nested_value, write_config,
+};
+
+/// 지원하는 최소 서버 버전 (REST API v4 + 봇 계정)
+const MIN_SERVER_VERSION: (u32, u32) = (7, 0);
+const PING_TIMEOUT: Duration = Duration::from_secs(10);
+/// Node.js 추가 CA 인증서 환경변수 (게이트웨이 재시작 후 적용)
+const CA_CERTS_ENV: &str = "NODE_EXTRA_CA_CERTS";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MattermostServerStatus {
+    pub base_url: String,
+    pub reachable: bool,
+    pub http_status: Option<u16>,
+    /// 서버 버전 (X-Version-Id 헤더 기준, 예: "9.11.2")
+    pub version: Option<String>,
+    pub min_version: String,
+    /// 버전을 모르면 None
+    pub compatible: Option<bool>,
+    pub latency_ms: Option<u64>,
+    /// "system" | "custom_ca" | "insecure"
+    pub tls: &'static str,
+    pub message: String,
+}
+
+/// Mattermost 서버 주소 정규화
+/// "chat.example.com/" → "https://chat.example.com"
+/// "https://chat.example.com/myteam/channels/town-square" → "https://chat.example.com"
+/// "https://example.com/mattermost/api/v4/" → "https://example.com/mattermost"
+pub fn normalize_base_url(input: &str) -> Result<String, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err("URL이 비어있습니다.".to_string());
+    }
+    let with_scheme = if input.contains("://") {
+        input.to_string()
+    } else {
+        format!("https://{}", input)
+    };
+    let mut url = reqwest::Url::parse(&with_scheme)
+        .map_err(|e| format!("올바른 서버 주소가 아닙니다 ({}): {}", input, e))?;
+    if url.scheme() != "https" && url.scheme() != "http" {
+        return Err(format!("http 또는 https 주소만 사용할 수 있습니다: {}", url.scheme()));
+    }
+    if url.host_str().unwrap_or_default().is_empty() {
+        return Err(format!("서버 주소에 호스트가 없습니다: {}", input));
+    }
+    url.set_query(None);
+    url.set_fragment(None);
+    let _ = url.set_username("");
+    let _ = url.set_password(None);
+
+    // 브라우저 주소를 붙여넣은 경우: API / 로그인 / 팀 채널 경로 제거
+    let segments: Vec<String> = url
+        .path_segments()
+        .map(|s| s.filter(|p| !p.is_empty()).map(String::from).collect())
+        .unwrap_or_default();
+    let cut = segments
+        .iter()
+        .position(|s| s == "api" || s == "login" || s == "signup_user_complete" || s == "admin_console")
+        .or_else(|| {
+            // <팀>/channels/<채널>, <팀>/messages/@<사용자>, <팀>/pl/<글>
+            segments
+                .iter()
+                .position(|s| s == "channels" || s == "messages" || s == "pl" || s == "threads")
+                .map(|i| i.saturating_sub(1))
+        })
+        .unwrap_or(segments.len());
+    url.set_path(&segments[..cut].join("/"));
+
+    Ok(url.as_str().trim_end_matches('/').to_string())
+}
+
+/// "9.11.2.9.11.2.abc123.false" → (9, 11, "9.11.2")
+fn parse_version(header: &str) -> Option<(u32, u32, String)> {
+    let parts: Vec<&str> = header.trim().split('.').collect();
+    let major = parts.first()?.parse().ok()?;
+    let minor = parts.get(1)?.parse().ok()?;
+    let patch = parts.get(2).and_then(|p| p.parse::<u32>().ok()).unwrap_or(0);
+    Some((major, minor, format!("{}.{}.{}", major, minor, patch)))
+}
+
+/// 저장된 CA 인증서 경로 (env.vars.NODE_EXTRA_CA_CERTS)
+fn stored_ca_cert_path(config: &Value) -> Option<String> {
+    config
+        .pointer(&format!("/env/vars/{}", CA_CERTS_ENV))
+        .and_then(|v| v.as_str())
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+}
+
+fn load_ca_certificate(path: &str) -> Result<reqwest::Certificate, String> {
+    let expanded = shellexpand::tilde(path.trim()).to_string();
+    let pem = std::fs::read(&expanded)
+        .map_err(|e| format!("CA 인증서를 읽을 수 없습니다 ({}): {}", expanded, e))?;
+    if !String::from_utf8_lossy(&pem).contains("-----BEGIN CERTIFICATE-----") {
+        return Err("CA 인증서는 PEM 형식이어야 합니다 (-----BEGIN CERTIFICATE-----)".to_string());
+    }
+    reqwest::Certificate::from_pem(&pem).map_err(|e| format!("CA 인증서 형식 오류: {}", e))
+}
+
+/// moldClaw가 관리하는 CA 인증서 사본 경로
+fn managed_ca_path() -> PathBuf {
+    get_moldclaw_state_dir().join("mattermost").join("ca.pem")
+}
+
+/// 설정의 NODE_EXTRA_CA_CERTS를 moldClaw 사본으로 지정(enable=true)하거나 해제
+/// 다른 경로가 들어 있으면 다른 프로그램/사용자가 설정한 값이므로 건드리지 않고 오류
+fn apply_ca_setting(config: &mut Value, managed: &Path, enable: bool) -> Result<(), String> {
+    let current = stored_ca_cert_path(config);
+    if let Some(foreign) = current.as_deref().filter(|c| Path::new(c) != managed) {
+        return Err(if enable {
+            format!(
+                "{}가 이미 다른 인증서로 설정되어 있습니다 ({}). 함께 사용하려면 두 PEM을 하나의 파일로 합쳐 직접 지정하세요",
+                CA_CERTS_ENV, foreign
+            )
+        } else {
+            format!("moldClaw가 설정하지 않은 {} 값은 삭제하지 않습니다 ({})", CA_CERTS_ENV, foreign)
+        });
+    }
+    if enable {
+        set_nested_value(config, &["env", "vars", CA_CERTS_ENV], json!(managed.to_string_lossy()));
+    } else if current.is_some() {
+        if let Some(vars) = config.pointer_mut("/env/vars").and_then(|v| v.as_object_mut()) {
+            vars.remove(CA_CERTS_ENV);
+        }
+    }
+    Ok(())
+}
+
+/// Mattermost 요청용 HTTP 클라이언트와 TLS 방식 ("system" | "custom_ca" | "insecure")
+/// ca_cert_path를 비우면 저장된 CA 인증서를 신뢰 (사설 인증서 서버도 토큰 검증 가능)
+pub fn mattermost_client(
+    ca_cert_path: Option<&str>,
+    allow_invalid_certs: bool,
+) -> Result<(reqwest::Client, &'static str), String> {
+    let ca_cert_path = ca_cert_path
+        .filter(|p| !p.trim().is_empty())
+        .map(String::from)
+        .or_else(|| stored_ca_cert_path(&read_existing_config()));
+    build_client(ca_cert_path.as_deref(), allow_invalid_certs)
+}
+
+/// 지정한 CA 인증서로 HTTP 클라이언트 생성 (저장된 설정은 읽지 않음)
+fn build_client(
+    ca_cert_path: Option<&str>,
+    allow_invalid_certs: bool,
+) -> Result<(reqwest::Client, &'static str), String> {
+    let mut builder = reqwest::Client::builder().timeout(PING_TIMEOUT);
+    let tls = if allow_invalid_certs {
+        builder = builder.danger_accept_invalid_certs(true);
+        "insecure"
+    } else if let Some(path) = ca_cert_path {
+        builder = builder.add_root_certificate(load_ca_certificate(path)?);
+        "custom_ca"
+    } else {
+        "system"
+    };
+    let client = builder.build().map_err(|e| format!("HTTP 클라이언트 생성 실패: {}", e))?;
+    Ok((client, tls))
+}
+
+/// /api/v4/system/ping 으로 접속 + 서버 버전 확인
+async fn ping_server(client: &reqwest::Client, tls: &'static str, base_url: String) -> MattermostServerStatus {
+    let min_version = format!("{}.{}", MIN_SERVER_VERSION.0, MIN_SERVER_VERSION.1);
+
+    let started = Instant::now();
+    let response = match client.get(format!("{}/api/v4/system/ping", base_url)).send().await {
+        Ok(r) => r,
+        Err(e) => {
+            let detail = e.to_string();
+            let message = if detail.contains("certificate") || detail.contains("SSL") || detail.contains("TLS") {
+                format!("인증서를 확인할 수 없습니다. 사설 인증서라면 CA 인증서를 지정하세요: {}", detail)
+            } else {
+                format!("서버에 연결할 수 없습니다: {}", detail)
+            };
+            return MattermostServerStatus {
+                base_url,
+                reachable: false,
+                http_status: None,
+                version: None,
+                min_version,
+                compatible: None,
+                latency_ms: None,
+                tls,
+                message,
+            };
+        }
+    };
+    let latency_ms = Some(started.elapsed().as_millis() as u64);
+    let http_status = response.status().as_u16();
+    let version = response
+        .headers()
+        .get("x-version-id")
+        .and_then(|h| h.to_str().ok())
+        .and_then(parse_version);
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    let status_ok = body.get("status").and_then(|s| s.as_str()) == Some("OK");
+
+    let compatible = version
+        .as_ref()
+        .map(|(major, minor, _)| (*major, *minor) >= MIN_SERVER_VERSION);
+    let message = if !status_ok {
+        format!("Mattermost 서버가 아니거나 응답이 올바르지 않습니다 (HTTP {})", http_status)
+    } else {
+        match (&version, compatible) {
+            (Some((_, _, v)), Some(false)) => {
+                format!("서버 버전 {}은(는) 지원하지 않습니다. {} 이상으로 업그레이드하세요", v, min_version)
+            }
+            (Some((_, _, v)), _) => format!("Mattermost {} 서버에 연결되었습니다", v),
+            (None, _) => "서버에 연결되었지만 버전을 확인할 수 없습니다".to_string(),
+        }
+    };
+
+    MattermostServerStatus {
+        base_url,
+        reachable: status_ok,
+        http_status: Some(http_status),
+        version: version.map(|(_, _, v)| v),
+        min_version,
+        compatible,
+        latency_ms,
+        tls,
+        message,
+    }
+}
+
+// ===== 커맨드 =====
+
+/// 서버 주소 정규화 결과 미리보기
+#[tauri::command]
+pub fn normalize_mattermost_url(url: String) -> Result<String, String> {
+    normalize_base_url(&url)
+}
+
+/// Mattermost 서버 접속 + 버전 확인
+/// url을 비우면 저장된 baseUrl 사용. ca_cert_path를 비우면 저장된 CA 인증서 사용
+/// allow_invalid_certs=true는 진단용 (인증서 검증 생략, 저장되지 않음)
+#[tauri::command]
+pub async fn check_mattermost_server(
+    url: Option<String>,
+    account_id: Option<String>,
+    ca_cert_path: Option<String>,
+    allow_invalid_certs: Option<bool>,
+) -> Result<MattermostServerStatus, String> {
+    let account_id = normalize_account_id(account_id.as_deref())?;
+    let config = read_existing_config();
+    let url = match url.filter(|u| !u.trim().is_empty()) {
+        Some(u) => u,
+        None => {
+            let pointer = match account_id.as_deref() {
+                Some(id) => format!("/channels/mattermost/accounts/{}/baseUrl", id),
+                None => "/channels/mattermost/baseUrl".to_string(),
+            };
+            config
+                .pointer(&pointer)
+                .or_else(|| config.pointer("/channels/mattermost/baseUrl"))
+                .and_then(|v| v.as_str())
+                .map(String::from)
+                .ok_or("Mattermost 서버 주소가 설정되지 않았습니다")?
+        }
+    };
+    let base_url = normalize_base_url(&url)?;
+
+    let (client, tls) = mattermost_client(ca_cert_path.as_deref(), allow_invalid_certs.unwrap_or(false))?;
+    Ok(ping_server(&client, tls, base_url).await)
+}
+
+/// 사설 인증서 CA 설정 (env.vars.NODE_EXTRA_CA_CERTS, 게이트웨이 재시작 후 적용)
+/// 인증서는 moldClaw 상태 디렉토리에 복사해 사용. ca_cert_path를 비우면 moldClaw가 설정한 값만 삭제
+#[tauri::command]
+pub fn set_mattermost_ca_cert(ca_cert_path: Option<String>) -> Result<(), String> {
+    let mut config = read_existing_config();
+    let managed = managed_ca_path();
+    match ca_cert_path.map(|p| p.trim().to_string()).filter(|p| !p.is_empty()) {
+        Some(path) => {
+            load_ca_certificate(&path)?;
+            apply_ca_setting(&mut config, &managed, true)?;
+            let expanded = shellexpand::tilde(&path).to_string();
+            if let Some(dir) = managed.parent() {
+                std::fs::create_dir_all(dir).map_err(|e| format!("디렉토리 생성 실패: {}", e))?;
+            }
+            std::fs::copy(&expanded, &managed).map_err(|e| format!("CA 인증서 복사 실패: {}", e))?;
+            write_config(&config)
+        }
+        None => {
+            apply_ca_setting(&mut config, &managed, false)?;
+            write_config(&config)?;
+            match std::fs::remove_file(&managed) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => {
+                    Err(format!("CA 인증서 사본 삭제 실패: {}", e))
+                }
+                _ => Ok(()),
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_base_url_strips_browser_paths() {
+        assert_eq!(normalize_base_url("chat.example.com/").unwrap(), "https://chat.example.com");
+        assert_eq!(
+            normalize_base_url("https://chat.example.com/myteam/channels/town-square").unwrap(),
+            "https://chat.example.com"
+        );
+        assert_eq!(
+            normalize_base_url("https://example.com/mattermost/api/v4/").unwrap(),
+            "https://example.com/mattermost"
+        );
+        assert_eq!(
+            normalize_base_url("http://user:pw@chat.local:8065/login?redirect=1#x").unwrap(),
+            "http://chat.local:8065"
+        );
+        assert!(normalize_base_url("  ").is_err());
+        assert!(normalize_base_url("ftp://chat.example.com").is_err());
+    }
+
+    #[test]
+    fn parse_version_reads_header() {
+        assert_eq!(parse_version("9.11.2.9.11.2.abc123.false"), Some((9, 11, "9.11.2".to_string())));
+        assert_eq!(parse_version("10.0"), Some((10, 0, "10.0.0".to_string())));
+        assert_eq!(parse_version("dev"), None);
+    }
+
+    async fn check(url: String) -> MattermostServerStatus {
+        let (client, tls) = build_client(None, false).unwrap();
+        ping_server(&client, tls, normalize_base_url(&url).unwrap()).await
+    }
+
+    #[tokio::test]
+    async fn check_server_against_mock() {
+        let mut server = mockito::Server::new_async().await;
+        let ping = server
+            .mock("GET", "/api/v4/system/ping")
+            .with_status(200)
+            .with_header("X-Version-Id", "9.11.2.9.11.2.abc123.false")
+            .with_body(r#"{"status":"OK"}"#)
+            .create_async()
+            .await;
+
+        let status = check(server.url()).await;
+        assert!(status.reachable);
+        assert_eq!(status.http_status, Some(200));
+        assert_eq!(status.version.as_deref(), Some("9.11.2"));
+        assert_eq!(status.compatible, Some(true));
+        ping.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn check_server_reports_old_or_foreign_servers() {
+        let mut old = mockito::Server::new_async().await;
+        old.mock("GET", "/api/v4/system/ping")
+            .with_status(200)
+            .with_header("X-Version-Id", "6.3.0")
+            .with_body(r#"{"status":"OK"}"#)
+            .create_async()
+            .await;
+        let status = check(old.url()).await;
+        assert_eq!(status.compatible, Some(false));
+
+        let mut foreign = mockito::Server::new_async().await;
+        foreign
+            .mock("GET", "/api/v4/system/ping")
+            .with_status(404)
+            .with_body("not found")
+            .create_async()
+            .await;
+        let status = check(foreign.url()).await;
+        assert!(!status.reachable);
+        assert_eq!(status.http_status, Some(404));
+    }
+
+    #[test]
+    fn client_tls_mode_follows_the_given_certificate() {
+        assert_eq!(build_client(None, false).unwrap().1, "system");
+        assert_eq!(build_client(None, true).unwrap().1, "insecure");
+
+        let dir = tempfile::tempdir().unwrap();
+        let not_pem = dir.path().join("ca.crt");
+        std::fs::write(&not_pem, "not a certificate").unwrap();
+        assert!(build_client(Some(not_pem.to_str().unwrap()), false).is_err());
+        assert!(build_client(Some(dir.path().join("missing.pem").to_str().unwrap()), false).is_err());
+    }
+
+    #[test]
+    fn ca_setting_only_touches_the_managed_copy() {
+        let managed = Path::new("/home/me/.openclaw/moldclaw/mattermost/ca.pem");
+        let mut config = json!({ "gateway": {} });
+        apply_ca_setting(&mut config, managed, true).unwrap();
+        assert_eq!(stored_ca_cert_path(&config).as_deref(), managed.to_str());
+        // 다시 지정해도 같은 사본을 가리킴
+        apply_ca_setting(&mut config, managed, true).unwrap();
+        apply_ca_setting(&mut config, managed, false).unwrap();
+        assert_eq!(stored_ca_cert_path(&config), None);
+        // 설정이 없을 때 해제는 변경 없음
+        apply_ca_setting(&mut config, managed, false).unwrap();
+
+        let mut foreign = json!({ "env": { "vars": { "NODE_EXTRA_CA_CERTS": "/etc/ssl/corp-root.pem" } } });
+        let before = foreign.clone();
+        assert!(apply_ca_setting(&mut foreign, managed, true).is_err());
+        assert!(apply_ca_setting(&mut foreign, managed, false).is_err());
+        assert_eq!(foreign, before);
+    }
+}
@@ -0,0 +1,287 @@
+// 모델 카탈로그
+// 번들 카탈로그(resources/model_catalog.json)를 기본으로 하고,
+// ~/.openclaw/moldclaw/model_catalog.json 이 있으면 프로바이더/모델 단위로 덮어씁니다.
+// 비용 단위: USD / 1M 토큰
+// 병합 결과는 메모리에 캐시하고, 사용자 카탈로그 파일의 수정 시각이 바뀔 때만 다시 읽습니다.
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::SystemTime;
+
+use crate::openclaw::get_moldclaw_state_dir;
+
+/// 앱에 포함된 기본 카탈로그
+const BUNDLED_CATALOG: &str = include_str!("../resources/model_catalog.json");
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelCost {
+    #[serde(default)]
+    pub input: f64,
+    #[serde(default)]
+    pub output: f64,
+    #[serde(default)]
+    pub cache_read: f64,
+    #[serde(default)]
+    pub cache_write: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogModel {
+    pub id: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    #[serde(default)]
+    pub reasoning: bool,
+    #[serde(default = "default_input")]
+    pub input: Vec<String>,
+    #[serde(default)]
+    pub cost: ModelCost,
+    pub context_window: u64,
+    pub max_tokens: u64,
+}
+
+fn default_input() -> Vec<String> {
+    vec!["text".to_string()]
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogProvider {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(default)]
+    pub models: Vec<CatalogModel>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelCatalog {
+    pub version: u32,
+    #[serde(default)]
+    pub updated_at: String,
+    #[serde(default)]
+    pub providers: BTreeMap<String, CatalogProvider>,
+}
+
+impl CatalogModel {
+    fn matches(&self, model: &str) -> bool {
+        self.id == model || self.aliases.iter().any(|a| a == model)
+    }
+
+    /// OpenClaw models.providers.<p>.models[] 항목 형식 (id는 요청한 그대로 유지)
+    pub fn to_config_entry(&self, id: &str) -> Value {
+        json!({
+            "id": id,
+            "name": self.name,
+            "reasoning": self.reasoning,
+            "input": self.input,
+            "cost": {
+                "input": self.cost.input,
+                "output": self.cost.output,
+                "cacheRead": self.cost.cache_read,
+                "cacheWrite": self.cost.cache_write,
+            },
+            "contextWindow": self.context_window,
+            "maxTokens": self.max_tokens
+        })
+    }
+}
+
+fn user_catalog_path() -> PathBuf {
+    get_moldclaw_state_dir().join("model_catalog.json")
+}
+
+/// 번들 카탈로그 (형식 오류면 빈 카탈로그로 동작, 테스트에서 형식 검증)
+static BUNDLED: Lazy<ModelCatalog> = Lazy::new(|| {
+    serde_json::from_str(BUNDLED_CATALOG).unwrap_or_else(|e| {
+        eprintln!("번들 모델 카탈로그 형식 오류: {}", e);
+        ModelCatalog::default()
+    })
+});
+
+/// 병합된 카탈로그 캐시 (사용자 카탈로그 수정 시각, 결과)
+type MergedCache = Option<(Option<SystemTime>, Arc<ModelCatalog>)>;
+static MERGED: Lazy<Mutex<MergedCache>> = Lazy::new(|| Mutex::new(None));
+
+fn bundled_catalog() -> ModelCatalog {
+    BUNDLED.clone()
+}
+
+fn user_catalog_mtime() -> Option<SystemTime> {
+    std::fs::metadata(user_catalog_path()).and_then(|m| m.modified()).ok()
+}
+
+/// 사용자 카탈로그 읽기 (없거나 형식이 잘못되면 None)
+fn user_catalog() -> Option<ModelCatalog> {
+    let content = std::fs::read_to_string(user_catalog_path()).ok()?;
+    match serde_json::from_str(&content) {
+        Ok(catalog) => Some(catalog),
+        Err(e) => {
+            eprintln!("사용자 모델 카탈로그 파싱 실패 (무시): {}", e);
+            None
+        }
+    }
+}
+
+/// 번들 + 사용자 카탈로그 병합 결과 (캐시, 사용자 파일이 바뀌었을 때만 다시 병합)
+pub fn load_catalog() -> Arc<ModelCatalog> {
+    let mtime = user_catalog_mtime();
+    let mut cache = MERGED.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some((cached_mtime, catalog)) = cache.as_ref() {
+        if *cached_mtime == mtime {
+            return catalog.clone();
+        }
+    }
+    let catalog = Arc::new(merge_catalog());
+    *cache = Some((mtime, catalog.clone()));
+    catalog
+}
+
+fn merge_catalog() -> ModelCatalog {
+    let mut catalog = bundled_catalog();
+    let Some(user) = user_catalog() else {
+        return catalog;
+    };
+
+    catalog.version = catalog.version.max(user.version);
+    if !user.updated_at.is_empty() {
+        catalog.updated_at = user.updated_at;
+    }
+    for (name, user_provider) in user.providers {
+        let provider = catalog.providers.entry(name).or_default();
+        if user_provider.base_url.is_some() {
+            provider.base_url = user_provider.base_url;
+        }
+        if user_provider.api.is_some() {
+            provider.api = user_provider.api;
+        }
+        if user_provider.note.is_some() {
+            provider.note = user_provider.note;
+        }
+        for model in user_provider.models {
+            match provider.models.iter_mut().find(|m| m.id == model.id) {
+                Some(existing) => *existing = model,
+                None => provider.models.push(model),
+            }
+        }
+    }
+    catalog
+}
+
+/// 프로바이더 정보 조회
+pub fn find_provider(provider: &str) -> Option<CatalogProvider> {
+    load_catalog().providers.get(provider).cloned()
+}
+
+/// 모델 조회 (id 또는 별칭). 해당 프로바이더에 없으면 다른 프로바이더에서 같은 id 검색
+pub fn find_model(provider: &str, model: &str) -> Option<CatalogModel> {
+    let catalog = load_catalog();
+    if let Some(found) = catalog
+        .providers
+        .get(provider)
+        .and_then(|p| p.models.iter().find(|m| m.matches(model)))
+    {
+        return Some(found.clone());
+    }
+    catalog
+        .providers
+        .values()
+        .flat_map(|p| p.models.iter())
+        .find(|m| m.matches(model))
+        .cloned()
+}
+
+/// openclaw.json에 넣을 모델 항목 생성 (카탈로그에 없으면 보수적인 기본값)
+pub fn model_config_entry(provider: &str, model: &str) -> Value {
+    match find_model(provider, model) {
+        Some(entry) => entry.to_config_entry(model),
+        None => json!({
+            "id": model,
+            "name": model,
+            "reasoning": false,
+            "input": ["text"],
+            "cost": { "input": 0, "output": 0, "cacheRead": 0, "cacheWrite": 0 },
+            "contextWindow": 128000,
+            "maxTokens": 8192
+        }),
+    }
+}
+
+/// 토큰 사용량으로 비용 추정 (USD). 카탈로그에 없는 모델은 None
+pub fn estimate_cost(
+    provider: &str,
+    model: &str,
+    input: u64,
+    output: u64,
+    cache_read: u64,
+    cache_write: u64,
+) -> Option<f64> {
+    let cost = find_model(provider, model)?.cost;
+    let per_token = |tokens: u64, price: f64| tokens as f64 * price / 1_000_000.0;
+    Some(
+        per_token(input, cost.input)
+            + per_token(output, cost.output)
+            + per_token(cache_read, cost.cache_read)
+            + per_token(cache_write, cost.cache_write),
+    )
+}
+
+// ===== Tauri 커맨드 =====
+
+/// 병합된 모델 카탈로그 조회
+#[tauri::command]
+pub fn get_model_catalog() -> Value {
+    let bundled_version = BUNDLED.version;
+    let user = user_catalog();
+    json!({
+        "bundledVersion": bundled_version,
+        "userVersion": user.as_ref().map(|c| c.version),
+        "userCatalogPath": user_catalog_path().to_string_lossy(),
+        "catalog": load_catalog(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn bundled_catalog_is_valid() {
+        let catalog: ModelCatalog = serde_json::from_str(BUNDLED_CATALOG).expect("번들 카탈로그 파싱 실패");
+        assert!(catalog.version > 0);
+        assert!(!catalog.providers.is_empty());
+        for (name, provider) in &catalog.providers {
+            let mut ids = HashSet::new();
+            for model in &provider.models {
+                assert!(ids.insert(model.id.as_str()), "{}: 중복 모델 {}", name, model.id);
+                assert!(!model.name.is_empty(), "{}/{}: 이름 없음", name, model.id);
+                assert!(model.context_window > 0, "{}/{}: contextWindow", name, model.id);
+                assert!(
+                    model.max_tokens > 0 && model.max_tokens <= model.context_window,
+                    "{}/{}: maxTokens",
+                    name,
+                    model.id
+                );
+                let cost = &model.cost;
+                assert!(
+                    [cost.input, cost.output, cost.cache_read, cost.cache_write].iter().all(|c| *c >= 0.0),
+                    "{}/{}: 음수 비용",
+                    name,
+                    model.id
+                );
+            }
+        }
+    }
+}
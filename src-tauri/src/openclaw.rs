@@ -162,7 +162,7 @@ use base64::{engine::general_purpose::STANDARD, Engine};
 const OPENCLAW_VERSION: &str = "2026.2.10";
 
 /// OpenClaw 명령 실행 헬퍼 (시스템 PATH 사용)
-fn run_openclaw_command(args: &[&str]) -> Result<String, String> {
+pub fn run_openclaw_command(args: &[&str]) -> Result<String, String> {
     #[cfg(windows)]
     {
         use std::os::windows::process::CommandExt;
@@ -305,7 +305,7 @@ pub async fn install_openclaw() -> Result<String, String> {
 }
 
 /// OpenClaw 설정 디렉토리
-fn get_openclaw_dir() -> PathBuf {
+pub fn get_openclaw_dir() -> PathBuf {
     dirs::home_dir()
         .unwrap_or_default()
         .join(".openclaw")
@@ -325,10 +325,16 @@ fn has_required_config_keys(config: &serde_json::Value) -> bool {
 }
 
 /// Workspace 디렉토리 경로
-fn get_workspace_dir() -> PathBuf {
+pub fn get_workspace_dir() -> PathBuf {
     get_openclaw_dir().join("workspace")
 }
 
+/// moldClaw 전용 상태 디렉토리 (~/.openclaw/moldclaw)
+/// openclaw.json 스키마 밖에서 moldClaw가 관리하는 파일 저장용
+pub fn get_moldclaw_state_dir() -> PathBuf {
+    get_openclaw_dir().join("moldclaw")
+}
+
 /// Identity 디렉토리 경로
 fn get_identity_dir() -> PathBuf {
     get_openclaw_dir().join("identity")
@@ -671,7 +677,7 @@ pub async fn create_official_config(
 /// - OpenAI API 키: `sk-xxx`, `sk-proj-xxx` → "api_key"
 /// - Google API 키: `AIza...` → "api_key"
 /// - 기타 모든 경우 → "api_key" (기본값)
-fn detect_auth_mode(provider: &str, api_key: &str) -> &'static str {
+pub fn detect_auth_mode(provider: &str, api_key: &str) -> &'static str {
     match provider {
         "anthropic" => {
             // Anthropic OAuth 토큰: sk-ant-oat01- (최소 80자)
@@ -720,49 +726,11 @@ pub async fn add_model_to_config(
         );
     }
     
-    // 프로바이더별 baseUrl 설정
-    match provider {
-        "anthropic" => {
-            set_nested_value(
-                &mut config,
-                &["models", "providers", provider, "baseUrl"],
-                json!("https://api.anthropic.com"),
-            );
-            set_nested_value(
-                &mut config,
-                &["models", "providers", provider, "api"],
-                json!("anthropic-messages"),
-            );
-        }
-        "openai" => {
-            set_nested_value(
-                &mut config,
-                &["models", "providers", provider, "baseUrl"],
-                json!("https://api.openai.com/v1"),
-            );
-        }
-        "google" => {
-            set_nested_value(
-                &mut config,
-                &["models", "providers", provider, "baseUrl"],
-                json!("https://generativelanguage.googleapis.com/v1beta"),
-            );
-            set_nested_value(
-                &mut config,
-                &["models", "providers", provider, "api"],
-                json!("google-generative-ai"),
-            );
-        }
-        _ => {}
-    }
+    // 프로바이더별 baseUrl/api 설정 (모델 카탈로그 기준)
+    apply_provider_endpoint(&mut config, provider);
     
-    // 모델 정보 추가
-    let model_info = create_model_info(model);
-    set_nested_value(
-        &mut config,
-        &["models", "providers", provider, "models"],
-        json!([model_info]),
-    );
+    // 모델 정보 추가 (기존 모델 목록 유지, 같은 id만 갱신)
+    upsert_provider_model(&mut config, provider, create_model_info(provider, model));
     
     // agents.defaults.model.primary 설정
     let model_string = format!("{}/{}", provider, model);
@@ -774,6 +742,7 @@ pub async fn add_model_to_config(
     
     // auth.profiles 추가
     // 토큰 형식에 따라 mode 자동 결정
+    // 키 없이 모델만 바꾸는 경우 기존 mode 유지 (token → api_key 덮어쓰기 방지)
     let auth_mode = detect_auth_mode(provider, api_key);
     let profile_id = format!("{}:default", provider);
     set_nested_value(
@@ -781,77 +750,352 @@ pub async fn add_model_to_config(
         &["auth", "profiles", &profile_id, "provider"],
         json!(provider),
     );
-    set_nested_value(
-        &mut config,
-        &["auth", "profiles", &profile_id, "mode"],
-        json!(auth_mode),
-    );
+    let has_mode = config
+        .pointer(&format!("/auth/profiles/{}/mode", pointer_escape(&profile_id)))
+        .is_some();
+    if !api_key.is_empty() || !has_mode {
+        set_nested_value(
+            &mut config,
+            &["auth", "profiles", &profile_id, "mode"],
+            json!(auth_mode),
+        );
+    }
     
     write_config(&config)?;
     Ok(())
 }
 
-/// 모델 정보 JSON 생성
-fn create_model_info(model: &str) -> Value {
-    match model {
-        "claude-sonnet-4-20250514" => json!({
-            "id": "claude-sonnet-4-20250514",
-            "name": "Claude Sonnet 4",
-            "reasoning": false,
-            "input": ["text"],
-            "cost": { "input": 0, "output": 0, "cacheRead": 0, "cacheWrite": 0 },
-            "contextWindow": 200000,
-            "maxTokens": 8192
-        }),
-        "claude-haiku-4-5-20251001" => json!({
-            "id": "claude-haiku-4-5-20251001", 
-            "name": "Claude Haiku 4.5",
-            "reasoning": false,
-            "input": ["text"],
-            "cost": { "input": 0, "output": 0, "cacheRead": 0, "cacheWrite": 0 },
-            "contextWindow": 200000,
-            "maxTokens": 8192
-        }),
-        "claude-opus-4-20250514" => json!({
-            "id": "claude-opus-4-20250514",
-            "name": "Claude Opus 4",
-            "reasoning": false,
-            "input": ["text"],
-            "cost": { "input": 0, "output": 0, "cacheRead": 0, "cacheWrite": 0 },
-            "contextWindow": 200000,
-            "maxTokens": 8192
-        }),
-        "gpt-4o" => json!({
-            "id": "gpt-4o",
-            "name": "GPT-4o",
-            "reasoning": false,
-            "input": ["text"],
-            "cost": { "input": 0, "output": 0, "cacheRead": 0, "cacheWrite": 0 },
-            "contextWindow": 128000,
-            "maxTokens": 16384
-        }),
-        "gpt-4o-mini" => json!({
-            "id": "gpt-4o-mini",
-            "name": "GPT-4o Mini",
-            "reasoning": false,
-            "input": ["text"],
-            "cost": { "input": 0, "output": 0, "cacheRead": 0, "cacheWrite": 0 },
-            "contextWindow": 128000,
-            "maxTokens": 16384
-        }),
-        _ => json!({
-            "id": model,
-            "name": model,
-            "reasoning": false,
-            "input": ["text"],
-            "cost": { "input": 0, "output": 0, "cacheRead": 0, "cacheWrite": 0 },
-            "contextWindow": 128000,
-            "maxTokens": 8192
+/// 프로바이더 모델 목록에 모델 추가 (같은 id가 있으면 교체, 없으면 뒤에 추가)
+pub fn upsert_provider_model(config: &mut Value, provider: &str, model_info: Value) {
+    let mut models = config
+        .pointer(&format!("/models/providers/{}/models", pointer_escape(provider)))
+        .and_then(|m| m.as_array())
+        .cloned()
+        .unwrap_or_default();
+
+    let id = model_info.get("id").cloned();
+    match models.iter_mut().find(|m| m.get("id") == id.as_ref()) {
+        Some(existing) => *existing = model_info,
+        None => models.push(model_info),
+    }
+    set_nested_value(config, &["models", "providers", provider, "models"], json!(models));
+}
+
+/// "provider/model" 참조가 가리키는 모델을 프로바이더 목록과 허용 목록에 등록
+/// (프로바이더 설정이 없는 참조는 OpenClaw 내장 카탈로그에 맡김)
+pub fn ensure_model_ref(config: &mut Value, model_ref: &str) -> Result<(), String> {
+    let Some((provider, model)) = model_ref.split_once('/') else {
+        return Err(format!("모델은 provider/model 형식이어야 합니다: {}", model_ref));
+    };
+    if provider.is_empty() || model.is_empty() {
+        return Err(format!("모델은 provider/model 형식이어야 합니다: {}", model_ref));
+    }
+
+    let provider_configured = config
+        .pointer(&format!("/models/providers/{}", pointer_escape(provider)))
+        .is_some();
+    let model_listed = config
+        .pointer(&format!("/models/providers/{}/models", pointer_escape(provider)))
+        .and_then(|m| m.as_array())
+        .map(|list| list.iter().any(|m| m.get("id").and_then(|v| v.as_str()) == Some(model)))
+        .unwrap_or(false);
+    if provider_configured && !model_listed {
+        upsert_provider_model(config, provider, create_model_info(provider, model));
+    }
+
+    // agents.defaults.models가 있으면 허용 목록으로 동작하므로 함께 등록
+    if let Some(allowlist) = config
+        .pointer_mut("/agents/defaults/models")
+        .and_then(|m| m.as_object_mut())
+    {
+        allowlist.entry(model_ref.to_string()).or_insert_with(|| json!({}));
+    }
+    Ok(())
+}
+
+/// 에이전트 설정 객체 (agent_id가 없으면 agents.defaults, 있으면 agents.list[id])
+fn agent_config_mut<'a>(config: &'a mut Value, agent_id: Option<&str>) -> Result<&'a mut Value, String> {
+    match agent_id.filter(|id| !id.is_empty() && *id != "defaults") {
+        None => {
+            if config.pointer("/agents/defaults").is_none() {
+                set_nested_value(config, &["agents", "defaults"], json!({}));
+            }
+            config
+                .pointer_mut("/agents/defaults")
+                .ok_or_else(|| "agents.defaults 설정을 만들 수 없습니다".to_string())
+        }
+        Some(id) => config
+            .pointer_mut("/agents/list")
+            .and_then(|l| l.as_array_mut())
+            .and_then(|list| {
+                list.iter_mut()
+                    .find(|a| a.get("id").and_then(|v| v.as_str()) == Some(id))
+            })
+            .ok_or_else(|| format!("에이전트를 찾을 수 없습니다: {}", id)),
+    }
+}
+
+/// JSON Pointer 경로 한 단계 이스케이프 (RFC 6901: ~ → ~0, / → ~1)
+pub fn pointer_escape(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+/// 역할별 모델 설정 경로 (에이전트 설정 기준, cron은 작업별 설정이라 별도 처리)
+fn role_model_path(role: &str) -> Result<&'static [&'static str], String> {
+    match role {
+        "chat" | "primary" => Ok(&["model", "primary"]),
+        "heartbeat" => Ok(&["heartbeat", "model"]),
+        "image" => Ok(&["imageModel", "primary"]),
+        "subagents" => Ok(&["subagents", "model"]),
+        _ => Err(format!("지원하지 않는 모델 역할입니다: {}", role)),
+    }
+}
+
+/// cron 작업 목록 (~/.openclaw/cron/jobs.json)
+fn read_cron_jobs() -> Vec<Value> {
+    std::fs::read_to_string(get_openclaw_dir().join("cron").join("jobs.json"))
+        .ok()
+        .and_then(|s| serde_json::from_str::<Value>(&s).ok())
+        .and_then(|v| v.get("jobs").and_then(|j| j.as_array()).cloned())
+        .unwrap_or_default()
+}
+
+/// 모델을 지정할 수 있는 cron 작업 (격리 세션에서 에이전트 턴을 실행하는 작업)
+/// agent_id가 있으면 해당 에이전트의 작업만
+fn cron_model_jobs(agent_id: Option<&str>) -> Vec<Value> {
+    read_cron_jobs()
+        .into_iter()
+        .filter(|job| job.pointer("/payload/kind").and_then(|k| k.as_str()) == Some("agentTurn"))
+        .filter(|job| {
+            agent_id.is_none_or(|id| job.get("agentId").and_then(|a| a.as_str()).unwrap_or("main") == id)
         })
+        .collect()
+}
+
+/// cron 작업 모델: 모든 작업이 같은 모델이면 그 모델, 아니면 null
+fn cron_role_model(agent_id: Option<&str>) -> Value {
+    let models: Vec<Option<String>> = cron_model_jobs(agent_id)
+        .iter()
+        .map(|job| job.pointer("/payload/model").and_then(|m| m.as_str()).map(String::from))
+        .collect();
+    match models.first() {
+        Some(Some(first)) if models.iter().all(|m| m.as_deref() == Some(first.as_str())) => json!(first),
+        _ => Value::Null,
+    }
+}
+
+/// 모델 참조 값에서 "provider/model" 목록 추출
+/// (문자열 또는 { primary, fallbacks } 객체)
+fn collect_model_refs(value: Option<&Value>, out: &mut Vec<String>) {
+    match value {
+        Some(Value::String(s)) => out.push(s.clone()),
+        Some(obj @ Value::Object(_)) => {
+            if let Some(primary) = obj.get("primary").and_then(|p| p.as_str()) {
+                out.push(primary.to_string());
+            }
+            if let Some(fallbacks) = obj.get("fallbacks").and_then(|f| f.as_array()) {
+                out.extend(fallbacks.iter().filter_map(|f| f.as_str()).map(String::from));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// 에이전트 설정(defaults + 에이전트별)과 cron 작업에서 사용 중인 모델 참조 전체
+pub fn model_refs_in_use(config: &Value) -> Vec<String> {
+    let mut refs = Vec::new();
+    let defaults = config.pointer("/agents/defaults").into_iter();
+    let agents = config
+        .pointer("/agents/list")
+        .and_then(|l| l.as_array())
+        .into_iter()
+        .flatten();
+    for agent in defaults.chain(agents) {
+        collect_model_refs(agent.get("model"), &mut refs);
+        collect_model_refs(agent.get("imageModel"), &mut refs);
+        collect_model_refs(agent.pointer("/heartbeat/model"), &mut refs);
+        collect_model_refs(agent.pointer("/subagents/model"), &mut refs);
+    }
+    for job in cron_model_jobs(None) {
+        collect_model_refs(job.pointer("/payload/model"), &mut refs);
+    }
+    refs
+}
+
+/// 모델 구성 조회 (primary, fallback 체인, 역할별 모델, 프로바이더별 모델 목록)
+pub fn get_model_chain(agent_id: Option<&str>) -> Result<Value, String> {
+    let mut config = read_existing_config();
+    let providers: serde_json::Map<String, Value> = config
+        .pointer("/models/providers")
+        .and_then(|p| p.as_object())
+        .map(|p| {
+            p.iter()
+                .map(|(name, cfg)| {
+                    let ids: Vec<Value> = cfg
+                        .get("models")
+                        .and_then(|m| m.as_array())
+                        .map(|list| list.iter().filter_map(|m| m.get("id").cloned()).collect())
+                        .unwrap_or_default();
+                    (name.clone(), json!(ids))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let agent = agent_config_mut(&mut config, agent_id)?.clone();
+    // 문자열 형식(model: "provider/model")도 primary로 인식
+    let model_str = |v: Option<&Value>| -> Value {
+        match v {
+            Some(Value::String(s)) => json!(s),
+            Some(obj) => obj.get("primary").cloned().unwrap_or(Value::Null),
+            None => Value::Null,
+        }
+    };
+
+    Ok(json!({
+        "agentId": agent_id.unwrap_or("defaults"),
+        "primary": model_str(agent.get("model")),
+        "fallbacks": agent.pointer("/model/fallbacks").cloned().unwrap_or(json!([])),
+        "roles": {
+            "heartbeat": agent.pointer("/heartbeat/model").cloned().unwrap_or(Value::Null),
+            "image": model_str(agent.get("imageModel")),
+            "subagents": agent.pointer("/subagents/model").cloned().unwrap_or(Value::Null),
+            "cron": cron_role_model(agent_id.filter(|id| *id != "defaults")),
+        },
+        "providers": providers,
+    }))
+}
+
+/// 프로바이더에 모델 추가 (primary는 변경하지 않음)
+pub fn add_provider_model(provider: &str, model: &str) -> Result<(), String> {
+    let mut config = read_existing_config();
+    if config.pointer(&format!("/models/providers/{}", pointer_escape(provider))).is_none() {
+        return Err(format!("{} 프로바이더가 설정되어 있지 않습니다", provider));
+    }
+    upsert_provider_model(&mut config, provider, create_model_info(provider, model));
+    write_config(&config)
+}
+
+/// 프로바이더에서 모델 제거 (primary/fallback/역할에서 사용 중이면 거부)
+pub fn remove_provider_model(provider: &str, model: &str) -> Result<(), String> {
+    let mut config = read_existing_config();
+    let model_ref = format!("{}/{}", provider, model);
+
+    if model_refs_in_use(&config).contains(&model_ref) {
+        return Err(format!("{} 모델이 에이전트 설정에서 사용 중입니다", model_ref));
+    }
+
+    if let Some(list) = config
+        .pointer_mut(&format!("/models/providers/{}/models", pointer_escape(provider)))
+        .and_then(|m| m.as_array_mut())
+    {
+        list.retain(|m| m.get("id").and_then(|v| v.as_str()) != Some(model));
+    }
+    write_config(&config)
+}
+
+/// fallback 체인 설정 (순서대로 시도, 빈 배열이면 fallback 없음)
+pub fn set_model_fallbacks(agent_id: Option<&str>, fallbacks: &[String]) -> Result<(), String> {
+    let mut config = read_existing_config();
+    for model_ref in fallbacks {
+        ensure_model_ref(&mut config, model_ref)?;
+    }
+
+    let agent = agent_config_mut(&mut config, agent_id)?;
+    // 문자열 형식이면 객체로 변환 후 fallbacks 추가
+    if let Some(Value::String(primary)) = agent.get("model") {
+        let primary = primary.clone();
+        set_nested_value(agent, &["model"], json!({ "primary": primary }));
+    }
+    set_nested_value(agent, &["model", "fallbacks"], json!(fallbacks));
+    write_config(&config)
+}
+
+/// 역할별 모델 설정 (role: chat | heartbeat | image | subagents | cron, model이 None이면 해제)
+pub fn set_role_model(agent_id: Option<&str>, role: &str, model: Option<&str>) -> Result<(), String> {
+    if role == "cron" {
+        return set_cron_model(agent_id, model);
+    }
+    let path = role_model_path(role)?;
+    let mut config = read_existing_config();
+    if let Some(model_ref) = model {
+        ensure_model_ref(&mut config, model_ref)?;
+    }
+
+    let agent = agent_config_mut(&mut config, agent_id)?;
+    match model {
+        Some(model_ref) => {
+            if let Some(Value::String(primary)) = agent.get("model") {
+                let primary = primary.clone();
+                set_nested_value(agent, &["model"], json!({ "primary": primary }));
+            }
+            set_nested_value(agent, path, json!(model_ref));
+        }
+        None => {
+            if role == "chat" || role == "primary" {
+                return Err("기본 대화 모델은 해제할 수 없습니다".to_string());
+            }
+            if let Some(parent) = agent.get_mut(path[0]).and_then(|v| v.as_object_mut()) {
+                parent.remove(path[1]);
+            }
+        }
+    }
+    write_config(&config)
+}
+
+/// cron 작업 모델 설정: 에이전트 턴 작업마다 openclaw cron update --model 실행
+/// (cron 작업은 Gateway가 관리하므로 jobs.json을 직접 수정하지 않음)
+/// 작업을 모두 바꾼 뒤에만 설정에 모델을 등록하고, 중간에 실패하면 바꾼 작업을 이전 모델로 되돌림
+fn set_cron_model(agent_id: Option<&str>, model: Option<&str>) -> Result<(), String> {
+    let model_ref = model.ok_or("cron 작업 모델은 해제할 수 없습니다. 사용할 모델을 지정하세요")?;
+    let mut config = read_existing_config();
+    ensure_model_ref(&mut config, model_ref)?;
+
+    let jobs = cron_model_jobs(agent_id.filter(|id| *id != "defaults"));
+    if jobs.is_empty() {
+        return Err("모델을 지정할 cron 작업이 없습니다".to_string());
+    }
+    let update = |id: &str, model: &str| {
+        run_openclaw_command(&["cron", "update", id, "--model", model, "--timeout", "5000"])
+    };
+    let mut updated: Vec<(&str, Option<&str>)> = Vec::new();
+    for job in &jobs {
+        let Some(id) = job.get("id").and_then(|i| i.as_str()) else { continue };
+        if let Err(e) = update(id, model_ref) {
+            let mut message = format!("cron 작업 {} 모델 변경 실패: {}", id, e);
+            for (done, previous) in updated.iter().rev() {
+                let restored = previous.is_some_and(|p| update(done, p).is_ok());
+                if !restored {
+                    message.push_str(&format!("\n작업 {}은(는) 이미 {}(으)로 바뀌었습니다", done, model_ref));
+                }
+            }
+            return Err(message);
+        }
+        updated.push((id, job.pointer("/payload/model").and_then(|m| m.as_str())));
+    }
+    write_config(&config)
+}
+
+/// 모델 정보 JSON 생성 (모델 카탈로그 기준)
+fn create_model_info(provider: &str, model: &str) -> Value {
+    crate::model_catalog::model_config_entry(provider, model)
+}
+
+/// 프로바이더 baseUrl/api 설정 (카탈로그에 정의된 프로바이더만)
+fn apply_provider_endpoint(config: &mut Value, provider: &str) {
+    let Some(catalog) = crate::model_catalog::find_provider(provider) else {
+        return;
+    };
+    if let Some(base_url) = catalog.base_url {
+        set_nested_value(config, &["models", "providers", provider, "baseUrl"], json!(base_url));
+    }
+    if let Some(api) = catalog.api {
+        set_nested_value(config, &["models", "providers", provider, "api"], json!(api));
     }
 }
 
 /// 채널(메신저) 설정 추가 (기존 설정 보존)
+#[allow(clippy::too_many_arguments)]
 pub async fn add_channel_to_config(
     channel: &str,
     bot_token: &str,
@@ -860,17 +1104,52 @@ pub async fn add_channel_to_config(
     group_policy: &str,
     group_allow_from: &[String],
     require_mention: bool,
+    account_id: Option<&str>,
 ) -> Result<(), String> {
+    let account_id = normalize_account_id(account_id)?;
+    // 지원하지 않는 채널은 플러그인 설정을 바꾸기 전에 거부
+    if !ACCOUNT_CHANNELS.contains(&channel) {
+        return Err(format!("지원하지 않는 채널: {}", channel));
+    }
+    
     // ⚠️ CRITICAL: 플러그인 활성화 먼저! (안 하면 "Unsupported channel" 에러)
     // OPENCLAW_SCHEMA_REFERENCE.md 섹션 12.0 참고
     enable_channel_plugin(channel)?;
     
     let mut config = read_existing_config();
-    
+    apply_channel_settings(
+        &mut config,
+        channel,
+        bot_token,
+        dm_policy,
+        allow_from,
+        group_policy,
+        group_allow_from,
+        require_mention,
+        account_id.as_deref(),
+    )?;
+    write_config(&config)?;
+    Ok(())
+}
+
+/// 설정 값에 채널 정책/토큰 반영 (저장은 호출자가 담당)
+/// 접속 정보를 함께 저장하는 호출자가 한 번에 쓸 수 있도록 분리
+#[allow(clippy::too_many_arguments)]
+pub fn apply_channel_settings(
+    config: &mut Value,
+    channel: &str,
+    bot_token: &str,
+    dm_policy: &str,
+    allow_from: &[String],
+    group_policy: &str,
+    group_allow_from: &[String],
+    require_mention: bool,
+    account_id: Option<&str>,
+) -> Result<(), String> {
     // ⚠️ Schema Pollution 버그 수정!
     // 이전: is_empty()만 체크 → browser config만 있으면 통과
     // 수정: 필수 키(gateway, agents, tools)가 있는지 확인
-    if !has_required_config_keys(&config) {
+    if !has_required_config_keys(config) {
         return Err("Config에 필수 설정이 없습니다. 먼저 create_official_config를 호출하세요.".to_string());
     }
     
@@ -900,269 +1179,107 @@ pub async fn add_channel_to_config(
     
     // meta.lastTouchedAt 업데이트
     let now = Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
-    set_nested_value(&mut config, &["meta", "lastTouchedAt"], json!(now));
+    set_nested_value(config, &["meta", "lastTouchedAt"], json!(now));
     
-    // 채널별 설정
+    // 채널별 설정 (기본 계정은 채널 루트, 추가 계정은 accounts.<id>)
+    let section = channel_section_mut(config, channel, account_id)?;
     match channel {
         "telegram" => {
             // Telegram은 botToken 사용 (OpenClaw 공식 형식)
-            set_nested_value(
-                &mut config,
-                &["channels", "telegram", "enabled"],
-                json!(true),
-            );
-            // 토큰이 비어있으면 기존 값 유지
-            if !bot_token.is_empty() {
-                set_nested_value(
-                    &mut config,
-                    &["channels", "telegram", "botToken"],
-                    json!(bot_token),
-                );
+            set_nested_value(section, &["enabled"], json!(true));
+            // 토큰이 비어있거나 마스킹 값이면 기존 값 유지
+            if !bot_token.is_empty() && bot_token != MASKED_SECRET {
+                set_nested_value(section, &["botToken"], json!(bot_token));
             }
-            set_nested_value(
-                &mut config,
-                &["channels", "telegram", "dmPolicy"],
-                json!(dm_policy),
-            );
-            set_nested_value(
-                &mut config,
-                &["channels", "telegram", "allowFrom"],
-                json!(allow_from),
-            );
-            set_nested_value(
-                &mut config,
-                &["channels", "telegram", "groupPolicy"],
-                json!(group_policy),
-            );
+            set_nested_value(section, &["dmPolicy"], json!(dm_policy));
+            set_nested_value(section, &["allowFrom"], json!(allow_from));
+            set_nested_value(section, &["groupPolicy"], json!(group_policy));
             if !group_allow_from.is_empty() {
-                set_nested_value(
-                    &mut config,
-                    &["channels", "telegram", "groupAllowFrom"],
-                    json!(group_allow_from),
-                );
+                set_nested_value(section, &["groupAllowFrom"], json!(group_allow_from));
             }
-            set_nested_value(
-                &mut config,
-                &["channels", "telegram", "groups", "*", "requireMention"],
-                json!(require_mention),
-            );
+            set_nested_value(section, &["groups", "*", "requireMention"], json!(require_mention));
         }
         "discord" => {
             // Discord는 "token" 사용 (botToken 아님!)
-            set_nested_value(
-                &mut config,
-                &["channels", "discord", "enabled"],
-                json!(true),
-            );
+            set_nested_value(section, &["enabled"], json!(true));
             // 토큰이 비어있으면 기존 값 유지
-            if !bot_token.is_empty() {
-                set_nested_value(
-                    &mut config,
-                    &["channels", "discord", "token"],
-                    json!(bot_token),
-                );
+            if !bot_token.is_empty() && bot_token != MASKED_SECRET {
+                set_nested_value(section, &["token"], json!(bot_token));
             }
-            set_nested_value(
-                &mut config,
-                &["channels", "discord", "groupPolicy"],
-                json!(group_policy),
-            );
+            set_nested_value(section, &["groupPolicy"], json!(group_policy));
             // DM 설정
-            set_nested_value(
-                &mut config,
-                &["channels", "discord", "dm", "enabled"],
-                json!(true),
-            );
-            set_nested_value(
-                &mut config,
-                &["channels", "discord", "dm", "policy"],
-                json!(dm_policy),
-            );
-            set_nested_value(
-                &mut config,
-                &["channels", "discord", "dm", "allowFrom"],
-                json!(allow_from),
-            );
+            set_nested_value(section, &["dm", "enabled"], json!(true));
+            set_nested_value(section, &["dm", "policy"], json!(dm_policy));
+            set_nested_value(section, &["dm", "allowFrom"], json!(allow_from));
             // Guild 설정
-            set_nested_value(
-                &mut config,
-                &["channels", "discord", "guilds", "*", "requireMention"],
-                json!(require_mention),
-            );
+            set_nested_value(section, &["guilds", "*", "requireMention"], json!(require_mention));
             // Discord는 guilds.*.users로 그룹 허용 사용자 매핑
             if !group_allow_from.is_empty() {
-                set_nested_value(
-                    &mut config,
-                    &["channels", "discord", "guilds", "*", "users"],
-                    json!(group_allow_from),
-                );
+                set_nested_value(section, &["guilds", "*", "users"], json!(group_allow_from));
             }
         }
         "whatsapp" => {
             // WhatsApp은 enabled 키가 없음! 세션 기반 자동 시작
-            // 공식 스키마: 루트 레벨에 설정 (accounts 없이)
-            set_nested_value(
-                &mut config,
-                &["channels", "whatsapp", "dmPolicy"],
-                json!(dm_policy),
-            );
-            set_nested_value(
-                &mut config,
-                &["channels", "whatsapp", "allowFrom"],
-                json!(allow_from),
-            );
-            set_nested_value(
-                &mut config,
-                &["channels", "whatsapp", "groupPolicy"],
-                json!(group_policy),
-            );
+            // 공식 스키마: 기본 계정은 루트 레벨, 추가 계정은 accounts.<id>
+            set_nested_value(section, &["dmPolicy"], json!(dm_policy));
+            set_nested_value(section, &["allowFrom"], json!(allow_from));
+            set_nested_value(section, &["groupPolicy"], json!(group_policy));
             if !group_allow_from.is_empty() {
-                set_nested_value(
-                    &mut config,
-                    &["channels", "whatsapp", "groupAllowFrom"],
-                    json!(group_allow_from),
-                );
+                set_nested_value(section, &["groupAllowFrom"], json!(group_allow_from));
             }
-            set_nested_value(
-                &mut config,
-                &["channels", "whatsapp", "groups", "*", "requireMention"],
-                json!(require_mention),
-            );
+            set_nested_value(section, &["groups", "*", "requireMention"], json!(require_mention));
         }
         "slack" => {
             // Slack은 botToken + appToken 필요
-            set_nested_value(
-                &mut config,
-                &["channels", "slack", "enabled"],
-                json!(true),
-            );
+            set_nested_value(section, &["enabled"], json!(true));
             // botToken (bot_token 파라미터 사용)
-            if !bot_token.is_empty() {
-                set_nested_value(
-                    &mut config,
-                    &["channels", "slack", "botToken"],
-                    json!(bot_token),
-                );
+            if !bot_token.is_empty() && bot_token != MASKED_SECRET {
+                set_nested_value(section, &["botToken"], json!(bot_token));
             }
-            set_nested_value(
-                &mut config,
-                &["channels", "slack", "groupPolicy"],
-                json!(group_policy),
-            );
+            set_nested_value(section, &["groupPolicy"], json!(group_policy));
             // DM 설정
-            set_nested_value(
-                &mut config,
-                &["channels", "slack", "dm", "policy"],
-                json!(dm_policy),
-            );
-            set_nested_value(
-                &mut config,
-                &["channels", "slack", "dm", "allowFrom"],
-                json!(allow_from),
-            );
-            set_nested_value(
-                &mut config,
-                &["channels", "slack", "requireMention"],
-                json!(require_mention),
-            );
+            set_nested_value(section, &["dm", "policy"], json!(dm_policy));
+            set_nested_value(section, &["dm", "allowFrom"], json!(allow_from));
+            set_nested_value(section, &["requireMention"], json!(require_mention));
             // Slack은 channels 설정으로 채널 허용 목록 관리
             if !group_allow_from.is_empty() {
                 for channel_id in group_allow_from {
-                    set_nested_value(
-                        &mut config,
-                        &["channels", "slack", "channels", channel_id, "enabled"],
-                        json!(true),
-                    );
+                    set_nested_value(section, &["channels", channel_id, "enabled"], json!(true));
                 }
             }
         }
         "googlechat" => {
             // Google Chat은 Service Account 필요 (별도 처리)
-            set_nested_value(
-                &mut config,
-                &["channels", "googlechat", "enabled"],
-                json!(true),
-            );
+            set_nested_value(section, &["enabled"], json!(true));
             // DM 설정 (중첩 구조 - OpenClaw 공식 스키마)
-            set_nested_value(
-                &mut config,
-                &["channels", "googlechat", "dm", "enabled"],
-                json!(true),
-            );
-            set_nested_value(
-                &mut config,
-                &["channels", "googlechat", "dm", "policy"],
-                json!(dm_policy),
-            );
-            set_nested_value(
-                &mut config,
-                &["channels", "googlechat", "dm", "allowFrom"],
-                json!(allow_from),
-            );
-            set_nested_value(
-                &mut config,
-                &["channels", "googlechat", "groupPolicy"],
-                json!(group_policy),
-            );
+            set_nested_value(section, &["dm", "enabled"], json!(true));
+            set_nested_value(section, &["dm", "policy"], json!(dm_policy));
+            set_nested_value(section, &["dm", "allowFrom"], json!(allow_from));
+            set_nested_value(section, &["groupPolicy"], json!(group_policy));
             if !group_allow_from.is_empty() {
-                set_nested_value(
-                    &mut config,
-                    &["channels", "googlechat", "groupAllowFrom"],
-                    json!(group_allow_from),
-                );
+                set_nested_value(section, &["groupAllowFrom"], json!(group_allow_from));
             }
-            set_nested_value(
-                &mut config,
-                &["channels", "googlechat", "requireMention"],
-                json!(require_mention),
-            );
+            set_nested_value(section, &["requireMention"], json!(require_mention));
         }
         "mattermost" => {
             // Mattermost는 url + botToken 필요
-            set_nested_value(
-                &mut config,
-                &["channels", "mattermost", "enabled"],
-                json!(true),
-            );
-            if !bot_token.is_empty() {
-                set_nested_value(
-                    &mut config,
-                    &["channels", "mattermost", "botToken"],
-                    json!(bot_token),
-                );
+            set_nested_value(section, &["enabled"], json!(true));
+            if !bot_token.is_empty() && bot_token != MASKED_SECRET {
+                set_nested_value(section, &["botToken"], json!(bot_token));
             }
-            set_nested_value(
-                &mut config,
-                &["channels", "mattermost", "dmPolicy"],
-                json!(dm_policy),
-            );
-            set_nested_value(
-                &mut config,
-                &["channels", "mattermost", "allowFrom"],
-                json!(allow_from),
-            );
-            set_nested_value(
-                &mut config,
-                &["channels", "mattermost", "groupPolicy"],
-                json!(group_policy),
-            );
+            set_nested_value(section, &["dmPolicy"], json!(dm_policy));
+            set_nested_value(section, &["allowFrom"], json!(allow_from));
+            set_nested_value(section, &["groupPolicy"], json!(group_policy));
             if !group_allow_from.is_empty() {
-                set_nested_value(
-                    &mut config,
-                    &["channels", "mattermost", "groupAllowFrom"],
-                    json!(group_allow_from),
-                );
+                set_nested_value(section, &["groupAllowFrom"], json!(group_allow_from));
             }
-            set_nested_value(
-                &mut config,
-                &["channels", "mattermost", "requireMention"],
-                json!(require_mention),
-            );
+            set_nested_value(section, &["requireMention"], json!(require_mention));
+        }
+        "signal" | "matrix" | "line" | "imessage" => {
+            write_plugin_channel_fields(section, channel, bot_token, dm_policy, &allow_from, group_policy, group_allow_from);
         }
         _ => {}
     }
-    
-    write_config(&config)?;
     Ok(())
 }
 
@@ -1185,7 +1302,7 @@ pub fn read_existing_config() -> Value {
 }
 
 /// 설정 파일 저장 (JSON5 형식, 들여쓰기)
-fn write_config(config: &Value) -> Result<(), String> {
+pub fn write_config(config: &Value) -> Result<(), String> {
     let config_dir = get_openclaw_dir();
     fs::create_dir_all(&config_dir)
         .map_err(|e| format!("설정 디렉토리 생성 실패: {}", e))?;
@@ -1222,7 +1339,7 @@ fn fix_config_structure(config: &mut Value) {
 }
 
 /// 중첩 JSON 객체에 값 설정 (deep merge)
-fn set_nested_value(config: &mut Value, path: &[&str], value: Value) {
+pub fn set_nested_value(config: &mut Value, path: &[&str], value: Value) {
     if path.is_empty() {
         return;
     }
@@ -1249,6 +1366,301 @@ fn set_nested_value(config: &mut Value, path: &[&str], value: Value) {
     }
 }
 
+// ===== 채널 계정 (멀티 계정) =====
+
+/// 기본 계정 ID (채널 루트 설정 / credentials/whatsapp/default)
+pub const DEFAULT_ACCOUNT_ID: &str = "default";
+
+/// 멀티 계정을 지원하는 메신저 채널
+pub const ACCOUNT_CHANNELS: &[&str] = &[
+    "telegram", "discord", "whatsapp", "slack", "googlechat", "mattermost",
+    "signal", "matrix", "line", "imessage",
+];
+
+/// 계정 ID 검증 및 정규화
+/// 비어 있거나 "default"면 None (채널 루트 레벨 = 기본 계정)
+pub fn normalize_account_id(account_id: Option<&str>) -> Result<Option<String>, String> {
+    let id = match account_id.map(str::trim) {
+        None | Some("") => return Ok(None),
+        Some(id) if id == DEFAULT_ACCOUNT_ID => return Ok(None),
+        Some(id) => id,
+    };
+    let valid = id.len() <= 64
+        && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !valid {
+        return Err(format!("계정 ID는 영문, 숫자, -, _ 만 사용할 수 있습니다: {}", id));
+    }
+    Ok(Some(id.to_string()))
+}
+
+/// 채널 계정의 설정 섹션 (없으면 생성)
+/// 기본 계정: channels.<channel>, 추가 계정: channels.<channel>.accounts.<id>
+pub fn channel_section_mut<'a>(config: &'a mut Value, channel: &str, account_id: Option<&str>) -> Result<&'a mut Value, String> {
+    let mut path = vec!["channels", channel];
+    if let Some(id) = account_id {
+        path.extend(["accounts", id]);
+    }
+    let pointer = format!("/{}", path.join("/"));
+    if !config.pointer(&pointer).is_some_and(|v| v.is_object()) {
+        set_nested_value(config, &path, json!({}));
+    }
+    config.pointer_mut(&pointer)
+        .ok_or_else(|| "Config 형식이 올바르지 않습니다".to_string())
+}
+
+/// 이미 설정된 채널 계정의 섹션 (없으면 오류)
+/// 잘못 입력한 계정 ID로 토큰 없는 빈 계정이 생기지 않도록 기존 설정을 고칠 때 사용
+pub fn existing_channel_section_mut<'a>(
+    config: &'a mut Value,
+    channel: &str,
+    account_id: Option<&str>,
+) -> Result<&'a mut Value, String> {
+    let id = account_id.unwrap_or(DEFAULT_ACCOUNT_ID);
+    let exists = channel_accounts(config, channel)
+        .iter()
+        .any(|a| a.get("accountId").and_then(|v| v.as_str()) == Some(id));
+    if !exists {
+        return Err(format!("{} 채널에 {} 계정이 없습니다", channel, id));
+    }
+    channel_section_mut(config, channel, account_id)
+}
+
+/// 채널 계정 섹션 안의 DM 설정 경로 (policy / allowFrom)
+/// Discord/Slack/Google Chat/Matrix는 DM 설정이 dm.* 아래에 있음
+pub fn dm_setting_path(channel: &str, key: &'static str) -> Vec<&'static str> {
+    match channel {
+        "discord" | "slack" | "googlechat" | "matrix" => vec!["dm", key],
+        _ => vec![if key == "policy" { "dmPolicy" } else { key }],
+    }
+}
+
+/// 플러그인 채널(Signal/Matrix/LINE/iMessage) 공통 정책 필드 기록
+/// 접속 정보(홈서버, 전화번호 등)는 channel_schema::configure_channel에서 저장
+fn write_plugin_channel_fields(
+    section: &mut Value,
+    channel: &str,
+    token: &str,
+    dm_policy: &str,
+    allow_from: &[String],
+    group_policy: &str,
+    group_allow_from: &[String],
+) {
+    set_nested_value(section, &["enabled"], json!(true));
+    if let Some(key) = crate::channel_schema::secret_field(channel) {
+        if !token.is_empty() && token != MASKED_SECRET {
+            set_nested_value(section, &[key], json!(token));
+        }
+    }
+    set_nested_value(section, &dm_setting_path(channel, "policy"), json!(dm_policy));
+    set_nested_value(section, &dm_setting_path(channel, "allowFrom"), json!(allow_from));
+    set_nested_value(section, &["groupPolicy"], json!(group_policy));
+    if !group_allow_from.is_empty() {
+        set_nested_value(section, &["groupAllowFrom"], json!(group_allow_from));
+    }
+}
+
+/// 기본 계정(루트 설정) 고유의 자격 증명 키
+/// 추가 계정이 상속하면 안 되는 값이므로 기본 계정 삭제 시 제거
+fn default_account_credential_keys(channel: &str) -> &'static [&'static str] {
+    match channel {
+        "telegram" => &["botToken", "tokenFile"],
+        "discord" => &["token"],
+        "slack" => &["botToken", "appToken", "userToken", "signingSecret"],
+        "googlechat" => &["serviceAccountFile"],
+        "mattermost" => &["botToken"],
+        "signal" => &["account"],
+        "matrix" => &["userId", "accessToken", "password"],
+        "line" => &["channelAccessToken", "channelSecret"],
+        _ => &[],
+    }
+}
+
+/// 기본 계정의 자격 증명과 DM/그룹 정책만 제거
+/// (루트 enabled와 나머지 공통 기본값은 추가 계정이 상속하므로 유지)
+fn remove_default_account_keys(config: &mut Value, channel: &str) {
+    let Some(section) = config
+        .pointer_mut(&format!("/channels/{}", pointer_escape(channel)))
+        .and_then(|s| s.as_object_mut())
+    else {
+        return;
+    };
+    for key in default_account_credential_keys(channel) {
+        section.remove(*key);
+    }
+    for key in ["name", "groupPolicy", "groupAllowFrom"] {
+        section.remove(key);
+    }
+    match dm_setting_path(channel, "policy").as_slice() {
+        [parent, _] => {
+            if let Some(dm) = section.get_mut(*parent).and_then(|d| d.as_object_mut()) {
+                dm.remove("policy");
+                dm.remove("allowFrom");
+            }
+        }
+        _ => {
+            section.remove("dmPolicy");
+            section.remove("allowFrom");
+        }
+    }
+}
+
+/// 채널 계정 비활성화/삭제
+/// - 추가 계정: accounts.<id> 항목 삭제
+/// - 기본 계정, 다른 계정 있음: 기본 계정 고유 키만 삭제 (다른 계정은 계속 동작)
+/// - 기본 계정, 유일한 계정: enabled=false (WhatsApp은 enabled 키가 없으므로 루트 설정 삭제)
+///
+/// WhatsApp은 세션 파일, Google Chat은 가져온 키 사본도 함께 삭제
+fn disable_channel_account(config: &mut Value, channel: &str, account_id: Option<&str>) {
+    if let Some(id) = account_id {
+        if let Some(accounts) = config.pointer_mut(&format!("/channels/{}/accounts", channel))
+            .and_then(|a| a.as_object_mut())
+        {
+            accounts.remove(id);
+        }
+        if channel == "whatsapp" {
+            let _ = logout_whatsapp(Some(id));
+        }
+        if channel == "googlechat" {
+            let _ = crate::googlechat::remove_managed_key(Some(id));
+        }
+        return;
+    }
+
+    let has_other_accounts = config
+        .pointer(&format!("/channels/{}/accounts", channel))
+        .and_then(|a| a.as_object())
+        .is_some_and(|a| !a.is_empty());
+    if has_other_accounts {
+        remove_default_account_keys(config, channel);
+    } else if channel == "whatsapp" {
+        if let Some(channels) = config.get_mut("channels").and_then(|c| c.as_object_mut()) {
+            channels.remove("whatsapp");
+        }
+    } else if config.pointer(&format!("/channels/{}", channel)).is_some() {
+        set_nested_value(config, &["channels", channel, "enabled"], json!(false));
+    }
+    // 게이트웨이에 로그아웃 후 세션 파일도 삭제 (남아있으면 자동 재연결됨)
+    if channel == "whatsapp" {
+        let _ = logout_whatsapp(None);
+    }
+    // Google Chat: 가져온 서비스 계정 키 사본 삭제
+    if channel == "googlechat" {
+        let _ = crate::googlechat::remove_managed_key(None);
+    }
+}
+
+/// 채널 계정 설정 요약 (토큰은 존재 여부만)
+fn channel_account_summary(channel: &str, account_id: &str, section: &Value) -> Value {
+    let str_list = |v: Option<&Value>| -> Vec<String> {
+        v.and_then(|a| a.as_array())
+            .map(|arr| arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect())
+            .unwrap_or_default()
+    };
+    let dm_policy = section.pointer(&format!("/{}", dm_setting_path(channel, "policy").join("/")));
+    let allow_from = section.pointer(&format!("/{}", dm_setting_path(channel, "allowFrom").join("/")));
+    let group_allow_from = if channel == "discord" {
+        section.pointer("/guilds/*/users")
+    } else {
+        section.get("groupAllowFrom")
+    };
+    let require_mention = match channel {
+        "telegram" | "whatsapp" => section.pointer("/groups/*/requireMention"),
+        "discord" => section.pointer("/guilds/*/requireMention"),
+        _ => section.get("requireMention"),
+    };
+    let token_key = match channel {
+        "discord" => Some("token"),
+        "telegram" | "slack" | "mattermost" => Some("botToken"),
+        "matrix" => Some("accessToken"),
+        "line" => Some("channelAccessToken"),
+        // Signal은 토큰 대신 연결된 전화번호
+        "signal" => Some("account"),
+        _ => None,
+    };
+    let has_token = token_key
+        .and_then(|k| section.get(k))
+        .and_then(|t| t.as_str())
+        .is_some_and(|t| !t.is_empty());
+
+    let mut summary = json!({
+        "accountId": account_id,
+        "name": section.get("name").and_then(|n| n.as_str()),
+        "enabled": section.get("enabled").and_then(|e| e.as_bool()).unwrap_or(true),
+        "hasToken": has_token,
+        "dmPolicy": dm_policy.and_then(|d| d.as_str()).unwrap_or("pairing"),
+        "allowFrom": str_list(allow_from),
+        "groupPolicy": section.get("groupPolicy").and_then(|g| g.as_str()).unwrap_or("allowlist"),
+        "groupAllowFrom": str_list(group_allow_from),
+        "requireMention": require_mention.and_then(|r| r.as_bool()).unwrap_or(true)
+    });
+    if channel == "whatsapp" {
+        let id = (account_id != DEFAULT_ACCOUNT_ID).then_some(account_id);
+        summary["isLinked"] = json!(check_whatsapp_linked(id));
+    }
+    summary
+}
+
+/// 채널의 계정 목록 (기본 계정 + accounts.*)
+fn channel_accounts(config: &Value, channel: &str) -> Vec<Value> {
+    let Some(section) = config.pointer(&format!("/channels/{}", channel)) else {
+        return vec![];
+    };
+
+    let mut accounts = Vec::new();
+    // 루트 레벨에 accounts 외의 설정이 있으면 기본 계정
+    let has_root_account = section.as_object()
+        .is_some_and(|o| o.keys().any(|k| k != "accounts"));
+    if has_root_account {
+        accounts.push(channel_account_summary(channel, DEFAULT_ACCOUNT_ID, section));
+    }
+    if let Some(extra) = section.get("accounts").and_then(|a| a.as_object()) {
+        for (id, account) in extra {
+            accounts.push(channel_account_summary(channel, id, account));
+        }
+    }
+    accounts
+}
+
+/// 채널의 계정 목록 (config 파일 기준)
+pub fn list_channel_accounts(channel: &str) -> Vec<Value> {
+    channel_accounts(&read_existing_config(), channel)
+}
+
+/// 채널 활성화 여부
+/// WhatsApp은 `enabled` 키가 없음 - 섹션이 비어있지 않으면 활성
+fn is_channel_enabled(channel: &str, section: &Value) -> bool {
+    if channel == "whatsapp" {
+        return section.as_object().is_some_and(|o| !o.is_empty());
+    }
+    section.get("enabled").and_then(|e| e.as_bool()).unwrap_or(false)
+}
+
+/// 설정된 모든 채널 보고서 (계정별 정책 포함, 토큰은 존재 여부만)
+pub fn channels_report(config: &Value) -> Vec<Value> {
+    ACCOUNT_CHANNELS
+        .iter()
+        .filter_map(|channel| {
+            let section = config.pointer(&format!("/channels/{}", channel))?;
+            Some(json!({
+                "channel": channel,
+                "enabled": is_channel_enabled(channel, section),
+                "accounts": channel_accounts(config, channel)
+            }))
+        })
+        .collect()
+}
+
+/// 채널 계정 삭제 (기본 계정은 비활성화)
+pub fn remove_channel_account(channel: &str, account_id: &str) -> Result<(), String> {
+    if !ACCOUNT_CHANNELS.contains(&channel) {
+        return Err(format!("지원하지 않는 채널: {}", channel));
+    }
+    let account_id = normalize_account_id(Some(account_id))?;
+    let mut config = read_existing_config();
+    disable_channel_account(&mut config, channel, account_id.as_deref());
+    write_config(&config)
+}
+
 /// 모델 설정 (사용자 API 키) - JSON5 형식
 pub async fn configure_model(provider: &str, model: &str, api_key: &str) -> Result<(), String> {
     let mut config = read_existing_config();
@@ -1261,74 +1673,10 @@ pub async fn configure_model(provider: &str, model: &str, api_key: &str) -> Resu
     );
 
     // models.providers.<provider>.baseUrl 설정 (필요한 경우)
-    match provider {
-        "anthropic" => {
-            set_nested_value(
-                &mut config,
-                &["models", "providers", provider, "baseUrl"],
-                json!("https://api.anthropic.com"),
-            );
-            set_nested_value(
-                &mut config,
-                &["models", "providers", provider, "api"],
-                json!("anthropic-messages"),
-            );
-        }
-        "openai" => {
-            set_nested_value(
-                &mut config,
-                &["models", "providers", provider, "baseUrl"],
-                json!("https://api.openai.com/v1"),
-            );
-        }
-        "google" => {
-            set_nested_value(
-                &mut config,
-                &["models", "providers", provider, "baseUrl"],
-                json!("https://generativelanguage.googleapis.com/v1beta"),
-            );
-            set_nested_value(
-                &mut config,
-                &["models", "providers", provider, "api"],
-                json!("google-generative-ai"),
-            );
-        }
-        _ => {}
-    }
+    apply_provider_endpoint(&mut config, provider);
 
-    // models.providers.<provider>.models 배열 추가 (현재 선택된 모델 정보)
-    let model_info = match model {
-        "claude-sonnet-4-20250514" => json!({
-            "id": "claude-sonnet-4-20250514",
-            "name": "Claude Sonnet 4"
-        }),
-        "claude-haiku-4-5-20251001" => json!({
-            "id": "claude-haiku-4-5-20251001", 
-            "name": "Claude Haiku 4.5"
-        }),
-        "claude-opus-4-20250514" => json!({
-            "id": "claude-opus-4-20250514",
-            "name": "Claude Opus 4"
-        }),
-        "gpt-4o" => json!({
-            "id": "gpt-4o",
-            "name": "GPT-4o"
-        }),
-        "gpt-4o-mini" => json!({
-            "id": "gpt-4o-mini",
-            "name": "GPT-4o Mini"
-        }),
-        _ => json!({
-            "id": model,
-            "name": model
-        })
-    };
-    
-    set_nested_value(
-        &mut config,
-        &["models", "providers", provider, "models"],
-        json!([model_info]),
-    );
+    // models.providers.<provider>.models 배열에 현재 선택된 모델 추가
+    upsert_provider_model(&mut config, provider, create_model_info(provider, model));
 
     // agents.defaults.model.primary 설정 (올바른 경로)
     let model_string = format!("{}/{}", provider, model);
@@ -1849,9 +2197,30 @@ pub async fn start_whatsapp_pairing() -> Result<String, String> {
     .map(|_| "WhatsApp 연결 준비 완료. QR 코드를 확인하세요.".to_string())
 }
 
-/// WhatsApp QR 로그인 (openclaw channels login)
-/// 터미널 창에서 QR 코드를 표시하고, 인증 완료까지 대기
-pub async fn login_whatsapp() -> Result<String, String> {
+/// WhatsApp 계정별 credentials 디렉토리 (~/.openclaw/credentials/whatsapp/<accountId>)
+pub fn whatsapp_credentials_dir(account_id: Option<&str>) -> Option<PathBuf> {
+    dirs::home_dir().map(|h| {
+        h.join(".openclaw")
+            .join("credentials")
+            .join("whatsapp")
+            .join(account_id.unwrap_or(DEFAULT_ACCOUNT_ID))
+    })
+}
+
+/// WhatsApp 로그인 CLI 인자 (추가 계정은 --account 지정)
+pub fn whatsapp_login_args(account_id: Option<&str>) -> Vec<String> {
+    let mut args: Vec<String> = ["channels", "login", "--channel", "whatsapp"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    if let Some(id) = account_id {
+        args.extend(["--account".to_string(), id.to_string()]);
+    }
+    args
+}
+
+/// 로그인 전 준비: 플러그인 활성화, 채널 추가, credentials 디렉토리 생성
+pub fn prepare_whatsapp_login(account_id: Option<&str>) -> Result<(), String> {
     // 1. 플러그인 활성화 (WhatsApp은 기본 비활성화)
     enable_channel_plugin("whatsapp")?;
     
@@ -1859,13 +2228,22 @@ pub async fn login_whatsapp() -> Result<String, String> {
     let _ = add_channel("whatsapp");
     
     // 3. credentials 디렉토리 미리 생성 (없으면 QR 안 뜨는 버그 방지)
-    if let Some(creds_dir) = dirs::home_dir()
-        .map(|h| h.join(".openclaw").join("credentials").join("whatsapp").join("default"))
-    {
+    if let Some(creds_dir) = whatsapp_credentials_dir(account_id) {
         let _ = fs::create_dir_all(&creds_dir);
     }
+    Ok(())
+}
+
+/// WhatsApp QR 로그인 (openclaw channels login)
+/// 터미널 창에서 QR 코드를 표시하고, 인증 완료까지 대기
+pub async fn login_whatsapp(account_id: Option<&str>) -> Result<String, String> {
+    let account_id = normalize_account_id(account_id)?;
+    prepare_whatsapp_login(account_id.as_deref())?;
     
     // 4. QR 로그인 실행
+    let login_args = whatsapp_login_args(account_id.as_deref());
+    let login_cmd = format!("openclaw {}", login_args.join(" "));
+    
     #[cfg(windows)]
     {
         use std::os::windows::process::CommandExt;
@@ -1874,7 +2252,7 @@ pub async fn login_whatsapp() -> Result<String, String> {
         // 새 콘솔 창에서 실행 (QR 코드 표시용)
         // 에러 시 pause로 메시지 확인 가능
         let mut child = Command::new("cmd")
-            .args(["/C", "openclaw channels login --channel whatsapp || (echo. && echo [오류 발생] 위 메시지를 확인하세요 && pause)"])
+            .args(["/C", &format!("{} || (echo. && echo [오류 발생] 위 메시지를 확인하세요 && pause)", login_cmd)])
             .creation_flags(CREATE_NEW_CONSOLE)
             .spawn()
             .map_err(|e| format!("WhatsApp 로그인 실행 실패: {}", e))?;
@@ -1897,9 +2275,10 @@ pub async fn login_whatsapp() -> Result<String, String> {
         let script = format!(
             r#"tell application "Terminal"
     activate
-    do script "export PATH=\"{}\"; openclaw channels login --channel whatsapp; echo '완료. 창을 닫으세요.'; read"
+    do script "export PATH=\"{}\"; {}; echo '완료. 창을 닫으세요.'; read"
 end tell"#,
-            path_str.replace('"', "\\\"")
+            path_str.replace('"', "\\\""),
+            login_cmd
         );
 
         let mut child = Command::new("osascript")
@@ -1920,11 +2299,14 @@ end tell"#,
     #[cfg(all(not(windows), not(target_os = "macos")))]
     {
         // Linux: 여러 터미널 시도
-        let terminals = [
-            ("gnome-terminal", vec!["--wait", "--", "openclaw", "channels", "login", "--channel", "whatsapp"]),
-            ("konsole", vec!["--hold", "-e", "openclaw", "channels", "login", "--channel", "whatsapp"]),
-            ("xfce4-terminal", vec!["--hold", "-e", "openclaw channels login --channel whatsapp"]),
-            ("xterm", vec!["-hold", "-e", "openclaw", "channels", "login", "--channel", "whatsapp"]),
+        let argv: Vec<&str> = std::iter::once("openclaw")
+            .chain(login_args.iter().map(|s| s.as_str()))
+            .collect();
+        let terminals: [(&str, Vec<&str>); 4] = [
+            ("gnome-terminal", [&["--wait", "--"][..], &argv].concat()),
+            ("konsole", [&["--hold", "-e"][..], &argv].concat()),
+            ("xfce4-terminal", vec!["--hold", "-e", &login_cmd]),
+            ("xterm", [&["-hold", "-e"][..], &argv].concat()),
         ];
 
         for (term, args) in terminals.iter() {
@@ -1939,34 +2321,27 @@ end tell"#,
             }
         }
 
-        Err("터미널을 찾을 수 없습니다. 수동으로 'openclaw channels login --channel whatsapp'을 실행하세요.".to_string())
+        Err(format!("터미널을 찾을 수 없습니다. 수동으로 '{}'을 실행하세요.", login_cmd))
     }
 }
 
 /// WhatsApp 로그인 터미널 열기 (비동기, 대기 안 함)
 /// 프론트엔드에서 폴링으로 creds.json 확인
-pub fn open_whatsapp_login_terminal() -> Result<(), String> {
-    // 1. 플러그인 활성화
-    enable_channel_plugin("whatsapp")?;
-    
-    // 2. 채널 추가 (이미 있으면 무시)
-    let _ = add_channel("whatsapp");
-    
-    // 3. credentials 디렉토리 미리 생성
-    if let Some(creds_dir) = dirs::home_dir()
-        .map(|h| h.join(".openclaw").join("credentials").join("whatsapp").join("default"))
-    {
-        let _ = fs::create_dir_all(&creds_dir);
-    }
+pub fn open_whatsapp_login_terminal(account_id: Option<&str>) -> Result<(), String> {
+    let account_id = normalize_account_id(account_id)?;
+    prepare_whatsapp_login(account_id.as_deref())?;
     
     // 4. 터미널 열기 (대기 없이 바로 리턴)
+    let login_args = whatsapp_login_args(account_id.as_deref());
+    let login_cmd = format!("openclaw {}", login_args.join(" "));
+    
     #[cfg(windows)]
     {
         use std::os::windows::process::CommandExt;
         const CREATE_NEW_CONSOLE: u32 = 0x00000010;
         
         Command::new("cmd")
-            .args(["/C", "openclaw channels login --channel whatsapp || pause"])
+            .args(["/C", &format!("{} || pause", login_cmd)])
             .creation_flags(CREATE_NEW_CONSOLE)
             .spawn()
             .map_err(|e| format!("터미널 열기 실패: {}", e))?;
@@ -1979,9 +2354,10 @@ pub fn open_whatsapp_login_terminal() -> Result<(), String> {
         let script = format!(
             r#"tell application "Terminal"
     activate
-    do script "export PATH=\"{}\"; openclaw channels login --channel whatsapp"
+    do script "export PATH=\"{}\"; {}"
 end tell"#,
-            path_str.replace('"', "\\\"")
+            path_str.replace('"', "\\\""),
+            login_cmd
         );
 
         Command::new("osascript")
@@ -1993,11 +2369,14 @@ end tell"#,
     #[cfg(all(unix, not(target_os = "macos")))]
     {
         // Linux: 여러 터미널 시도
-        let terminals = [
-            ("gnome-terminal", vec!["--", "openclaw", "channels", "login", "--channel", "whatsapp"]),
-            ("konsole", vec!["-e", "openclaw", "channels", "login", "--channel", "whatsapp"]),
-            ("xfce4-terminal", vec!["-e", "openclaw channels login --channel whatsapp"]),
-            ("xterm", vec!["-e", "openclaw", "channels", "login", "--channel", "whatsapp"]),
+        let argv: Vec<&str> = std::iter::once("openclaw")
+            .chain(login_args.iter().map(|s| s.as_str()))
+            .collect();
+        let terminals: [(&str, Vec<&str>); 4] = [
+            ("gnome-terminal", [&["--"][..], &argv].concat()),
+            ("konsole", [&["-e"][..], &argv].concat()),
+            ("xfce4-terminal", vec!["-e", &login_cmd]),
+            ("xterm", [&["-e"][..], &argv].concat()),
         ];
         
         let mut opened = false;
@@ -2009,26 +2388,26 @@ end tell"#,
         }
         
         if !opened {
-            return Err("터미널을 찾을 수 없습니다. 수동으로 'openclaw channels login --channel whatsapp'을 실행하세요.".to_string());
+            return Err(format!("터미널을 찾을 수 없습니다. 수동으로 '{}'을 실행하세요.", login_cmd));
         }
     }
     
     Ok(())
 }
 
-/// WhatsApp 인증 상태 확인 (creds.json 존재 여부)
-pub fn check_whatsapp_linked() -> bool {
-    let creds_path = dirs::home_dir()
-        .map(|h| h.join(".openclaw").join("credentials").join("whatsapp").join("default").join("creds.json"))
-        .unwrap_or_default();
-    
-    creds_path.exists() && creds_path.is_file()
+/// WhatsApp 인증 상태 확인 (계정별 creds.json에 연결된 계정 정보 me가 있는지)
+/// creds.json은 QR 스캔 전에도 생성되므로 파일 존재만으로는 판단하지 않음
+pub fn check_whatsapp_linked(account_id: Option<&str>) -> bool {
+    whatsapp_credentials_dir(account_id)
+        .map(|d| d.join("creds.json"))
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+        .is_some_and(|creds| creds.get("me").is_some_and(|me| !me.is_null()))
 }
 
 /// WhatsApp 세션 파일 삭제 (연결 해제 시 호출)
-pub fn delete_whatsapp_session() -> Result<(), String> {
-    let session_dir = dirs::home_dir()
-        .map(|h| h.join(".openclaw").join("credentials").join("whatsapp").join("default"))
+pub fn delete_whatsapp_session(account_id: Option<&str>) -> Result<(), String> {
+    let session_dir = whatsapp_credentials_dir(account_id)
         .ok_or("홈 디렉토리를 찾을 수 없습니다")?;
     
     if session_dir.exists() {
@@ -2039,6 +2418,22 @@ pub fn delete_whatsapp_session() -> Result<(), String> {
     Ok(())
 }
 
+/// WhatsApp 로그아웃: 게이트웨이에 알려 연결된 기기를 해제한 뒤 세션 파일 삭제
+/// 게이트웨이가 꺼져 있어 알림에 실패해도 로컬 세션은 삭제하고 경고 메시지를 반환
+pub fn logout_whatsapp(account_id: Option<&str>) -> Result<Option<String>, String> {
+    let warning = if check_whatsapp_linked(account_id) {
+        let mut args = vec!["channels", "logout", "--channel", "whatsapp"];
+        if let Some(id) = account_id {
+            args.extend(["--account", id]);
+        }
+        run_openclaw_command(&args).err()
+    } else {
+        None
+    };
+    delete_whatsapp_session(account_id)?;
+    Ok(warning)
+}
+
 /// 전체 onboard 실행 (non-interactive)
 pub async fn run_full_onboard(
     provider: &str,
@@ -2103,125 +2498,148 @@ pub async fn validate_config() -> Result<bool, String> {
     }
 }
 
-/// 현재 설정 요약 가져오기
-/// 현재 config를 프론트엔드 FullConfig 형식으로 반환
+/// 마스킹된 비밀 값 표시 (읽기 모델에서 사용, 저장 시에는 기존 값 유지)
+pub const MASKED_SECRET: &str = "***";
+
+fn mask_secret(value: &str) -> &'static str {
+    if value.is_empty() { "" } else { MASKED_SECRET }
+}
+
+/// agents.defaults.model의 primary 모델 ("provider/model")
+/// 문자열 형식(레거시)과 객체 형식 모두 지원
+pub fn primary_model_ref(config: &Value) -> Option<String> {
+    let model_val = config.pointer("/agents/defaults/model")?;
+    let model_id = match model_val {
+        Value::String(s) => s.as_str(),
+        other => other.get("primary").and_then(|p| p.as_str())?,
+    };
+    (!model_id.is_empty()).then(|| model_id.to_string())
+}
+
+/// 프로바이더 API 키(인증 프로필) 존재 여부
+fn provider_has_credentials(config: &Value, provider: &str) -> bool {
+    let has_profile = config.pointer("/auth/profiles")
+        .and_then(|p| p.as_object())
+        .is_some_and(|p| p.keys().any(|k| k.split(':').next() == Some(provider)));
+    let has_provider_key = config.pointer(&format!("/models/providers/{}/apiKey", pointer_escape(provider)))
+        .and_then(|k| k.as_str())
+        .is_some_and(|k| !k.is_empty());
+    has_profile || has_provider_key
+}
+
+/// 레거시 단일 메신저 보기: 첫 번째 활성 채널의 첫 계정
+fn primary_messenger_view(channels: &[Value]) -> Option<Value> {
+    let channel = channels.iter().find(|c| c["enabled"].as_bool() == Some(true))?;
+    let account = channel["accounts"].as_array().and_then(|a| a.first()).cloned().unwrap_or(json!({}));
+    let mut view = json!({
+        "type": channel["channel"],
+        "accountId": account.get("accountId").cloned().unwrap_or(json!(DEFAULT_ACCOUNT_ID)),
+        "hasToken": account.get("hasToken").cloned().unwrap_or(json!(false)),
+        "dmPolicy": account.get("dmPolicy").cloned().unwrap_or(json!("pairing")),
+        "allowFrom": account.get("allowFrom").cloned().unwrap_or(json!([])),
+        "groupPolicy": account.get("groupPolicy").cloned().unwrap_or(json!("allowlist")),
+        "groupAllowFrom": account.get("groupAllowFrom").cloned().unwrap_or(json!([])),
+        "requireMention": account.get("requireMention").cloned().unwrap_or(json!(true)),
+        "accounts": channel["accounts"]
+    });
+    if let Some(linked) = account.get("isLinked") {
+        view["isLinked"] = linked.clone();
+    }
+    Some(view)
+}
+
+/// 스킬 설정 보고서 (skills.entries, API 키는 존재 여부만)
+fn skills_report(config: &Value) -> Vec<Value> {
+    config.pointer("/skills/entries")
+        .and_then(|e| e.as_object())
+        .map(|entries| {
+            entries.iter().map(|(id, entry)| {
+                let env_keys: Vec<&String> = entry.get("env")
+                    .and_then(|e| e.as_object())
+                    .map(|e| e.keys().collect())
+                    .unwrap_or_default();
+                json!({
+                    "id": id,
+                    "enabled": entry.get("enabled").and_then(|e| e.as_bool()).unwrap_or(true),
+                    "hasApiKey": entry.get("apiKey").and_then(|k| k.as_str()).is_some_and(|k| !k.is_empty()),
+                    "env": env_keys
+                })
+            }).collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 현재 config를 프론트엔드 FullConfig 형식으로 반환 (UI의 단일 읽기 모델)
+/// 모든 채널/계정과 실제 정책, 통합, 스킬을 포함하며 비밀 값은 마스킹
 pub fn get_full_config() -> Value {
     let config = read_existing_config();
     
-    // Model 정보 추출
-    let model = if let Some(model_str) = config.get("agents")
-        .and_then(|a| a.get("defaults"))
-        .and_then(|d| d.get("model"))
-    {
-        // model이 객체인 경우 (primary 필드)
-        let model_id = if let Some(primary) = model_str.get("primary").and_then(|p| p.as_str()) {
-            primary.to_string()
-        } else if let Some(s) = model_str.as_str() {
-            s.to_string()
-        } else {
-            String::new()
-        };
-        
-        if !model_id.is_empty() {
-            // provider 추출 (model_id에서 / 앞 부분)
-            let parts: Vec<&str> = model_id.split('/').collect();
-            let provider = parts.first().unwrap_or(&"").to_string();
-            let model_name = parts.get(1).unwrap_or(&model_id.as_str()).to_string();
-            
-            // API 키 확인 (auth.profiles에서)
-            let has_api_key = config.get("auth")
-                .and_then(|a| a.get("profiles"))
-                .map(|p| !p.as_object().map(|o| o.is_empty()).unwrap_or(true))
-                .unwrap_or(false);
-            
-            Some(json!({
-                "provider": provider,
-                "model": model_name,
-                "apiKey": if has_api_key { "***" } else { "" }
-            }))
-        } else {
-            None
-        }
-    } else {
-        None
-    };
-    
-    // Messenger 정보 추출
-    let messenger = {
-        let channels = config.get("channels");
-        
-        let (msg_type, token, dm_policy, allow_from, group_policy, require_mention) = 
-            if let Some(tg) = channels.and_then(|c| c.get("telegram")) {
-                (
-                    "telegram",
-                    tg.get("botToken").and_then(|t| t.as_str()).unwrap_or(""),
-                    tg.get("dmPolicy").and_then(|d| d.as_str()).unwrap_or("pairing"),
-                    tg.get("allowFrom").and_then(|a| a.as_array()).map(|arr| 
-                        arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect::<Vec<_>>()
-                    ).unwrap_or_default(),
-                    tg.get("groupPolicy").and_then(|g| g.as_str()).unwrap_or("allowlist"),
-                    tg.get("groups").and_then(|g| g.get("*")).and_then(|s| s.get("requireMention")).and_then(|r| r.as_bool()).unwrap_or(true),
-                )
-            } else if let Some(dc) = channels.and_then(|c| c.get("discord")) {
-                (
-                    "discord",
-                    dc.get("token").and_then(|t| t.as_str()).unwrap_or(""),
-                    dc.get("dm").and_then(|d| d.get("policy")).and_then(|p| p.as_str()).unwrap_or("pairing"),
-                    dc.get("dm").and_then(|d| d.get("allowFrom")).and_then(|a| a.as_array()).map(|arr| 
-                        arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect::<Vec<_>>()
-                    ).unwrap_or_default(),
-                    dc.get("groupPolicy").and_then(|g| g.as_str()).unwrap_or("allowlist"),
-                    true, // Discord는 guilds 설정으로 관리
-                )
-            } else if let Some(wa) = channels.and_then(|c| c.get("whatsapp")) {
-                (
-                    "whatsapp",
-                    "", // WhatsApp은 토큰 없음
-                    wa.get("dmPolicy").and_then(|d| d.as_str()).unwrap_or("pairing"),
-                    wa.get("allowFrom").and_then(|a| a.as_array()).map(|arr| 
-                        arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect::<Vec<_>>()
-                    ).unwrap_or_default(),
-                    wa.get("groupPolicy").and_then(|g| g.as_str()).unwrap_or("allowlist"),
-                    wa.get("groups").and_then(|g| g.get("*")).and_then(|s| s.get("requireMention")).and_then(|r| r.as_bool()).unwrap_or(true),
-                )
-            } else {
-                ("", "", "pairing", vec![], "allowlist", true)
-            };
-        
+    // Model 정보 (primary + fallback)
+    let model = primary_model_ref(&config).map(|model_id| {
+        let (provider, model_name) = model_id.split_once('/').unwrap_or(("", model_id.as_str()));
         json!({
-            "type": if msg_type.is_empty() { Value::Null } else { json!(msg_type) },
-            "token": if token.is_empty() { "" } else { "***" },  // 토큰은 마스킹
-            "dmPolicy": dm_policy,
-            "allowFrom": allow_from,
-            "groupPolicy": group_policy,
-            "groupAllowFrom": [],
-            "requireMention": require_mention
+            "provider": provider,
+            "model": model_name,
+            "apiKey": if provider_has_credentials(&config, provider) { MASKED_SECRET } else { "" },
+            "fallbacks": config.pointer("/agents/defaults/model/fallbacks").cloned().unwrap_or(json!([]))
         })
-    };
+    });
+    
+    // Messenger 정보: 모든 채널 + 레거시 단일 메신저 보기
+    let channels = channels_report(&config);
+    let messenger = primary_messenger_view(&channels).map(|view| json!({
+        "type": view["type"],
+        "accountId": view["accountId"],
+        "token": if view["hasToken"].as_bool() == Some(true) { MASKED_SECRET } else { "" },
+        "dmPolicy": view["dmPolicy"],
+        "allowFrom": view["allowFrom"],
+        "groupPolicy": view["groupPolicy"],
+        "groupAllowFrom": view["groupAllowFrom"],
+        "requireMention": view["requireMention"],
+        "accounts": view["accounts"]
+    })).unwrap_or_else(|| json!({
+        "type": Value::Null,
+        "token": "",
+        "dmPolicy": "pairing",
+        "allowFrom": [],
+        "groupPolicy": "allowlist",
+        "groupAllowFrom": [],
+        "requireMention": true,
+        "accounts": []
+    }));
     
     // Gateway 정보
     let gateway = {
         let gw = config.get("gateway");
+        let secret = |key: &str| gw
+            .and_then(|g| g.pointer(&format!("/auth/{}", key)))
+            .and_then(|v| v.as_str())
+            .map(mask_secret)
+            .unwrap_or("");
         json!({
             "port": gw.and_then(|g| g.get("port")).and_then(|p| p.as_u64()).unwrap_or(18789),
             "bind": gw.and_then(|g| g.get("bind")).and_then(|b| b.as_str()).unwrap_or("loopback"),
             "authMode": gw.and_then(|g| g.get("auth")).and_then(|a| a.get("mode")).and_then(|m| m.as_str()).unwrap_or("token"),
-            "token": "***",  // 마스킹
-            "password": ""
+            "token": secret("token"),
+            "password": secret("password")
         })
     };
     
-    // Integrations (env에서)
-    let integrations = config.get("env")
-        .and_then(|e| e.get("vars"))
-        .cloned()
-        .unwrap_or(json!({}));
+    // Integrations (env.vars, 값은 마스킹)
+    let integrations: serde_json::Map<String, Value> = config.pointer("/env/vars")
+        .and_then(|v| v.as_object())
+        .map(|vars| vars.iter()
+            .map(|(k, v)| (k.clone(), json!(v.as_str().map(mask_secret).unwrap_or(MASKED_SECRET))))
+            .collect())
+        .unwrap_or_default();
     
     json!({
         "model": model,
         "messenger": messenger,
+        "channels": channels,
         "gateway": gateway,
-        "integrations": integrations
+        "integrations": integrations,
+        "skills": skills_report(&config)
     })
 }
 
@@ -2274,147 +2692,31 @@ pub fn get_model_config() -> Value {
 }
 
 /// 활성화된 모든 채널 ID 목록 반환
-/// 활성화된 모든 채널 ID 목록 반환
-/// 주의: WhatsApp은 `enabled` 키가 없음 - config 섹션 존재 + 세션 여부로 판단
+/// 주의: WhatsApp은 `enabled` 키가 없음 - config 섹션 존재 여부로 판단
 pub fn get_enabled_channels() -> Vec<String> {
     let config = read_existing_config();
-    let channels = config.get("channels");
-    let mut enabled = Vec::new();
-    
-    if let Some(ch) = channels {
-        // Telegram: enabled 키 지원
-        if ch.get("telegram")
-            .and_then(|t| t.get("enabled"))
-            .and_then(|e| e.as_bool())
-            .unwrap_or(false)
-        {
-            enabled.push("telegram".to_string());
-        }
-        
-        // Discord: enabled 키 지원
-        if ch.get("discord")
-            .and_then(|d| d.get("enabled"))
-            .and_then(|e| e.as_bool())
-            .unwrap_or(false)
-        {
-            enabled.push("discord".to_string());
-        }
-        
-        // WhatsApp: enabled 키 없음! config 섹션 존재 시 활성화
-        // 세션 기반 자동 시작이므로 dmPolicy 또는 다른 설정이 있으면 활성 상태
-        if let Some(wa) = ch.get("whatsapp") {
-            // whatsapp 섹션이 존재하고, 비어있지 않으면 활성화된 것으로 간주
-            if wa.as_object().map(|o| !o.is_empty()).unwrap_or(false) {
-                enabled.push("whatsapp".to_string());
-            }
-        }
-        
-        // Slack: enabled 키 지원
-        if ch.get("slack")
-            .and_then(|s| s.get("enabled"))
-            .and_then(|e| e.as_bool())
-            .unwrap_or(false)
-        {
-            enabled.push("slack".to_string());
-        }
-        
-        // Google Chat: enabled 키 지원
-        if ch.get("googlechat")
-            .and_then(|g| g.get("enabled"))
-            .and_then(|e| e.as_bool())
-            .unwrap_or(false)
-        {
-            enabled.push("googlechat".to_string());
-        }
-        
-        // Mattermost: enabled 키 지원
-        if ch.get("mattermost")
-            .and_then(|m| m.get("enabled"))
-            .and_then(|e| e.as_bool())
-            .unwrap_or(false)
-        {
-            enabled.push("mattermost".to_string());
-        }
-    }
-    
-    enabled
+    ACCOUNT_CHANNELS
+        .iter()
+        .filter(|channel| {
+            config.pointer(&format!("/channels/{}", channel))
+                .is_some_and(|section| is_channel_enabled(channel, section))
+        })
+        .map(|channel| channel.to_string())
+        .collect()
 }
 
 /// 현재 메신저 설정만 읽기
+/// 최상위 필드는 첫 번째 활성 채널(레거시 단일 메신저 UI용), `channels`는 모든 채널/계정
 pub fn get_messenger_config() -> Value {
     let config = read_existing_config();
-    let channels = config.get("channels");
-    
-    // Telegram 확인
-    if let Some(tg) = channels.and_then(|c| c.get("telegram")) {
-        if tg.get("enabled").and_then(|e| e.as_bool()).unwrap_or(true) {
-            return json!({
-                "type": "telegram",
-                "hasToken": tg.get("botToken").and_then(|t| t.as_str()).map(|s| !s.is_empty()).unwrap_or(false),
-                "dmPolicy": tg.get("dmPolicy").and_then(|d| d.as_str()).unwrap_or("pairing"),
-                "allowFrom": tg.get("allowFrom").and_then(|a| a.as_array()).map(|arr| 
-                    arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect::<Vec<_>>()
-                ).unwrap_or_default(),
-                "groupPolicy": tg.get("groupPolicy").and_then(|g| g.as_str()).unwrap_or("allowlist"),
-                "requireMention": tg.get("groups").and_then(|g| g.get("*")).and_then(|s| s.get("requireMention")).and_then(|r| r.as_bool()).unwrap_or(true)
-            });
-        }
-    }
-    
-    // Discord 확인
-    if let Some(dc) = channels.and_then(|c| c.get("discord")) {
-        if dc.get("enabled").and_then(|e| e.as_bool()).unwrap_or(true) {
-            return json!({
-                "type": "discord",
-                "hasToken": dc.get("token").and_then(|t| t.as_str()).map(|s| !s.is_empty()).unwrap_or(false),
-                "dmPolicy": dc.get("dm").and_then(|d| d.get("policy")).and_then(|p| p.as_str()).unwrap_or("pairing"),
-                "allowFrom": dc.get("dm").and_then(|d| d.get("allowFrom")).and_then(|a| a.as_array()).map(|arr| 
-                    arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect::<Vec<_>>()
-                ).unwrap_or_default(),
-                "groupPolicy": dc.get("groupPolicy").and_then(|g| g.as_str()).unwrap_or("allowlist"),
-                "requireMention": true
-            });
-        }
-    }
-    
-    // WhatsApp 확인 - enabled 키 없음! config 섹션 존재 시 활성화
-    if let Some(wa) = channels.and_then(|c| c.get("whatsapp")) {
-        // whatsapp 섹션이 존재하고 비어있지 않으면 활성 상태
-        if wa.as_object().map(|o| !o.is_empty()).unwrap_or(false) {
-            // 루트 레벨 또는 accounts.default에서 설정 읽기
-            let dm_policy = wa.get("dmPolicy")
-                .or_else(|| wa.get("accounts").and_then(|a| a.get("default")).and_then(|d| d.get("dmPolicy")))
-                .and_then(|p| p.as_str())
-                .unwrap_or("pairing");
-            let allow_from = wa.get("allowFrom")
-                .or_else(|| wa.get("accounts").and_then(|a| a.get("default")).and_then(|d| d.get("allowFrom")))
-                .and_then(|a| a.as_array())
-                .map(|arr| arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect::<Vec<_>>())
-                .unwrap_or_default();
-            let group_policy = wa.get("groupPolicy")
-                .or_else(|| wa.get("accounts").and_then(|a| a.get("default")).and_then(|d| d.get("groupPolicy")))
-                .and_then(|g| g.as_str())
-                .unwrap_or("allowlist");
-            let require_mention = wa.get("groups")
-                .or_else(|| wa.get("accounts").and_then(|a| a.get("default")).and_then(|d| d.get("groups")))
-                .and_then(|g| g.get("*"))
-                .and_then(|s| s.get("requireMention"))
-                .and_then(|r| r.as_bool())
-                .unwrap_or(true);
-            
-            return json!({
-                "type": "whatsapp",
-                "hasToken": false,  // WhatsApp은 토큰 없음
-                "isLinked": check_whatsapp_linked(),
-                "dmPolicy": dm_policy,
-                "allowFrom": allow_from,
-                "groupPolicy": group_policy,
-                "requireMention": require_mention
-            });
-        }
+    let channels = channels_report(&config);
+    if channels.is_empty() {
+        return json!(null);
     }
     
-    json!(null)
+    let mut view = primary_messenger_view(&channels).unwrap_or_else(|| json!({ "type": Value::Null }));
+    view["channels"] = json!(channels);
+    view
 }
 
 /// 현재 부가기능(통합) 설정만 읽기
@@ -2437,6 +2739,7 @@ pub async fn update_model_config(provider: &str, model: &str, api_key: &str) ->
 
 /// 메신저 설정만 업데이트 (기존 config에 패치)
 /// 토큰이 비어있으면 해당 채널을 비활성화 (삭제 모드)
+#[allow(clippy::too_many_arguments)]
 pub async fn update_messenger_config(
     channel: &str,
     token: &str,
@@ -2445,7 +2748,13 @@ pub async fn update_messenger_config(
     group_policy: &str,
     group_allow_from: &[String],
     require_mention: bool,
+    account_id: Option<&str>,
 ) -> Result<(), String> {
+    if !ACCOUNT_CHANNELS.contains(&channel) {
+        return Err(format!("지원하지 않는 채널: {}", channel));
+    }
+    let account_id = normalize_account_id(account_id)?;
+    
     // 토큰이 비어있으면 삭제(비활성화) 모드
     let is_delete_mode = token.is_empty();
     
@@ -2479,34 +2788,9 @@ pub async fn update_messenger_config(
     let now = Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
     set_nested_value(&mut config, &["meta", "lastTouchedAt"], json!(now));
     
-    // 삭제 모드: 해당 채널만 비활성화
+    // 삭제 모드: 해당 채널(계정)만 비활성화
     if is_delete_mode {
-        match channel {
-            "telegram" => {
-                set_nested_value(&mut config, &["channels", "telegram", "enabled"], json!(false));
-            }
-            "discord" => {
-                set_nested_value(&mut config, &["channels", "discord", "enabled"], json!(false));
-            }
-            "whatsapp" => {
-                // WhatsApp은 enabled 키가 없음 - 섹션 삭제 + 세션 파일 삭제
-                if let Some(channels) = config.get_mut("channels").and_then(|c| c.as_object_mut()) {
-                    channels.remove("whatsapp");
-                }
-                // 세션 파일도 삭제 (남아있으면 자동 재연결됨)
-                let _ = delete_whatsapp_session();
-            }
-            "slack" => {
-                set_nested_value(&mut config, &["channels", "slack", "enabled"], json!(false));
-            }
-            "googlechat" => {
-                set_nested_value(&mut config, &["channels", "googlechat", "enabled"], json!(false));
-            }
-            "mattermost" => {
-                set_nested_value(&mut config, &["channels", "mattermost", "enabled"], json!(false));
-            }
-            _ => {}
-        }
+        disable_channel_account(&mut config, channel, account_id.as_deref());
         write_config(&config)?;
         return Ok(());
     }
@@ -2515,91 +2799,104 @@ pub async fn update_messenger_config(
     let _ = enable_channel_plugin(channel);
     let _ = add_channel(channel);
     
-    // 새 채널 설정
+    // 새 채널 설정 (기본 계정은 채널 루트, 추가 계정은 accounts.<id>)
+    // 마스킹된 토큰("***")이 돌아오면 기존 토큰 유지
+    let section = channel_section_mut(&mut config, channel, account_id.as_deref())?;
     match channel {
         "telegram" => {
-            set_nested_value(&mut config, &["channels", "telegram", "enabled"], json!(true));
-            set_nested_value(&mut config, &["channels", "telegram", "botToken"], json!(token));
-            set_nested_value(&mut config, &["channels", "telegram", "dmPolicy"], json!(dm_policy));
+            set_nested_value(section, &["enabled"], json!(true));
+            if token != MASKED_SECRET {
+                set_nested_value(section, &["botToken"], json!(token));
+            }
+            set_nested_value(section, &["dmPolicy"], json!(dm_policy));
             if !allow_from.is_empty() {
-                set_nested_value(&mut config, &["channels", "telegram", "allowFrom"], json!(allow_from));
+                set_nested_value(section, &["allowFrom"], json!(allow_from));
             }
-            set_nested_value(&mut config, &["channels", "telegram", "groupPolicy"], json!(group_policy));
+            set_nested_value(section, &["groupPolicy"], json!(group_policy));
             if !group_allow_from.is_empty() {
-                set_nested_value(&mut config, &["channels", "telegram", "groupAllowFrom"], json!(group_allow_from));
+                set_nested_value(section, &["groupAllowFrom"], json!(group_allow_from));
             }
-            set_nested_value(&mut config, &["channels", "telegram", "groups", "*", "requireMention"], json!(require_mention));
+            set_nested_value(section, &["groups", "*", "requireMention"], json!(require_mention));
         }
         "discord" => {
-            set_nested_value(&mut config, &["channels", "discord", "enabled"], json!(true));
-            set_nested_value(&mut config, &["channels", "discord", "token"], json!(token));
-            set_nested_value(&mut config, &["channels", "discord", "dm", "enabled"], json!(true));
-            set_nested_value(&mut config, &["channels", "discord", "dm", "policy"], json!(dm_policy));
+            set_nested_value(section, &["enabled"], json!(true));
+            if token != MASKED_SECRET {
+                set_nested_value(section, &["token"], json!(token));
+            }
+            set_nested_value(section, &["dm", "enabled"], json!(true));
+            set_nested_value(section, &["dm", "policy"], json!(dm_policy));
             if !allow_from.is_empty() {
-                set_nested_value(&mut config, &["channels", "discord", "dm", "allowFrom"], json!(allow_from));
+                set_nested_value(section, &["dm", "allowFrom"], json!(allow_from));
             }
-            set_nested_value(&mut config, &["channels", "discord", "groupPolicy"], json!(group_policy));
+            set_nested_value(section, &["groupPolicy"], json!(group_policy));
             // Discord는 guilds 설정으로 그룹 허용 목록 관리 (groupAllowFrom은 guilds.*.users로 매핑)
             // 간단한 구현: 전역 guilds.* 설정에 users 추가
             if !group_allow_from.is_empty() {
-                set_nested_value(&mut config, &["channels", "discord", "guilds", "*", "users"], json!(group_allow_from));
+                set_nested_value(section, &["guilds", "*", "users"], json!(group_allow_from));
             }
         }
         "whatsapp" => {
             // WhatsApp은 enabled 키가 없음! 세션 기반 자동 시작
-            // 공식 스키마: 루트 레벨에 설정 (accounts 없이)
-            set_nested_value(&mut config, &["channels", "whatsapp", "dmPolicy"], json!(dm_policy));
+            // 공식 스키마: 기본 계정은 루트 레벨, 추가 계정은 accounts.<id>
+            set_nested_value(section, &["dmPolicy"], json!(dm_policy));
             if !allow_from.is_empty() {
-                set_nested_value(&mut config, &["channels", "whatsapp", "allowFrom"], json!(allow_from));
+                set_nested_value(section, &["allowFrom"], json!(allow_from));
             }
-            set_nested_value(&mut config, &["channels", "whatsapp", "groupPolicy"], json!(group_policy));
+            set_nested_value(section, &["groupPolicy"], json!(group_policy));
             if !group_allow_from.is_empty() {
-                set_nested_value(&mut config, &["channels", "whatsapp", "groupAllowFrom"], json!(group_allow_from));
+                set_nested_value(section, &["groupAllowFrom"], json!(group_allow_from));
             }
-            set_nested_value(&mut config, &["channels", "whatsapp", "groups", "*", "requireMention"], json!(require_mention));
+            set_nested_value(section, &["groups", "*", "requireMention"], json!(require_mention));
         }
         "slack" => {
-            set_nested_value(&mut config, &["channels", "slack", "enabled"], json!(true));
-            set_nested_value(&mut config, &["channels", "slack", "botToken"], json!(token));
-            set_nested_value(&mut config, &["channels", "slack", "groupPolicy"], json!(group_policy));
-            set_nested_value(&mut config, &["channels", "slack", "dm", "policy"], json!(dm_policy));
+            set_nested_value(section, &["enabled"], json!(true));
+            if token != MASKED_SECRET {
+                set_nested_value(section, &["botToken"], json!(token));
+            }
+            set_nested_value(section, &["groupPolicy"], json!(group_policy));
+            set_nested_value(section, &["dm", "policy"], json!(dm_policy));
             if !allow_from.is_empty() {
-                set_nested_value(&mut config, &["channels", "slack", "dm", "allowFrom"], json!(allow_from));
+                set_nested_value(section, &["dm", "allowFrom"], json!(allow_from));
             }
             // Slack은 channels 설정으로 채널 허용 목록 관리
             if !group_allow_from.is_empty() {
                 // 각 채널 ID를 channels 설정에 추가
                 for channel_id in group_allow_from {
-                    set_nested_value(&mut config, &["channels", "slack", "channels", channel_id, "enabled"], json!(true));
+                    set_nested_value(section, &["channels", channel_id, "enabled"], json!(true));
                 }
             }
-            set_nested_value(&mut config, &["channels", "slack", "requireMention"], json!(require_mention));
+            set_nested_value(section, &["requireMention"], json!(require_mention));
         }
         "googlechat" => {
-            set_nested_value(&mut config, &["channels", "googlechat", "enabled"], json!(true));
-            set_nested_value(&mut config, &["channels", "googlechat", "dm", "enabled"], json!(true));
-            set_nested_value(&mut config, &["channels", "googlechat", "dm", "policy"], json!(dm_policy));
+            set_nested_value(section, &["enabled"], json!(true));
+            set_nested_value(section, &["dm", "enabled"], json!(true));
+            set_nested_value(section, &["dm", "policy"], json!(dm_policy));
             if !allow_from.is_empty() {
-                set_nested_value(&mut config, &["channels", "googlechat", "dm", "allowFrom"], json!(allow_from));
+                set_nested_value(section, &["dm", "allowFrom"], json!(allow_from));
             }
-            set_nested_value(&mut config, &["channels", "googlechat", "groupPolicy"], json!(group_policy));
+            set_nested_value(section, &["groupPolicy"], json!(group_policy));
             if !group_allow_from.is_empty() {
-                set_nested_value(&mut config, &["channels", "googlechat", "groupAllowFrom"], json!(group_allow_from));
+                set_nested_value(section, &["groupAllowFrom"], json!(group_allow_from));
             }
-            set_nested_value(&mut config, &["channels", "googlechat", "requireMention"], json!(require_mention));
+            set_nested_value(section, &["requireMention"], json!(require_mention));
         }
         "mattermost" => {
-            set_nested_value(&mut config, &["channels", "mattermost", "enabled"], json!(true));
-            set_nested_value(&mut config, &["channels", "mattermost", "botToken"], json!(token));
-            set_nested_value(&mut config, &["channels", "mattermost", "dmPolicy"], json!(dm_policy));
+            set_nested_value(section, &["enabled"], json!(true));
+            if token != MASKED_SECRET {
+                set_nested_value(section, &["botToken"], json!(token));
+            }
+            set_nested_value(section, &["dmPolicy"], json!(dm_policy));
             if !allow_from.is_empty() {
-                set_nested_value(&mut config, &["channels", "mattermost", "allowFrom"], json!(allow_from));
+                set_nested_value(section, &["allowFrom"], json!(allow_from));
             }
-            set_nested_value(&mut config, &["channels", "mattermost", "groupPolicy"], json!(group_policy));
+            set_nested_value(section, &["groupPolicy"], json!(group_policy));
             if !group_allow_from.is_empty() {
-                set_nested_value(&mut config, &["channels", "mattermost", "groupAllowFrom"], json!(group_allow_from));
+                set_nested_value(section, &["groupAllowFrom"], json!(group_allow_from));
             }
-            set_nested_value(&mut config, &["channels", "mattermost", "requireMention"], json!(require_mention));
+            set_nested_value(section, &["requireMention"], json!(require_mention));
+        }
+        "signal" | "matrix" | "line" | "imessage" => {
+            write_plugin_channel_fields(section, channel, token, dm_policy, &allow_from, group_policy, group_allow_from);
         }
         _ => return Err(format!("지원하지 않는 채널: {}", channel)),
     }
@@ -2628,6 +2925,10 @@ pub async fn update_integrations_config(integrations: Value) -> Result<(), Strin
     if let Some(vars) = integrations.as_object() {
         for (key, value) in vars {
             if let Some(v) = value.as_str() {
+                // 읽기 모델의 마스킹 값이 그대로 돌아오면 기존 값 유지
+                if v == MASKED_SECRET {
+                    continue;
+                }
                 // OpenClaw 내장 도구 확인 (Brave, Firecrawl)
                 let is_builtin = is_builtin_tool(key);
                 
@@ -2692,13 +2993,16 @@ pub async fn get_config_summary() -> Result<String, String> {
     
     let mut summary = String::new();
     
-    // Model
-    if let Some(model) = config.get("agents")
-        .and_then(|a| a.get("defaults"))
-        .and_then(|d| d.get("model"))
-        .and_then(|m| m.as_str())
-    {
+    // Model (문자열/객체 형식 모두 지원)
+    if let Some(model) = primary_model_ref(&config) {
         summary.push_str(&format!("모델: {}\n", model));
+        let fallbacks: Vec<&str> = config.pointer("/agents/defaults/model/fallbacks")
+            .and_then(|f| f.as_array())
+            .map(|f| f.iter().filter_map(|v| v.as_str()).collect())
+            .unwrap_or_default();
+        if !fallbacks.is_empty() {
+            summary.push_str(&format!("대체 모델: {}\n", fallbacks.join(", ")));
+        }
     }
     
     // Workspace
@@ -2718,15 +3022,31 @@ pub async fn get_config_summary() -> Result<String, String> {
         summary.push_str(&format!("Gateway 포트: {}\n", port));
     }
     
-    // Channels
-    if config.get("channels").and_then(|c| c.get("telegram")).is_some() {
-        summary.push_str("Telegram: 설정됨\n");
+    // Channels (계정 수 포함)
+    for channel in channels_report(&config) {
+        let name = match channel["channel"].as_str().unwrap_or("") {
+            "telegram" => "Telegram",
+            "discord" => "Discord",
+            "whatsapp" => "WhatsApp",
+            "slack" => "Slack",
+            "googlechat" => "Google Chat",
+            "mattermost" => "Mattermost",
+            other => other,
+        }.to_string();
+        let state = if channel["enabled"].as_bool() == Some(true) { "활성" } else { "비활성" };
+        let accounts = channel["accounts"].as_array().map(|a| a.len()).unwrap_or(0);
+        summary.push_str(&format!("{}: {} (계정 {}개)\n", name, state, accounts));
     }
-    if config.get("channels").and_then(|c| c.get("discord")).is_some() {
-        summary.push_str("Discord: 설정됨\n");
+    
+    // Integrations / Skills
+    let integrations = config.pointer("/env/vars").and_then(|v| v.as_object()).map(|v| v.len()).unwrap_or(0);
+    if integrations > 0 {
+        summary.push_str(&format!("연동: {}개\n", integrations));
     }
-    if config.get("channels").and_then(|c| c.get("whatsapp")).is_some() {
-        summary.push_str("WhatsApp: 설정됨\n");
+    let skills = skills_report(&config);
+    if !skills.is_empty() {
+        let enabled = skills.iter().filter(|s| s["enabled"].as_bool() == Some(true)).count();
+        summary.push_str(&format!("스킬: {}개 활성 / {}개\n", enabled, skills.len()));
     }
     
     if summary.is_empty() {
@@ -2937,6 +3257,10 @@ pub fn get_node_install_url() -> String {
 
 /// 환경변수/API 키 설정 (openclaw.json의 env 섹션에 저장)
 pub async fn set_env_config(key: &str, value: &str) -> Result<(), String> {
+    // 마스킹된 값을 그대로 돌려받은 경우 기존 값 유지
+    if value == MASKED_SECRET {
+        return Ok(());
+    }
     let mut config = read_existing_config();
 
     // env.vars 섹션에 저장
@@ -2968,7 +3292,8 @@ pub async fn set_env_config(key: &str, value: &str) -> Result<(), String> {
             set_nested_value(&mut config, &["channels", "mattermost", "botToken"], json!(value));
         }
         "MATTERMOST_URL" => {
-            set_nested_value(&mut config, &["channels", "mattermost", "baseUrl"], json!(value));
+            let url = crate::mattermost::normalize_base_url(value).unwrap_or_else(|_| value.to_string());
+            set_nested_value(&mut config, &["channels", "mattermost", "baseUrl"], json!(url));
         }
         // Google Chat
         "GOOGLE_CHAT_SERVICE_ACCOUNT_FILE" => {
@@ -2981,6 +3306,9 @@ pub async fn set_env_config(key: &str, value: &str) -> Result<(), String> {
         "GROQ_API_KEY" => {
             set_nested_value(&mut config, &["models", "providers", "groq", "apiKey"], json!(value));
         }
+        "TOGETHER_API_KEY" => {
+            set_nested_value(&mut config, &["models", "providers", "together", "apiKey"], json!(value));
+        }
         "MINIMAX_API_KEY" => {
             set_nested_value(&mut config, &["models", "providers", "minimax", "apiKey"], json!(value));
         }
@@ -3280,43 +3608,16 @@ pub async fn set_slack_app_token(app_token: &str) -> Result<(), String> {
     Ok(())
 }
 
-/// Google Chat Service Account 파일 경로 설정
+/// Google Chat Service Account 키 설정
+/// 키를 검증한 뒤 moldClaw 관리 위치로 복사(0600)하고 그 경로를 serviceAccountFile에 저장
 pub async fn set_googlechat_service_account(file_path: &str) -> Result<(), String> {
-    if file_path.is_empty() {
-        return Err("파일 경로가 비어있습니다.".to_string());
-    }
-    
-    // 파일 존재 확인
-    if !std::path::Path::new(file_path).exists() {
-        return Err(format!("파일을 찾을 수 없습니다: {}", file_path));
-    }
-    
-    let mut config = read_existing_config();
-    
-    if config.as_object().map(|o| o.is_empty()).unwrap_or(true) {
-        return Err("Config가 없습니다.".to_string());
-    }
-    
-    // meta.lastTouchedAt 업데이트
-    let now = Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
-    set_nested_value(&mut config, &["meta", "lastTouchedAt"], json!(now));
-    
-    // Google Chat serviceAccountFile 설정
-    set_nested_value(
-        &mut config,
-        &["channels", "googlechat", "serviceAccountFile"],
-        json!(file_path),
-    );
-    
-    write_config(&config)?;
-    Ok(())
+    crate::googlechat::import_key_file(file_path, None).map(|_| ())
 }
 
 /// Mattermost URL 설정
+/// 입력한 주소는 정규화해서 저장 (스킴 보완, 끝 슬래시/팀 채널 경로 제거)
 pub async fn set_mattermost_url(url: &str) -> Result<(), String> {
-    if url.is_empty() {
-        return Err("URL이 비어있습니다.".to_string());
-    }
+    let url = crate::mattermost::normalize_base_url(url)?;
     
     let mut config = read_existing_config();
     
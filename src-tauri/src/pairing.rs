@@ -0,0 +1,422 @@
+// DM 페어링 요청 수신함 및 허용 목록(allowFrom) 관리
+// dmPolicy가 "pairing"이면 OpenClaw가 모르는 사용자에게 페어링 코드를 발급하고
+// 요청을 ~/.openclaw/credentials/<channel>-pairing.json 에 보관합니다.
+// 승인은 `openclaw pairing approve <channel> <code>`로 처리하고 보낸 사람을 allowFrom에도 추가합니다.
+// 허용 목록 항목의 표시 이름은 moldClaw 상태(allowlist-labels.json)에 따로 저장합니다.
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::path::PathBuf;
+
+use crate::openclaw::{
+    dm_setting_path, existing_channel_section_mut, get_moldclaw_state_dir, get_openclaw_dir,
+    normalize_account_id, read_existing_config, run_openclaw_command, set_nested_value,
+    write_config, ACCOUNT_CHANNELS,
+};
+// credentials/ 아래 파일은 OpenClaw와 같이 소유자 전용(0600)으로 저장
+use crate::auth_profiles::write_json;
+
+/// OpenClaw 페어링 코드 유효 시간
+const PAIRING_TTL_MS: i64 = 60 * 60 * 1000;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PairingRequest {
+    pub channel: String,
+    /// 보낸 사람 ID (allowFrom에 추가되는 값)
+    pub sender_id: String,
+    pub code: String,
+    pub account_id: Option<String>,
+    /// 플랫폼에서 받은 이름 (있으면)
+    pub display_name: Option<String>,
+    pub created_at: Option<String>,
+    pub last_seen_at: Option<String>,
+    pub expired: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AllowlistEntry {
+    pub id: String,
+    pub label: Option<String>,
+    /// config: openclaw.json allowFrom | pairing: 페어링 승인 저장소
+    pub source: String,
+}
+
+// ===== 파일 경로 =====
+
+fn pairing_store_path(channel: &str) -> PathBuf {
+    get_openclaw_dir().join("credentials").join(format!("{}-pairing.json", channel))
+}
+
+fn approved_store_path(channel: &str) -> PathBuf {
+    get_openclaw_dir().join("credentials").join(format!("{}-allowFrom.json", channel))
+}
+
+fn labels_path() -> PathBuf {
+    get_moldclaw_state_dir().join("allowlist-labels.json")
+}
+
+fn read_json(path: &PathBuf) -> Value {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_else(|| json!({}))
+}
+
+fn validate_channel(channel: &str) -> Result<(), String> {
+    if ACCOUNT_CHANNELS.contains(&channel) {
+        Ok(())
+    } else {
+        Err(format!("지원하지 않는 채널: {}", channel))
+    }
+}
+
+fn validate_entry(entry: &str) -> Result<String, String> {
+    let entry = entry.trim();
+    if entry.is_empty() || entry.chars().any(char::is_whitespace) {
+        return Err(format!("허용 목록 항목이 올바르지 않습니다: '{}'", entry));
+    }
+    Ok(entry.to_string())
+}
+
+/// 허용 목록 비교용 키
+/// 기본은 입력 그대로 비교하고, 전화번호/숫자 ID를 쓰는 Telegram·WhatsApp만
+/// 채널 접두어와 구분 기호를 떼어 숫자로 맞춤 (다른 사용자가 같은 키가 되지 않도록)
+fn peer_key(channel: &str, id: &str) -> String {
+    let id = id.trim();
+    let prefixes: &[&str] = match channel {
+        "telegram" => &["telegram:", "tg:"],
+        "whatsapp" => &["whatsapp:"],
+        _ => return id.to_string(),
+    };
+    let rest = prefixes
+        .iter()
+        .find_map(|p| {
+            id.get(..p.len())
+                .filter(|head| head.eq_ignore_ascii_case(p))
+                .map(|_| &id[p.len()..])
+        })
+        .unwrap_or(id);
+    let is_number = rest.chars().any(|c| c.is_ascii_digit())
+        && rest.chars().all(|c| c.is_ascii_digit() || "+-() ".contains(c));
+    if is_number {
+        rest.chars().filter(|c| c.is_ascii_digit()).collect()
+    } else {
+        id.to_string()
+    }
+}
+
+pub(crate) fn same_peer(channel: &str, a: &str, b: &str) -> bool {
+    a == b || peer_key(channel, a) == peer_key(channel, b)
+}
+
+// ===== 페어링 요청 =====
+
+fn parse_requests(channel: &str, store: &Value) -> Vec<PairingRequest> {
+    let now = chrono::Utc::now().timestamp_millis();
+    let str_field = |r: &Value, pointer: &str| r.pointer(pointer).and_then(|v| v.as_str()).map(|s| s.to_string());
+
+    store
+        .get("requests")
+        .and_then(|r| r.as_array())
+        .map(|list| {
+            list.iter()
+                .filter_map(|r| {
+                    let created_at = str_field(r, "/createdAt");
+                    let expired = created_at
+                        .as_deref()
+                        .and_then(|c| chrono::DateTime::parse_from_rfc3339(c).ok())
+                        .is_some_and(|c| now - c.timestamp_millis() > PAIRING_TTL_MS);
+                    Some(PairingRequest {
+                        channel: channel.to_string(),
+                        sender_id: str_field(r, "/id")?,
+                        code: str_field(r, "/code")?,
+                        account_id: str_field(r, "/meta/accountId"),
+                        display_name: str_field(r, "/meta/name")
+                            .or_else(|| str_field(r, "/meta/username")),
+                        created_at,
+                        last_seen_at: str_field(r, "/lastSeenAt"),
+                        expired,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn find_request(channel: &str, code: &str) -> Result<PairingRequest, String> {
+    parse_requests(channel, &read_json(&pairing_store_path(channel)))
+        .into_iter()
+        .find(|r| r.code.eq_ignore_ascii_case(code.trim()))
+        .ok_or_else(|| format!("페어링 요청을 찾을 수 없습니다: {} {}", channel, code))
+}
+
+/// 페어링 저장소에서 요청 제거
+/// (`openclaw pairing`에는 거절 명령이 없으므로 파일을 직접 수정하고 권한은 0600 유지)
+fn remove_request(channel: &str, code: &str) -> Result<(), String> {
+    let path = pairing_store_path(channel);
+    let mut store = read_json(&path);
+    if let Some(list) = store.get_mut("requests").and_then(|r| r.as_array_mut()) {
+        list.retain(|r| {
+            !r.get("code")
+                .and_then(|c| c.as_str())
+                .is_some_and(|c| c.eq_ignore_ascii_case(code.trim()))
+        });
+    }
+    write_json(&path, &store)
+}
+
+// ===== 허용 목록 =====
+
+fn config_allow_from(config: &Value, channel: &str, account_id: Option<&str>) -> Vec<String> {
+    let mut pointer = format!("/channels/{}", channel);
+    if let Some(id) = account_id {
+        pointer.push_str(&format!("/accounts/{}", id));
+    }
+    pointer.push_str(&format!("/{}", dm_setting_path(channel, "allowFrom").join("/")));
+    config
+        .pointer(&pointer)
+        .and_then(|a| a.as_array())
+        .map(|arr| arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect())
+        .unwrap_or_default()
+}
+
+fn set_config_allow_from(config: &mut Value, channel: &str, account_id: Option<&str>, list: Vec<String>) -> Result<(), String> {
+    let section = existing_channel_section_mut(config, channel, account_id)?;
+    set_nested_value(section, &dm_setting_path(channel, "allowFrom"), json!(list));
+    Ok(())
+}
+
+/// allowFrom에 추가 (이미 있으면 무시). 추가되었으면 true
+fn add_to_config(config: &mut Value, channel: &str, account_id: Option<&str>, entry: &str) -> Result<bool, String> {
+    let mut list = config_allow_from(config, channel, account_id);
+    if list.iter().any(|e| same_peer(channel, e, entry)) {
+        return Ok(false);
+    }
+    list.push(entry.to_string());
+    set_config_allow_from(config, channel, account_id, list)?;
+    Ok(true)
+}
+
+fn approved_store_entries(channel: &str) -> Vec<String> {
+    read_json(&approved_store_path(channel))
+        .get("allowFrom")
+        .and_then(|a| a.as_array())
+        .map(|arr| arr.iter().filter_map(|v| v.as_str().map(|s| s.to_string())).collect())
+        .unwrap_or_default()
+}
+
+fn load_labels() -> Value {
+    read_json(&labels_path())
+}
+
+fn label_for(labels: &Value, channel: &str, entry: &str) -> Option<String> {
+    labels
+        .get(channel)
+        .and_then(|c| c.get(peer_key(channel, entry)))
+        .and_then(|l| l.as_str())
+        .map(|s| s.to_string())
+}
+
+fn save_label(channel: &str, entry: &str, label: Option<&str>) -> Result<(), String> {
+    let mut labels = load_labels();
+    let key = peer_key(channel, entry);
+    match label.map(str::trim).filter(|l| !l.is_empty()) {
+        Some(label) => set_nested_value(&mut labels, &[channel, &key], json!(label)),
+        None => {
+            if let Some(map) = labels.get_mut(channel).and_then(|c| c.as_object_mut()) {
+                map.remove(&key);
+            }
+        }
+    }
+    write_json(&labels_path(), &labels)
+}
+
+// ===== Tauri 커맨드 =====
+
+/// 대기 중인 페어링 요청 목록 (channel을 생략하면 모든 채널)
+#[tauri::command]
+pub fn list_pairing_requests(channel: Option<String>) -> Result<Vec<PairingRequest>, String> {
+    let channels: Vec<&str> = match channel.as_deref() {
+        Some(ch) => {
+            validate_channel(ch)?;
+            vec![ch]
+        }
+        None => ACCOUNT_CHANNELS.to_vec(),
+    };
+    Ok(channels
+        .into_iter()
+        .flat_map(|ch| parse_requests(ch, &read_json(&pairing_store_path(ch))))
+        .collect())
+}
+
+/// 페어링 요청 승인: OpenClaw 승인 처리 + 보낸 사람을 allowFrom에 추가
+/// account_id를 생략하면 요청에 기록된 계정(없으면 기본 계정)에 추가
+#[tauri::command]
+pub fn approve_pairing_request(
+    channel: String,
+    code: String,
+    account_id: Option<String>,
+    label: Option<String>,
+) -> Result<PairingRequest, String> {
+    validate_channel(&channel)?;
+    let request = find_request(&channel, &code)?;
+    if request.expired {
+        return Err("만료된 페어링 코드입니다. 사용자가 다시 메시지를 보내 새 코드를 받아야 합니다".to_string());
+    }
+
+    run_openclaw_command(&["pairing", "approve", &channel, &request.code])
+        .map_err(|e| format!("페어링 승인 실패: {}", e))?;
+
+    let account_id = normalize_account_id(account_id.as_deref().or(request.account_id.as_deref()))?;
+    let mut config = read_existing_config();
+    if add_to_config(&mut config, &channel, account_id.as_deref(), &request.sender_id)? {
+        write_config(&config)?;
+    }
+
+    let label = label.or_else(|| request.display_name.clone());
+    if label.is_some() {
+        save_label(&channel, &request.sender_id, label.as_deref())?;
+    }
+    Ok(request)
+}
+
+/// 페어링 요청 거절 (요청만 삭제, 허용 목록은 변경하지 않음)
+#[tauri::command]
+pub fn deny_pairing_request(channel: String, code: String) -> Result<(), String> {
+    validate_channel(&channel)?;
+    let request = find_request(&channel, &code)?;
+    remove_request(&channel, &request.code)
+}
+
+/// 허용 목록 (config allowFrom + 페어링 승인 저장소, 표시 이름 포함)
+#[tauri::command]
+pub fn get_allowlist(channel: String, account_id: Option<String>) -> Result<Vec<AllowlistEntry>, String> {
+    validate_channel(&channel)?;
+    let account_id = normalize_account_id(account_id.as_deref())?;
+    let config = read_existing_config();
+    let labels = load_labels();
+
+    let mut entries: Vec<AllowlistEntry> = config_allow_from(&config, &channel, account_id.as_deref())
+        .into_iter()
+        .map(|id| AllowlistEntry {
+            label: label_for(&labels, &channel, &id),
+            id,
+            source: "config".to_string(),
+        })
+        .collect();
+    for id in approved_store_entries(&channel) {
+        if !entries.iter().any(|e| same_peer(&channel, &e.id, &id)) {
+            entries.push(AllowlistEntry {
+                label: label_for(&labels, &channel, &id),
+                id,
+                source: "pairing".to_string(),
+            });
+        }
+    }
+    Ok(entries)
+}
+
+/// 허용 목록 항목 추가 (label은 표시 이름)
+#[tauri::command]
+pub fn add_allowlist_entry(
+    channel: String,
+    entry: String,
+    account_id: Option<String>,
+    label: Option<String>,
+) -> Result<(), String> {
+    validate_channel(&channel)?;
+    let entry = validate_entry(&entry)?;
+    let account_id = normalize_account_id(account_id.as_deref())?;
+
+    let mut config = read_existing_config();
+    if add_to_config(&mut config, &channel, account_id.as_deref(), &entry)? {
+        write_config(&config)?;
+    }
+    if label.is_some() {
+        save_label(&channel, &entry, label.as_deref())?;
+    }
+    Ok(())
+}
+
+/// 허용 목록 항목 삭제 (config allowFrom과 페어링 승인 저장소 모두에서 제거)
+#[tauri::command]
+pub fn remove_allowlist_entry(channel: String, entry: String, account_id: Option<String>) -> Result<(), String> {
+    validate_channel(&channel)?;
+    let entry = validate_entry(&entry)?;
+    let account_id = normalize_account_id(account_id.as_deref())?;
+    let mut config = read_existing_config();
+
+    // dmPolicy "open"은 allowFrom에 "*"가 필수
+    if entry == "*" {
+        let mut pointer = format!("/channels/{}", channel);
+        if let Some(id) = account_id.as_deref() {
+            pointer.push_str(&format!("/accounts/{}", id));
+        }
+        pointer.push_str(&format!("/{}", dm_setting_path(&channel, "policy").join("/")));
+        if config.pointer(&pointer).and_then(|p| p.as_str()) == Some("open") {
+            return Err("DM 정책이 open이면 '*' 항목을 삭제할 수 없습니다. 먼저 DM 정책을 변경하세요".to_string());
+        }
+    }
+
+    let list = config_allow_from(&config, &channel, account_id.as_deref());
+    let remaining: Vec<String> = list.iter().filter(|e| !same_peer(&channel, e, &entry)).cloned().collect();
+    if remaining.len() != list.len() {
+        set_config_allow_from(&mut config, &channel, account_id.as_deref(), remaining)?;
+        write_config(&config)?;
+    }
+
+    let store_path = approved_store_path(&channel);
+    let mut store = read_json(&store_path);
+    if let Some(arr) = store.get_mut("allowFrom").and_then(|a| a.as_array_mut()) {
+        let before = arr.len();
+        arr.retain(|v| !v.as_str().is_some_and(|id| same_peer(&channel, id, &entry)));
+        if arr.len() != before {
+            write_json(&store_path, &store)?;
+        }
+    }
+    Ok(())
+}
+
+/// 허용 목록 항목의 표시 이름 설정 (빈 값이면 삭제)
+#[tauri::command]
+pub fn set_allowlist_label(channel: String, entry: String, label: Option<String>) -> Result<(), String> {
+    validate_channel(&channel)?;
+    let entry = validate_entry(&entry)?;
+    save_label(&channel, &entry, label.as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_peer_normalizes_only_numeric_channels() {
+        assert!(same_peer("telegram", "tg:123456", "123456"));
+        assert!(same_peer("telegram", "Telegram:123456", "123456"));
+        assert!(same_peer("whatsapp", "whatsapp:+82 10-1234-5678", "+821012345678"));
+        assert!(!same_peer("telegram", "@alice", "alice"));
+        // 다른 채널은 입력 그대로 비교
+        assert!(!same_peer("discord", "user:1234", "1234"));
+        assert!(!same_peer("slack", "U0ABC", "u0abc"));
+        assert!(same_peer("slack", "U0ABC", "U0ABC"));
+    }
+
+    #[test]
+    fn allow_from_is_not_written_to_unknown_accounts() {
+        let mut config = json!({
+            "channels": {
+                "telegram": { "botToken": "1:abc", "allowFrom": ["111"], "accounts": { "work": { "botToken": "2:def" } } }
+            }
+        });
+        assert!(!add_to_config(&mut config, "telegram", None, "tg:111").unwrap());
+        assert!(add_to_config(&mut config, "telegram", Some("work"), "222").unwrap());
+        assert_eq!(config_allow_from(&config, "telegram", Some("work")), vec!["222".to_string()]);
+
+        let before = config.clone();
+        assert!(add_to_config(&mut config, "telegram", Some("wrok"), "333").is_err());
+        assert!(add_to_config(&mut config, "discord", None, "444").is_err());
+        assert_eq!(config, before);
+    }
+}
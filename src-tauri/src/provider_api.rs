@@ -0,0 +1,781 @@
+// 프로바이더 API 호출 (API 키 검증, 실시간 모델 목록)
+// 각 프로바이더에 가벼운 인증 요청(모델 목록 등)을 보내 결과를 분류합니다.
+// 모델 목록은 로컬 카탈로그와 병합하고 TTL 캐시(~/.openclaw/moldclaw/model_cache)에 저장합니다.
+// base_url을 지정하면 해당 주소로 요청하므로 로컬 mock 서버로 테스트할 수 있습니다.
+
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use crate::model_catalog::{self, CatalogModel, ModelCost};
+use crate::openclaw::{detect_auth_mode, get_moldclaw_state_dir, pointer_escape, read_existing_config};
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// 모델 목록 캐시 유효 시간
+const MODEL_CACHE_TTL_MS: i64 = 6 * 60 * 60 * 1000;
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyStatus {
+    Valid,
+    InvalidKey,
+    QuotaExhausted,
+    /// 요청 빈도 제한 (키는 유효할 수 있으므로 검증 보류)
+    RateLimited,
+    NetworkError,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyValidation {
+    pub provider: String,
+    pub status: KeyStatus,
+    pub http_status: Option<u16>,
+    pub message: String,
+}
+
+/// 프로바이더 API 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiStyle {
+    Anthropic,
+    Google,
+    /// OpenAI 호환 (Bearer 인증, /models)
+    OpenAi,
+}
+
+impl ApiStyle {
+    pub fn from_api(api: Option<&str>) -> Self {
+        match api {
+            Some("anthropic-messages") => ApiStyle::Anthropic,
+            Some("google-generative-ai") => ApiStyle::Google,
+            _ => ApiStyle::OpenAi,
+        }
+    }
+}
+
+/// 요청 대상 (프로바이더 기본값 또는 지정한 base_url)
+pub struct Endpoint {
+    pub base_url: String,
+    pub style: ApiStyle,
+}
+
+/// 프로바이더 엔드포인트 (openclaw.json → 모델 카탈로그 순), base_url로 덮어쓰기 가능
+/// 설정 파일의 baseUrl을 우선하므로 프록시/게이트웨이를 거치도록 설정한 경우 그 주소로 검증
+pub fn resolve_endpoint(provider: &str, base_url: Option<&str>) -> Result<Endpoint, String> {
+    let configured = read_existing_config()
+        .pointer(&format!("/models/providers/{}", pointer_escape(provider)))
+        .cloned();
+    endpoint_from(provider, base_url, configured.as_ref(), model_catalog::find_provider(provider))
+}
+
+/// 설정(models.providers.<p>)과 카탈로그 항목으로 엔드포인트 결정
+fn endpoint_from(
+    provider: &str,
+    base_url: Option<&str>,
+    configured: Option<&serde_json::Value>,
+    catalog: Option<model_catalog::CatalogProvider>,
+) -> Result<Endpoint, String> {
+    let config_str = |key: &str| {
+        configured
+            .and_then(|p| p.get(key))
+            .and_then(|v| v.as_str())
+            .filter(|s| !s.trim().is_empty())
+            .map(|s| s.to_string())
+    };
+
+    let api = config_str("api").or_else(|| catalog.as_ref().and_then(|p| p.api.clone()));
+    let style = ApiStyle::from_api(api.as_deref());
+    let base_url = base_url
+        .filter(|u| !u.trim().is_empty())
+        .map(|u| u.to_string())
+        .or_else(|| config_str("baseUrl"))
+        .or_else(|| catalog.and_then(|p| p.base_url))
+        .ok_or_else(|| format!("{} 프로바이더의 API 주소를 알 수 없습니다", provider))?;
+    Ok(Endpoint {
+        base_url: base_url.trim().trim_end_matches('/').to_string(),
+        style,
+    })
+}
+
+pub fn http_client() -> Result<reqwest::Client, String> {
+    reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .map_err(|e| format!("HTTP 클라이언트 생성 실패: {}", e))
+}
+
+/// 인증 헤더를 붙인 GET 요청 생성
+/// path는 base_url 뒤에 붙는 경로 (예: "/models")
+pub fn authorized_get(
+    client: &reqwest::Client,
+    provider: &str,
+    endpoint: &Endpoint,
+    api_key: &str,
+    path: &str,
+) -> reqwest::RequestBuilder {
+    match endpoint.style {
+        ApiStyle::Anthropic => {
+            // baseUrl에 /v1이 없는 형식 (https://api.anthropic.com)
+            let url = if endpoint.base_url.ends_with("/v1") {
+                format!("{}{}", endpoint.base_url, path)
+            } else {
+                format!("{}/v1{}", endpoint.base_url, path)
+            };
+            let request = client.get(url).header("anthropic-version", ANTHROPIC_VERSION);
+            if detect_auth_mode(provider, api_key) == "token" {
+                request
+                    .bearer_auth(api_key)
+                    .header("anthropic-beta", "oauth-2025-04-20")
+            } else {
+                request.header("x-api-key", api_key)
+            }
+        }
+        ApiStyle::Google => client
+            .get(format!("{}{}", endpoint.base_url, path))
+            .query(&[("key", api_key)]),
+        ApiStyle::OpenAi => client
+            .get(format!("{}{}", endpoint.base_url, path))
+            .bearer_auth(api_key),
+    }
+}
+
+/// 응답 본문에서 오류 메시지 추출
+fn error_message(body: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|v| {
+            v.pointer("/error/message")
+                .or_else(|| v.get("message"))
+                .or_else(|| v.get("error"))
+                .and_then(|m| m.as_str())
+                .map(|s| s.to_string())
+        })
+        .unwrap_or_else(|| crate::truncate_message(body.trim(), 200))
+}
+
+/// HTTP 상태 코드와 본문으로 결과 분류
+fn classify(status: u16, body: &str) -> (KeyStatus, String) {
+    let message = error_message(body);
+    let lower = body.to_lowercase();
+    let quota_hint = ["insufficient_quota", "quota", "credit", "billing", "resource_exhausted"]
+        .iter()
+        .any(|k| lower.contains(k));
+
+    match status {
+        200..=299 => (KeyStatus::Valid, "API 키가 유효합니다".to_string()),
+        401 => (KeyStatus::InvalidKey, message),
+        // Google은 잘못된 키에 400 API_KEY_INVALID 반환
+        400 if lower.contains("api_key_invalid") || lower.contains("api key not valid") => {
+            (KeyStatus::InvalidKey, message)
+        }
+        402 => (KeyStatus::QuotaExhausted, message),
+        403 if quota_hint => (KeyStatus::QuotaExhausted, message),
+        403 => (KeyStatus::InvalidKey, message),
+        429 if quota_hint => (KeyStatus::QuotaExhausted, message),
+        429 => (KeyStatus::RateLimited, format!("요청 빈도 제한 (HTTP 429): {}", message)),
+        _ => (KeyStatus::NetworkError, format!("HTTP {}: {}", status, message)),
+    }
+}
+
+/// API 키 검증
+pub async fn validate_key(provider: &str, api_key: &str, base_url: Option<&str>) -> KeyValidation {
+    match resolve_endpoint(provider, base_url) {
+        Ok(endpoint) => validate_key_at(provider, api_key, &endpoint).await,
+        Err(message) => KeyValidation {
+            provider: provider.to_string(),
+            status: KeyStatus::NetworkError,
+            http_status: None,
+            message,
+        },
+    }
+}
+
+/// 정해진 엔드포인트로 API 키 검증
+async fn validate_key_at(provider: &str, api_key: &str, endpoint: &Endpoint) -> KeyValidation {
+    let result = |status, http_status, message: String| KeyValidation {
+        provider: provider.to_string(),
+        status,
+        http_status,
+        message,
+    };
+
+    if api_key.trim().is_empty() {
+        return result(KeyStatus::InvalidKey, None, "API 키가 비어 있습니다".to_string());
+    }
+    let client = match http_client() {
+        Ok(c) => c,
+        Err(e) => return result(KeyStatus::NetworkError, None, e),
+    };
+
+    // OpenRouter의 /models는 인증 없이도 응답하므로 키 정보 엔드포인트 사용
+    let path = if provider == "openrouter" { "/key" } else { "/models" };
+    // Google은 키를 쿼리에 넣으므로 오류 메시지에서 URL 제외
+    let response = match authorized_get(&client, provider, endpoint, api_key.trim(), path)
+        .send()
+        .await
+    {
+        Ok(r) => r,
+        Err(e) => return result(KeyStatus::NetworkError, None, format!("연결 실패: {}", e.without_url())),
+    };
+
+    let status = response.status().as_u16();
+    let body = response.text().await.unwrap_or_default();
+    let (mut key_status, mut message) = classify(status, &body);
+
+    // OpenRouter: 크레딧 한도 소진 여부
+    if key_status == KeyStatus::Valid && provider == "openrouter" {
+        let remaining = serde_json::from_str::<serde_json::Value>(&body)
+            .ok()
+            .and_then(|v| v.pointer("/data/limit_remaining").and_then(|r| r.as_f64()));
+        if remaining.is_some_and(|r| r <= 0.0) {
+            key_status = KeyStatus::QuotaExhausted;
+            message = "크레딧 한도를 모두 사용했습니다".to_string();
+        }
+    }
+
+    result(key_status, Some(status), message)
+}
+
+/// 환경 변수 키 → 프로바이더 이름 (프로바이더 키가 아니면 None)
+pub fn provider_for_env_key(key: &str) -> Option<&'static str> {
+    match key {
+        "ANTHROPIC_API_KEY" => Some("anthropic"),
+        "OPENAI_API_KEY" => Some("openai"),
+        "GEMINI_API_KEY" | "GOOGLE_API_KEY" => Some("google"),
+        "OPENROUTER_API_KEY" => Some("openrouter"),
+        "GROQ_API_KEY" => Some("groq"),
+        "TOGETHER_API_KEY" => Some("together"),
+        "MINIMAX_API_KEY" => Some("minimax"),
+        "MOONSHOT_API_KEY" => Some("moonshot"),
+        "ZAI_API_KEY" => Some("zai"),
+        "KIMI_API_KEY" => Some("kimi-coding"),
+        "SYNTHETIC_API_KEY" => Some("synthetic"),
+        "VENICE_API_KEY" => Some("venice"),
+        "XIAOMI_API_KEY" => Some("xiaomi"),
+        "VERCEL_GATEWAY_API_KEY" => Some("vercel-gateway"),
+        "OPENCODE_API_KEY" => Some("opencode"),
+        _ => None,
+    }
+}
+
+/// 저장 전 검증: 잘못된 키/한도 소진은 거부, 네트워크·서버 오류(5xx 등)와 빈도 제한(429)은 오프라인 설정을 위해 허용
+/// 검증하지 못하고 허용한 경우 사용자에게 보여줄 안내 문구 반환
+pub async fn ensure_key_usable(provider: &str, api_key: &str) -> Result<Option<String>, String> {
+    let validation = validate_key(provider, api_key, None).await;
+    match validation.status {
+        KeyStatus::Valid => Ok(None),
+        KeyStatus::NetworkError | KeyStatus::RateLimited => {
+            eprintln!("API 키 검증 건너뜀 ({}): {}", provider, validation.message);
+            Ok(Some(format!(
+                "API 키를 확인하지 못해 검증 없이 저장했습니다 ({})",
+                validation.message
+            )))
+        }
+        KeyStatus::InvalidKey => Err(format!("API 키가 유효하지 않습니다: {}", validation.message)),
+        KeyStatus::QuotaExhausted => Err(format!("API 사용 한도를 초과했습니다: {}", validation.message)),
+    }
+}
+
+/// 프로바이더 이름 → 환경 변수 키 (provider_for_env_key의 역방향)
+fn env_keys_for_provider(provider: &str) -> Vec<&'static str> {
+    [
+        "ANTHROPIC_API_KEY", "OPENAI_API_KEY", "GEMINI_API_KEY", "GOOGLE_API_KEY",
+        "OPENROUTER_API_KEY", "GROQ_API_KEY", "TOGETHER_API_KEY", "MINIMAX_API_KEY",
+        "MOONSHOT_API_KEY", "ZAI_API_KEY", "KIMI_API_KEY", "SYNTHETIC_API_KEY",
+        "VENICE_API_KEY", "XIAOMI_API_KEY", "VERCEL_GATEWAY_API_KEY", "OPENCODE_API_KEY",
+    ]
+    .into_iter()
+    .filter(|k| provider_for_env_key(k) == Some(provider))
+    .collect()
+}
+
+/// 설정된 API 키 조회 (models.providers.<p>.apiKey → env.vars → 프로세스 환경 변수)
+pub fn configured_api_key(provider: &str) -> Option<String> {
+    let config = read_existing_config();
+    if let Some(key) = config
+        .pointer(&format!("/models/providers/{}/apiKey", pointer_escape(provider)))
+        .and_then(|v| v.as_str())
+        .filter(|k| !k.is_empty())
+    {
+        return Some(key.to_string());
+    }
+    env_keys_for_provider(provider).into_iter().find_map(|env_key| {
+        config
+            .pointer(&format!("/env/vars/{}", env_key))
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .or_else(|| std::env::var(env_key).ok())
+            .filter(|k| !k.is_empty())
+    })
+}
+
+/// 프로바이더 응답에서 읽은 모델 정보 (캐시 저장 형식)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LiveModel {
+    id: String,
+    name: Option<String>,
+    context_window: Option<u64>,
+    max_tokens: Option<u64>,
+    /// USD / 1M 토큰 (OpenRouter처럼 가격을 알려주는 경우)
+    cost: Option<ModelCost>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ModelCache {
+    fetched_at_ms: i64,
+    /// 조회에 사용한 base_url 지정값 (없으면 기본 주소). 다른 주소의 캐시는 사용하지 않음
+    #[serde(default)]
+    base_url: Option<String>,
+    models: Vec<LiveModel>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListedModel {
+    #[serde(flatten)]
+    pub model: CatalogModel,
+    /// 로컬 카탈로그에 있는 모델
+    pub in_catalog: bool,
+    /// 프로바이더가 현재 제공하는 모델 (실시간 목록에 있음)
+    pub live: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelListing {
+    pub provider: String,
+    pub fetched_at_ms: Option<i64>,
+    pub from_cache: bool,
+    /// 실시간 조회 실패로 오래된 캐시나 카탈로그만 반환한 경우의 오류
+    pub error: Option<String>,
+    pub models: Vec<ListedModel>,
+}
+
+fn cache_path(provider: &str) -> PathBuf {
+    let safe: String = provider
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect();
+    get_moldclaw_state_dir().join("model_cache").join(format!("{}.json", safe))
+}
+
+fn load_cache(provider: &str) -> Option<ModelCache> {
+    std::fs::read_to_string(cache_path(provider))
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+}
+
+fn save_cache(provider: &str, cache: &ModelCache) -> Result<(), String> {
+    let path = cache_path(provider);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| format!("캐시 디렉토리 생성 실패: {}", e))?;
+    }
+    let json = serde_json::to_string(cache).map_err(|e| format!("캐시 직렬화 실패: {}", e))?;
+    std::fs::write(path, json).map_err(|e| format!("캐시 저장 실패: {}", e))
+}
+
+/// 가격 문자열(USD / 토큰)을 USD / 1M 토큰으로 변환
+fn per_million(value: Option<&serde_json::Value>) -> f64 {
+    value
+        .and_then(|v| v.as_str().and_then(|s| s.parse::<f64>().ok()).or_else(|| v.as_f64()))
+        .map(|per_token| per_token * 1_000_000.0)
+        .unwrap_or(0.0)
+}
+
+/// 모델 목록 응답 파싱 (OpenAI 호환 data[], Google models[])
+fn parse_live_models(style: ApiStyle, body: &serde_json::Value) -> Vec<LiveModel> {
+    let u64_field = |m: &serde_json::Value, keys: &[&str]| keys.iter().find_map(|k| m.get(*k).and_then(|v| v.as_u64()));
+
+    if style == ApiStyle::Google {
+        return body
+            .get("models")
+            .and_then(|m| m.as_array())
+            .map(|list| {
+                list.iter()
+                    .filter(|m| {
+                        m.get("supportedGenerationMethods")
+                            .and_then(|g| g.as_array())
+                            .is_some_and(|g| g.iter().any(|x| x.as_str() == Some("generateContent")))
+                    })
+                    .filter_map(|m| {
+                        let name = m.get("name")?.as_str()?;
+                        Some(LiveModel {
+                            id: name.trim_start_matches("models/").to_string(),
+                            name: m.get("displayName").and_then(|v| v.as_str()).map(|s| s.to_string()),
+                            context_window: u64_field(m, &["inputTokenLimit"]),
+                            max_tokens: u64_field(m, &["outputTokenLimit"]),
+                            cost: None,
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+    }
+
+    body.get("data")
+        .and_then(|d| d.as_array())
+        .map(|list| {
+            list.iter()
+                .filter_map(|m| {
+                    let id = m.get("id")?.as_str()?.to_string();
+                    let pricing = m.get("pricing");
+                    Some(LiveModel {
+                        name: m
+                            .get("display_name")
+                            .or_else(|| m.get("name"))
+                            .and_then(|v| v.as_str())
+                            .map(|s| s.to_string()),
+                        context_window: u64_field(m, &["context_window", "context_length"]),
+                        max_tokens: m
+                            .pointer("/top_provider/max_completion_tokens")
+                            .and_then(|v| v.as_u64())
+                            .or_else(|| u64_field(m, &["max_completion_tokens", "max_tokens"])),
+                        cost: pricing.map(|p| ModelCost {
+                            input: per_million(p.get("prompt")),
+                            output: per_million(p.get("completion")),
+                            cache_read: per_million(p.get("input_cache_read")),
+                            cache_write: per_million(p.get("input_cache_write")),
+                        }),
+                        id,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 프로바이더 모델 목록 실시간 조회
+async fn fetch_live_models(
+    provider: &str,
+    api_key: &str,
+    base_url: Option<&str>,
+) -> Result<Vec<LiveModel>, String> {
+    let endpoint = resolve_endpoint(provider, base_url)?;
+    let client = http_client()?;
+    let path = if endpoint.style == ApiStyle::Anthropic { "/models?limit=1000" } else { "/models" };
+    let response = authorized_get(&client, provider, &endpoint, api_key, path)
+        .send()
+        .await
+        .map_err(|e| format!("모델 목록 조회 실패: {}", e.without_url()))?;
+
+    let status = response.status().as_u16();
+    let body = response.text().await.unwrap_or_default();
+    if !(200..300).contains(&status) {
+        return Err(format!("모델 목록 조회 실패 (HTTP {}): {}", status, error_message(&body)));
+    }
+    let json: serde_json::Value =
+        serde_json::from_str(&body).map_err(|e| format!("모델 목록 파싱 실패: {}", e))?;
+    Ok(parse_live_models(endpoint.style, &json))
+}
+
+/// 실시간 목록과 카탈로그 병합 (실시간 모델 우선, 카탈로그에만 있는 모델은 live=false)
+fn merge_with_catalog(provider: &str, live: Option<&[LiveModel]>) -> Vec<ListedModel> {
+    let catalog = model_catalog::find_provider(provider).map(|p| p.models).unwrap_or_default();
+    merge_models(catalog, live)
+}
+
+fn merge_models(catalog: Vec<CatalogModel>, live: Option<&[LiveModel]>) -> Vec<ListedModel> {
+    let mut result: Vec<ListedModel> = Vec::new();
+
+    for lm in live.unwrap_or_default() {
+        let known = catalog.iter().find(|c| c.id == lm.id || c.aliases.contains(&lm.id));
+        let model = match known {
+            Some(c) => CatalogModel { id: lm.id.clone(), ..c.clone() },
+            None => CatalogModel {
+                id: lm.id.clone(),
+                name: lm.name.clone().unwrap_or_else(|| lm.id.clone()),
+                aliases: vec![],
+                reasoning: false,
+                input: vec!["text".to_string()],
+                cost: lm.cost.clone().unwrap_or_default(),
+                context_window: lm.context_window.unwrap_or(128000),
+                max_tokens: lm.max_tokens.unwrap_or(8192),
+            },
+        };
+        result.push(ListedModel { model, in_catalog: known.is_some(), live: true });
+    }
+
+    for c in catalog {
+        let listed = result
+            .iter()
+            .any(|r| r.model.id == c.id || c.aliases.contains(&r.model.id));
+        if !listed {
+            result.push(ListedModel { model: c, in_catalog: true, live: false });
+        }
+    }
+    result
+}
+
+fn now_ms() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+/// 모델 목록 (캐시 → 실시간 조회 → 카탈로그 순으로 사용)
+pub async fn list_models(provider: &str, refresh: bool, base_url: Option<&str>) -> ModelListing {
+    let base_url = base_url.map(|u| u.trim().trim_end_matches('/')).filter(|u| !u.is_empty());
+    let cache = load_cache(provider).filter(|c| c.base_url.as_deref() == base_url);
+    let fresh = cache
+        .as_ref()
+        .filter(|c| !refresh && now_ms() - c.fetched_at_ms < MODEL_CACHE_TTL_MS);
+
+    if let Some(cache) = fresh {
+        return ModelListing {
+            provider: provider.to_string(),
+            fetched_at_ms: Some(cache.fetched_at_ms),
+            from_cache: true,
+            error: None,
+            models: merge_with_catalog(provider, Some(&cache.models)),
+        };
+    }
+
+    let fetched = match configured_api_key(provider) {
+        Some(key) => fetch_live_models(provider, &key, base_url).await,
+        None => Err(format!("{} API 키가 설정되어 있지 않습니다", provider)),
+    };
+
+    match fetched {
+        Ok(models) => {
+            let new_cache = ModelCache {
+                fetched_at_ms: now_ms(),
+                base_url: base_url.map(String::from),
+                models,
+            };
+            if let Err(e) = save_cache(provider, &new_cache) {
+                eprintln!("모델 목록 캐시 저장 실패: {}", e);
+            }
+            ModelListing {
+                provider: provider.to_string(),
+                fetched_at_ms: Some(new_cache.fetched_at_ms),
+                from_cache: false,
+                error: None,
+                models: merge_with_catalog(provider, Some(&new_cache.models)),
+            }
+        }
+        // 조회 실패: 오래된 캐시라도 있으면 사용, 없으면 카탈로그만
+        Err(e) => ModelListing {
+            provider: provider.to_string(),
+            fetched_at_ms: cache.as_ref().map(|c| c.fetched_at_ms),
+            from_cache: cache.is_some(),
+            error: Some(e),
+            models: merge_with_catalog(provider, cache.as_ref().map(|c| c.models.as_slice())),
+        },
+    }
+}
+
+// ===== Tauri 커맨드 =====
+
+/// API 키 검증 (base_url 지정 시 해당 주소 사용)
+#[tauri::command]
+pub async fn validate_api_key(provider: String, api_key: String, base_url: Option<String>) -> KeyValidation {
+    validate_key(&provider, &api_key, base_url.as_deref()).await
+}
+
+/// 프로바이더 모델 목록 (카탈로그 병합, 캐시 사용. refresh=true면 강제 재조회)
+#[tauri::command]
+pub async fn list_provider_models(
+    provider: String,
+    refresh: Option<bool>,
+    base_url: Option<String>,
+) -> ModelListing {
+    list_models(&provider, refresh.unwrap_or(false), base_url.as_deref()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn endpoint(base_url: &str, style: ApiStyle) -> Endpoint {
+        Endpoint { base_url: base_url.to_string(), style }
+    }
+
+    #[test]
+    fn classify_maps_status_codes() {
+        assert_eq!(classify(200, "{}").0, KeyStatus::Valid);
+        assert_eq!(classify(401, r#"{"error":{"message":"bad key"}}"#).0, KeyStatus::InvalidKey);
+        assert_eq!(classify(400, r#"{"error":{"status":"API_KEY_INVALID"}}"#).0, KeyStatus::InvalidKey);
+        assert_eq!(classify(403, r#"{"error":{"code":"insufficient_quota"}}"#).0, KeyStatus::QuotaExhausted);
+        assert_eq!(classify(403, "forbidden").0, KeyStatus::InvalidKey);
+        assert_eq!(classify(429, r#"{"error":{"type":"insufficient_quota"}}"#).0, KeyStatus::QuotaExhausted);
+        assert_eq!(classify(429, "slow down").0, KeyStatus::RateLimited);
+        assert_eq!(classify(503, "unavailable").0, KeyStatus::NetworkError);
+    }
+
+    #[test]
+    fn configured_endpoint_wins_over_catalog() {
+        let catalog = model_catalog::CatalogProvider {
+            base_url: Some("https://api.openai.com/v1".to_string()),
+            ..Default::default()
+        };
+        let proxy = json!({ "baseUrl": "https://proxy.example.com/v1/", "api": "openai-completions" });
+        let resolved = endpoint_from("openai", None, Some(&proxy), Some(catalog.clone())).unwrap();
+        assert_eq!(resolved.base_url, "https://proxy.example.com/v1");
+        assert_eq!(resolved.style, ApiStyle::OpenAi);
+
+        let resolved = endpoint_from("openai", None, None, Some(catalog.clone())).unwrap();
+        assert_eq!(resolved.base_url, "https://api.openai.com/v1");
+
+        let resolved = endpoint_from("openai", Some("http://127.0.0.1:9"), Some(&proxy), Some(catalog)).unwrap();
+        assert_eq!(resolved.base_url, "http://127.0.0.1:9");
+
+        let anthropic = json!({ "baseUrl": "https://gw.example.com", "api": "anthropic-messages" });
+        assert_eq!(endpoint_from("gw", None, Some(&anthropic), None).unwrap().style, ApiStyle::Anthropic);
+        assert!(endpoint_from("unknown", None, None, None).is_err());
+    }
+
+    #[tokio::test]
+    async fn validate_key_against_mock_server() {
+        let mut server = mockito::Server::new_async().await;
+        let ok = server
+            .mock("GET", "/models")
+            .match_header("authorization", "Bearer sk-valid")
+            .with_status(200)
+            .with_body(r#"{"data":[]}"#)
+            .create_async()
+            .await;
+        let unauthorized = server
+            .mock("GET", "/models")
+            .match_header("authorization", "Bearer sk-invalid")
+            .with_status(401)
+            .with_body(r#"{"error":{"message":"Incorrect API key provided"}}"#)
+            .create_async()
+            .await;
+        let limited = server
+            .mock("GET", "/models")
+            .match_header("authorization", "Bearer sk-limited")
+            .with_status(429)
+            .with_body(r#"{"error":{"message":"Rate limit reached"}}"#)
+            .create_async()
+            .await;
+        let unavailable = server
+            .mock("GET", "/models")
+            .match_header("authorization", "Bearer sk-down")
+            .with_status(503)
+            .with_body("upstream unavailable")
+            .create_async()
+            .await;
+
+        let target = endpoint(&server.url(), ApiStyle::OpenAi);
+        let valid = validate_key_at("openai", "sk-valid", &target).await;
+        assert_eq!(valid.status, KeyStatus::Valid);
+        assert_eq!(valid.http_status, Some(200));
+
+        let invalid = validate_key_at("openai", "sk-invalid", &target).await;
+        assert_eq!(invalid.status, KeyStatus::InvalidKey);
+        assert_eq!(invalid.message, "Incorrect API key provided");
+
+        let rate_limited = validate_key_at("openai", "sk-limited", &target).await;
+        assert_eq!(rate_limited.status, KeyStatus::RateLimited);
+
+        let down = validate_key_at("openai", "sk-down", &target).await;
+        assert_eq!(down.status, KeyStatus::NetworkError);
+        assert_eq!(down.http_status, Some(503));
+
+        for mock in [ok, unauthorized, limited, unavailable] {
+            mock.assert_async().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn validate_key_uses_provider_auth() {
+        let mut server = mockito::Server::new_async().await;
+        let anthropic = server
+            .mock("GET", "/v1/models")
+            .match_header("x-api-key", "sk-ant-api03-test")
+            .match_header("anthropic-version", ANTHROPIC_VERSION)
+            .with_status(200)
+            .with_body(r#"{"data":[]}"#)
+            .create_async()
+            .await;
+        let google = server
+            .mock("GET", "/models")
+            .match_query(mockito::Matcher::UrlEncoded("key".into(), "AIza-test".into()))
+            .with_status(400)
+            .with_body(r#"{"error":{"message":"API key not valid. Please pass a valid API key."}}"#)
+            .create_async()
+            .await;
+        let openrouter = server
+            .mock("GET", "/key")
+            .with_status(200)
+            .with_body(r#"{"data":{"limit_remaining":0}}"#)
+            .create_async()
+            .await;
+
+        let result = validate_key_at("anthropic", "sk-ant-api03-test", &endpoint(&server.url(), ApiStyle::Anthropic)).await;
+        assert_eq!(result.status, KeyStatus::Valid);
+        let result = validate_key_at("google", "AIza-test", &endpoint(&server.url(), ApiStyle::Google)).await;
+        assert_eq!(result.status, KeyStatus::InvalidKey);
+        let result = validate_key_at("openrouter", "sk-or-test", &endpoint(&server.url(), ApiStyle::OpenAi)).await;
+        assert_eq!(result.status, KeyStatus::QuotaExhausted);
+
+        anthropic.assert_async().await;
+        google.assert_async().await;
+        openrouter.assert_async().await;
+    }
+
+    fn catalog_model(id: &str, aliases: &[&str]) -> CatalogModel {
+        CatalogModel {
+            id: id.to_string(),
+            name: id.to_uppercase(),
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+            reasoning: true,
+            input: vec!["text".to_string()],
+            cost: ModelCost::default(),
+            context_window: 200000,
+            max_tokens: 64000,
+        }
+    }
+
+    fn live(id: &str) -> LiveModel {
+        LiveModel { id: id.to_string(), name: None, context_window: None, max_tokens: None, cost: None }
+    }
+
+    #[test]
+    fn merge_models_marks_live_and_catalog_models() {
+        let catalog = vec![
+            catalog_model("claude-opus-4-5", &["claude-opus-4-5-20251101"]),
+            catalog_model("claude-sonnet-4-5", &[]),
+        ];
+        let merged = merge_models(catalog, Some(&[live("claude-opus-4-5-20251101"), live("new-model")]));
+        assert_eq!(merged.len(), 3);
+
+        // 별칭으로 찾은 모델은 카탈로그 정보를 쓰되 실시간 id 유지
+        let alias = &merged[0];
+        assert_eq!(alias.model.id, "claude-opus-4-5-20251101");
+        assert_eq!(alias.model.context_window, 200000);
+        assert!(alias.in_catalog && alias.live);
+
+        let unknown = &merged[1];
+        assert_eq!(unknown.model.name, "new-model");
+        assert_eq!(unknown.model.context_window, 128000);
+        assert!(!unknown.in_catalog && unknown.live);
+
+        // 별칭으로 이미 나온 카탈로그 모델은 다시 넣지 않음
+        assert!(!merged.iter().any(|m| m.model.id == "claude-opus-4-5"));
+        let sonnet = &merged[2];
+        assert_eq!(sonnet.model.id, "claude-sonnet-4-5");
+        assert!(sonnet.in_catalog && !sonnet.live);
+    }
+
+    #[test]
+    fn merge_models_without_live_list() {
+        let merged = merge_models(vec![catalog_model("gpt-5", &[]), catalog_model("gpt-5-mini", &[])], None);
+        assert_eq!(merged.len(), 2);
+        assert!(merged.iter().all(|m| m.in_catalog && !m.live));
+    }
+
+    #[tokio::test]
+    async fn connection_errors_do_not_leak_the_key() {
+        // 닫힌 포트: 연결 실패 메시지에 쿼리의 키가 들어가면 안 됨
+        let result = validate_key_at("google", "AIza-secret", &endpoint("http://127.0.0.1:9", ApiStyle::Google)).await;
+        assert_eq!(result.status, KeyStatus::NetworkError);
+        assert!(!result.message.contains("AIza-secret"), "{}", result.message);
+
+        let empty = validate_key_at("openai", "  ", &endpoint("http://127.0.0.1:9", ApiStyle::OpenAi)).await;
+        assert_eq!(empty.status, KeyStatus::InvalidKey);
+        assert_eq!(empty.http_status, None);
+    }
+}
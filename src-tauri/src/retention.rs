@@ -0,0 +1,508 @@
+// 데이터 보존 정책 및 개인정보 삭제
+// 대상: 세션 기록, logs/cache-trace.jsonl, credentials/whatsapp, workspace/memory
+// - 기간 기반 정리: 정책에 지정한 일수보다 오래된 데이터 삭제
+// - 상대방 기반 삭제: allowFrom에 쓰이는 ID로 해당 상대의 데이터 삭제
+// - dry-run: 실제 삭제 없이 삭제 대상 보고
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::openclaw::{get_gateway_port, get_moldclaw_state_dir, get_openclaw_dir, get_workspace_dir};
+use crate::sessions;
+
+/// 자동 정리 확인 주기
+const CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+/// 숫자 ID(전화번호, Telegram, Discord snowflake)의 최소 자릿수
+const MIN_NUMERIC_PEER_LEN: usize = 5;
+
+/// 보존 정책 (일 단위, None이면 무기한 보존)
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionPolicy {
+    pub sessions_days: Option<u32>,
+    pub logs_days: Option<u32>,
+    pub whatsapp_credentials_days: Option<u32>,
+    pub memory_days: Option<u32>,
+    /// 하루 한 번 자동 적용
+    #[serde(default)]
+    pub auto_apply: bool,
+    #[serde(default)]
+    pub last_run_ms: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionItem {
+    /// sessions | logs | whatsapp | memory
+    pub category: String,
+    pub path: String,
+    pub description: String,
+    pub bytes: u64,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionReport {
+    pub dry_run: bool,
+    pub items: Vec<RetentionItem>,
+    pub total_bytes: u64,
+    pub errors: Vec<String>,
+}
+
+impl RetentionReport {
+    fn push(&mut self, category: &str, path: &Path, description: String, bytes: u64) {
+        self.total_bytes += bytes;
+        self.items.push(RetentionItem {
+            category: category.to_string(),
+            path: path.to_string_lossy().to_string(),
+            description,
+            bytes,
+        });
+    }
+}
+
+/// 삭제 대상 선택 기준
+enum Target<'a> {
+    /// 이 시각(ms)보다 오래된 데이터
+    OlderThan(&'a RetentionPolicy, i64),
+    /// 특정 상대방 (정규화된 ID)
+    Peer(String),
+}
+
+fn policy_path() -> PathBuf {
+    get_moldclaw_state_dir().join("retention.json")
+}
+
+fn load_policy() -> RetentionPolicy {
+    std::fs::read_to_string(policy_path())
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save_policy(policy: &RetentionPolicy) -> Result<(), String> {
+    std::fs::create_dir_all(get_moldclaw_state_dir())
+        .map_err(|e| format!("상태 디렉토리 생성 실패: {}", e))?;
+    let json = serde_json::to_string_pretty(policy)
+        .map_err(|e| format!("정책 직렬화 실패: {}", e))?;
+    std::fs::write(policy_path(), json).map_err(|e| format!("정책 저장 실패: {}", e))
+}
+
+fn now_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
+
+fn cutoff_ms(days: Option<u32>, now: i64) -> Option<i64> {
+    days.map(|d| now - d as i64 * DAY_MS)
+}
+
+fn mtime_ms(path: &Path) -> Option<i64> {
+    std::fs::metadata(path)
+        .ok()
+        .and_then(|m| m.modified().ok())
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_millis() as i64)
+}
+
+fn file_size(path: &Path) -> u64 {
+    std::fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+}
+
+/// allowFrom 형식의 ID 정규화
+/// 예) "+82 10-1234-5678" → "821012345678", "user:1234" → "1234",
+///     "821012345678@s.whatsapp.net" → "821012345678", "tg:1234" → "1234"
+pub fn normalize_peer_id(id: &str) -> String {
+    let mut s = id.trim().to_lowercase();
+    for prefix in ["telegram:", "tg:", "whatsapp:", "discord:", "slack:", "user:", "<@", "@"] {
+        if let Some(rest) = s.strip_prefix(prefix) {
+            s = rest.to_string();
+        }
+    }
+    if let Some((local, _)) = s.split_once('@') {
+        s = local.to_string();
+    }
+    // 기기 번호 접미사 제거 (WhatsApp: 821012345678:12)
+    let s = s.trim_end_matches('>');
+    let s = s.split(':').next().unwrap_or(s);
+
+    // 전화번호 형식이면 숫자만 남김
+    if s.chars().all(|c| c.is_ascii_digit() || "+-() ".contains(c)) {
+        s.chars().filter(|c| c.is_ascii_digit()).collect()
+    } else {
+        s.to_string()
+    }
+}
+
+/// 정규화된 ID가 실제 플랫폼 ID 형식인지
+/// 숫자 ID(전화번호/WhatsApp JID/Telegram/Discord snowflake), Slack 사용자 ID(U…/W…), Signal UUID만 허용
+/// (짧거나 일반적인 단어를 받으면 무관한 파일과 메모리 줄까지 지워지므로 거부)
+fn is_platform_peer_id(id: &str) -> bool {
+    let numeric = id.len() >= MIN_NUMERIC_PEER_LEN && id.chars().all(|c| c.is_ascii_digit());
+    let slack = (id.starts_with('u') || id.starts_with('w'))
+        && (9..=12).contains(&id.len())
+        && id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        && id.chars().any(|c| c.is_ascii_digit());
+    let uuid = id.len() == 36
+        && id.char_indices().all(|(i, c)| {
+            if matches!(i, 8 | 13 | 18 | 23) {
+                c == '-'
+            } else {
+                c.is_ascii_hexdigit()
+            }
+        });
+    numeric || slack || uuid
+}
+
+/// WhatsApp Signal 키 파일명에서 상대방 자리의 ID
+/// session-<peer>.<기기>.json, sender-key-<group>--<peer>--<기기>.json
+/// (LID 주소의 _<도메인> 접미사는 제거)
+fn whatsapp_key_peer(name: &str) -> Option<&str> {
+    let peer = if let Some(rest) = name.strip_prefix("sender-key-") {
+        let mut parts = rest.strip_suffix(".json")?.rsplitn(3, "--");
+        let _device = parts.next()?;
+        let peer = parts.next()?;
+        parts.next()?;
+        peer
+    } else {
+        let rest = name.strip_prefix("session-")?.strip_suffix(".json")?;
+        rest.rsplit_once('.')?.0
+    };
+    let peer = peer.split('_').next().unwrap_or(peer);
+    (!peer.is_empty()).then_some(peer)
+}
+
+/// 메모리 한 줄에 상대방 ID가 그대로 언급되었는지 (ID 형식인 단어 전체를 정규화해 비교)
+fn mentions_peer(line: &str, peer: &str) -> bool {
+    line.split(|c: char| !(c.is_ascii_alphanumeric() || "+@.:_-".contains(c)))
+        .map(|word| word.trim_end_matches(|c: char| !c.is_ascii_alphanumeric()))
+        .filter(|word| !word.is_empty())
+        .any(|word| {
+            let id = normalize_peer_id(word);
+            is_platform_peer_id(&id) && id == peer
+        })
+}
+
+/// 게이트웨이가 실행 중인지 (로그 파일에 쓰는 중일 수 있음)
+fn gateway_running() -> bool {
+    let addr = std::net::SocketAddr::from(([127, 0, 0, 1], get_gateway_port()));
+    std::net::TcpStream::connect_timeout(&addr, Duration::from_secs(1)).is_ok()
+}
+
+// ===== 카테고리별 처리 =====
+
+fn purge_sessions(target: &Target, dry_run: bool, report: &mut RetentionReport) {
+    for entry in sessions::load_entries() {
+        let matched = match target {
+            Target::OlderThan(policy, now) => cutoff_ms(policy.sessions_days, *now)
+                .is_some_and(|cutoff| entry.updated_at().is_some_and(|t| t < cutoff)),
+            Target::Peer(peer) => entry.peer().is_some_and(|p| normalize_peer_id(&p) == *peer),
+        };
+        if !matched {
+            continue;
+        }
+
+        let Some(transcript) = entry.transcript_path() else {
+            report.errors.push(format!("세션 {}: 세션 디렉토리 밖의 transcript는 삭제하지 않습니다", entry.key));
+            continue;
+        };
+        report.push("sessions", &transcript, format!("세션 {}", entry.key), file_size(&transcript));
+        if !dry_run {
+            if let Err(e) = sessions::remove_entry(&entry) {
+                report.errors.push(e);
+            }
+        }
+    }
+}
+
+/// cache-trace.jsonl에서 대상 줄만 제거
+/// 게이트웨이가 실행 중이면 추가되는 줄을 잃지 않도록 보고만 하고 수정하지 않음
+fn purge_logs(target: &Target, dry_run: bool, report: &mut RetentionReport) {
+    let log_path = get_openclaw_dir().join("logs").join("cache-trace.jsonl");
+    let Ok(content) = std::fs::read_to_string(&log_path) else { return };
+
+    let cutoff = match target {
+        Target::OlderThan(policy, now) => match cutoff_ms(policy.logs_days, *now) {
+            Some(c) => Some(c),
+            None => return,
+        },
+        Target::Peer(_) => None,
+    };
+
+    let mut kept = String::new();
+    let mut removed_lines = 0usize;
+    let mut removed_bytes = 0u64;
+    for line in content.lines() {
+        let entry = serde_json::from_str::<serde_json::Value>(line).ok();
+        let remove = match (target, &entry) {
+            (Target::OlderThan(..), Some(e)) => e
+                .get("ts")
+                .and_then(|v| v.as_str())
+                .and_then(|ts| chrono::DateTime::parse_from_rfc3339(ts).ok())
+                .is_some_and(|t| Some(t.timestamp_millis()) < cutoff),
+            (Target::Peer(peer), Some(e)) => e
+                .get("sessionKey")
+                .and_then(|v| v.as_str())
+                .and_then(|key| sessions::parse_session_key(key).2)
+                .is_some_and(|p| normalize_peer_id(&p) == *peer),
+            _ => false,
+        };
+        if remove {
+            removed_lines += 1;
+            removed_bytes += line.len() as u64 + 1;
+        } else {
+            kept.push_str(line);
+            kept.push('\n');
+        }
+    }
+
+    if removed_lines == 0 {
+        return;
+    }
+    report.push("logs", &log_path, format!("로그 {}줄", removed_lines), removed_bytes);
+    if dry_run {
+        return;
+    }
+    if gateway_running() {
+        report
+            .errors
+            .push("게이트웨이 실행 중에는 로그를 정리하지 않습니다. 게이트웨이를 멈춘 뒤 다시 실행하세요".to_string());
+        return;
+    }
+    // 임시 파일에 쓴 뒤 교체 (중간에 실패해도 원본 유지)
+    let tmp_path = log_path.with_extension("jsonl.tmp");
+    let result = std::fs::write(&tmp_path, kept).and_then(|_| std::fs::rename(&tmp_path, &log_path));
+    if let Err(e) = result {
+        let _ = std::fs::remove_file(&tmp_path);
+        report.errors.push(format!("로그 정리 실패: {}", e));
+    }
+}
+
+/// WhatsApp 신호 세션 키 파일 정리 (creds.json은 절대 삭제하지 않음)
+fn purge_whatsapp(target: &Target, dry_run: bool, report: &mut RetentionReport) {
+    let cutoff = match target {
+        Target::OlderThan(policy, now) => match cutoff_ms(policy.whatsapp_credentials_days, *now) {
+            Some(c) => Some(c),
+            None => return,
+        },
+        Target::Peer(_) => None,
+    };
+
+    let base = get_openclaw_dir().join("credentials").join("whatsapp");
+    let Ok(accounts) = std::fs::read_dir(&base) else { return };
+    for account in accounts.flatten() {
+        let Ok(files) = std::fs::read_dir(account.path()) else { continue };
+        for file in files.flatten() {
+            let name = file.file_name().to_string_lossy().to_string();
+            if !(name.starts_with("session-") || name.starts_with("sender-key-")) {
+                continue;
+            }
+            let path = file.path();
+            let matched = match target {
+                Target::OlderThan(..) => mtime_ms(&path).is_some_and(|t| Some(t) < cutoff),
+                // 파일명의 상대방 자리만 비교 (그룹 ID, 기기 번호는 비교하지 않음)
+                Target::Peer(peer) => whatsapp_key_peer(&name).is_some_and(|p| normalize_peer_id(p) == *peer),
+            };
+            if !matched {
+                continue;
+            }
+            report.push("whatsapp", &path, format!("WhatsApp 세션 키 {}", name), file_size(&path));
+            if !dry_run {
+                if let Err(e) = std::fs::remove_file(&path) {
+                    report.errors.push(format!("{} 삭제 실패: {}", name, e));
+                }
+            }
+        }
+    }
+}
+
+/// 파일명 앞부분의 날짜(YYYY-MM-DD)를 ms로 변환
+fn date_from_file_name(name: &str) -> Option<i64> {
+    let date = chrono::NaiveDate::parse_from_str(name.get(..10)?, "%Y-%m-%d").ok()?;
+    Some(date.and_hms_opt(0, 0, 0)?.and_utc().timestamp_millis())
+}
+
+/// workspace/memory 정리
+/// - 기간 기반: 날짜별 메모리 파일 삭제 (파일명에 날짜가 없는 정리된 메모리 파일은 건드리지 않음)
+/// - 상대방 기반: 해당 ID가 그대로 언급된 줄만 제거
+fn purge_memory(target: &Target, dry_run: bool, report: &mut RetentionReport) {
+    let memory_dir = get_workspace_dir().join("memory");
+    let Ok(files) = std::fs::read_dir(&memory_dir) else { return };
+
+    for file in files.flatten() {
+        let path = file.path();
+        if !path.is_file() {
+            continue;
+        }
+        let name = file.file_name().to_string_lossy().to_string();
+
+        match target {
+            Target::OlderThan(policy, now) => {
+                let Some(cutoff) = cutoff_ms(policy.memory_days, *now) else { return };
+                let Some(date) = date_from_file_name(&name) else {
+                    continue;
+                };
+                if date >= cutoff {
+                    continue;
+                }
+                report.push("memory", &path, format!("메모리 파일 {}", name), file_size(&path));
+                if !dry_run {
+                    if let Err(e) = std::fs::remove_file(&path) {
+                        report.errors.push(format!("{} 삭제 실패: {}", name, e));
+                    }
+                }
+            }
+            Target::Peer(peer) => {
+                if !is_platform_peer_id(peer) {
+                    return;
+                }
+                let Ok(content) = std::fs::read_to_string(&path) else { continue };
+                let (removed, kept): (Vec<&str>, Vec<&str>) =
+                    content.lines().partition(|line| mentions_peer(line, peer));
+                if removed.is_empty() {
+                    continue;
+                }
+                let bytes = removed.iter().map(|l| l.len() as u64 + 1).sum();
+                report.push("memory", &path, format!("{} 중 {}줄", name, removed.len()), bytes);
+                if !dry_run {
+                    let mut new_content = kept.join("\n");
+                    new_content.push('\n');
+                    if let Err(e) = std::fs::write(&path, new_content) {
+                        report.errors.push(format!("{} 수정 실패: {}", name, e));
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn run(target: Target, dry_run: bool) -> RetentionReport {
+    let mut report = RetentionReport { dry_run, ..Default::default() };
+    purge_sessions(&target, dry_run, &mut report);
+    purge_logs(&target, dry_run, &mut report);
+    purge_whatsapp(&target, dry_run, &mut report);
+    purge_memory(&target, dry_run, &mut report);
+    report
+}
+
+fn run_policy(dry_run: bool) -> Result<RetentionReport, String> {
+    let mut policy = load_policy();
+    let now = now_ms();
+    let report = run(Target::OlderThan(&policy, now), dry_run);
+    if !dry_run {
+        policy.last_run_ms = Some(now);
+        save_policy(&policy)?;
+    }
+    Ok(report)
+}
+
+/// 백그라운드 자동 정리 루프 (하루 한 번, auto_apply 정책일 때만)
+pub fn start_retention_loop() {
+    std::thread::spawn(|| loop {
+        let policy = load_policy();
+        let due = policy.last_run_ms.is_none_or(|last| now_ms() - last >= DAY_MS);
+        if policy.auto_apply && due {
+            match run_policy(false) {
+                Ok(report) if !report.items.is_empty() => {
+                    eprintln!("보존 정책 적용: {}개 항목 정리", report.items.len());
+                }
+                Ok(_) => {}
+                Err(e) => eprintln!("보존 정책 적용 실패: {}", e),
+            }
+        }
+        std::thread::sleep(CHECK_INTERVAL);
+    });
+}
+
+// ===== Tauri 커맨드 =====
+
+/// 보존 정책 조회
+#[tauri::command]
+pub fn get_retention_policy() -> RetentionPolicy {
+    load_policy()
+}
+
+/// 보존 정책 저장
+#[tauri::command]
+pub fn set_retention_policy(policy: RetentionPolicy) -> Result<(), String> {
+    let last_run_ms = load_policy().last_run_ms;
+    save_policy(&RetentionPolicy { last_run_ms, ..policy })
+}
+
+/// 보존 정책에 따른 정리 (dry_run=true면 삭제 대상만 보고)
+#[tauri::command]
+pub fn apply_retention_policy(dry_run: Option<bool>) -> Result<RetentionReport, String> {
+    run_policy(dry_run.unwrap_or(true))
+}
+
+/// 특정 상대방 관련 데이터 전체 삭제 (dry_run=true면 삭제 대상만 보고)
+#[tauri::command]
+pub fn wipe_peer_data(peer: String, dry_run: Option<bool>) -> Result<RetentionReport, String> {
+    let normalized = normalize_peer_id(&peer);
+    if !is_platform_peer_id(&normalized) {
+        return Err(format!(
+            "잘못된 상대방 ID입니다: {} (전화번호, 숫자 ID, Slack 사용자 ID 또는 UUID)",
+            peer
+        ));
+    }
+    Ok(run(Target::Peer(normalized), dry_run.unwrap_or(true)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_peer_id_examples() {
+        assert_eq!(normalize_peer_id("+82 10-1234-5678"), "821012345678");
+        assert_eq!(normalize_peer_id("user:1234"), "1234");
+        assert_eq!(normalize_peer_id("821012345678@s.whatsapp.net"), "821012345678");
+        assert_eq!(normalize_peer_id("821012345678:12@s.whatsapp.net"), "821012345678");
+        assert_eq!(normalize_peer_id("tg:1234"), "1234");
+        assert_eq!(normalize_peer_id("<@U0ABC1234>"), "u0abc1234");
+    }
+
+    #[test]
+    fn only_platform_ids_are_wipe_targets() {
+        assert!(is_platform_peer_id("821012345678"));
+        assert!(is_platform_peer_id("123456789012345678"));
+        assert!(is_platform_peer_id("u0abc1234"));
+        assert!(is_platform_peer_id("5f3c9a2e-1b7d-4c8e-9a6f-2d4b8e1c7a90"));
+        for generic in ["", "0", "1234", "json", "session", "key", "main", "telegram", "user", "*", "uabcdefgh"] {
+            assert!(!is_platform_peer_id(generic), "{}", generic);
+        }
+        assert!(wipe_peer_data("json".to_string(), Some(true)).is_err());
+        assert!(wipe_peer_data("0".to_string(), Some(true)).is_err());
+    }
+
+    #[test]
+    fn whatsapp_key_peer_reads_only_the_peer_slot() {
+        assert_eq!(whatsapp_key_peer("session-821012345678.0.json"), Some("821012345678"));
+        assert_eq!(whatsapp_key_peer("session-123456789012345_1.3.json"), Some("123456789012345"));
+        assert_eq!(
+            whatsapp_key_peer("sender-key-120363025246125888@g.us--821012345678--0.json"),
+            Some("821012345678")
+        );
+        assert_eq!(whatsapp_key_peer("sender-key-memory-120363025246125888@g.us.json"), None);
+        assert_eq!(whatsapp_key_peer("pre-key-12.json"), None);
+        assert_eq!(whatsapp_key_peer("creds.json"), None);
+        // 기기 번호나 그룹 자리는 상대방으로 보지 않음
+        assert_ne!(whatsapp_key_peer("session-821012345678.0.json"), Some("0"));
+    }
+
+    #[test]
+    fn mentions_peer_matches_whole_ids() {
+        let peer = "821012345678";
+        assert!(mentions_peer("- +82 10 연락처: +82-10-1234-5678.", peer));
+        assert!(mentions_peer("whatsapp:+821012345678 님이 요청", peer));
+        assert!(mentions_peer("jid 821012345678@s.whatsapp.net", peer));
+        assert!(!mentions_peer("번호 8210123456789 는 다른 사람", peer));
+        assert!(!mentions_peer("번호 821012345678", "21012345678"));
+        assert!(mentions_peer("이전 번호는 21012345678", "21012345678"));
+        assert!(!mentions_peer("session json key 0", "0"));
+    }
+}
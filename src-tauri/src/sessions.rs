@@ -0,0 +1,531 @@
+// 대화 기록(세션) 조회
+// Gateway가 디스크에 저장하는 세션 파일을 직접 읽으므로 Gateway가 꺼져 있어도 동작합니다.
+//   ~/.openclaw/agents/<agentId>/sessions/sessions.json   (sessionKey → 메타데이터)
+//   ~/.openclaw/agents/<agentId>/sessions/<sessionId>.jsonl (대화 transcript)
+
+use serde::Serialize;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use crate::openclaw::get_openclaw_dir;
+
+/// 검색 결과 최대 개수
+const MAX_SEARCH_RESULTS: usize = 200;
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionSummary {
+    pub key: String,
+    pub session_id: String,
+    pub agent_id: String,
+    /// telegram, discord, whatsapp ... (메인 세션은 "main")
+    pub channel: String,
+    /// dm, group, channel ...
+    pub chat_type: Option<String>,
+    /// 상대방 ID (allowFrom에 쓰이는 것과 같은 형식)
+    pub peer: Option<String>,
+    pub display_name: Option<String>,
+    pub updated_at: Option<i64>,
+    pub message_count: usize,
+    pub preview: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionMessage {
+    pub index: usize,
+    pub role: String,
+    pub text: String,
+    pub timestamp: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionMessagePage {
+    pub key: String,
+    pub total: usize,
+    pub offset: usize,
+    pub messages: Vec<SessionMessage>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionSearchHit {
+    pub key: String,
+    pub channel: String,
+    pub peer: Option<String>,
+    pub index: usize,
+    pub role: String,
+    pub snippet: String,
+    pub timestamp: Option<String>,
+}
+
+/// 세션 저장소 항목 (sessions.json 한 줄 + 파일 위치)
+pub(crate) struct SessionEntry {
+    pub key: String,
+    agent_id: String,
+    session_id: String,
+    meta: serde_json::Value,
+    sessions_dir: PathBuf,
+}
+
+impl SessionEntry {
+    /// 마지막 갱신 시각 (ms)
+    pub fn updated_at(&self) -> Option<i64> {
+        self.meta.get("updatedAt").and_then(|v| v.as_i64())
+    }
+
+    /// 채널 이름 (세션 키에서 추출, 채널 없는 DM 세션은 lastChannel 사용)
+    pub fn channel(&self) -> String {
+        let (channel, _, peer) = parse_session_key(&self.key);
+        if channel == "main" && peer.is_some() {
+            if let Some(last) = self.meta.get("lastChannel").and_then(|v| v.as_str()) {
+                return last.to_string();
+            }
+        }
+        channel
+    }
+
+    /// 상대방 ID (세션 키에서 추출)
+    pub fn peer(&self) -> Option<String> {
+        parse_session_key(&self.key).2
+    }
+
+    /// transcript 파일 경로
+    /// sessionFile이 세션 디렉토리 밖을 가리키면 무시하고 <sessionId>.jsonl 사용
+    /// 그 경로도 세션 디렉토리 밖이면 (sessionId에 ../ 등) None
+    pub fn transcript_path(&self) -> Option<PathBuf> {
+        self.meta
+            .get("sessionFile")
+            .and_then(|v| v.as_str())
+            .map(|f| self.sessions_dir.join(f))
+            .filter(|p| is_inside_dir(&self.sessions_dir, p))
+            .or_else(|| {
+                Some(self.sessions_dir.join(format!("{}.jsonl", self.session_id)))
+                    .filter(|p| is_inside_dir(&self.sessions_dir, p))
+            })
+    }
+
+    /// transcript의 user/assistant 메시지 (경로가 잘못되었으면 빈 목록)
+    fn messages(&self) -> Vec<SessionMessage> {
+        self.transcript_path().map(|p| read_messages(&p)).unwrap_or_default()
+    }
+}
+
+/// path가 dir 안에 있는지 (심볼릭 링크, .. 해석 후 비교. 아직 없는 파일은 부모 디렉토리 기준)
+fn is_inside_dir(dir: &Path, path: &Path) -> bool {
+    let Ok(dir) = dir.canonicalize() else {
+        return false;
+    };
+    let resolved = match path.canonicalize() {
+        Ok(p) => p,
+        Err(_) => match (path.parent().and_then(|p| p.canonicalize().ok()), path.file_name()) {
+            (Some(parent), Some(name)) => parent.join(name),
+            _ => return false,
+        },
+    };
+    resolved != dir && resolved.starts_with(&dir)
+}
+
+/// 세션 키 파싱: agent:<agentId>:<channel>:<chatType>:<peer...>
+/// 예) agent:main:telegram:dm:123456, agent:main:main, agent:main:dm:+821012345678
+pub(crate) fn parse_session_key(key: &str) -> (String, Option<String>, Option<String>) {
+    let parts: Vec<&str> = key.split(':').collect();
+    let rest: &[&str] = if parts.len() >= 2 && parts[0] == "agent" { &parts[2..] } else { &parts[..] };
+
+    match rest {
+        [] => ("main".to_string(), None, None),
+        [single] => (single.to_string(), None, None),
+        // dmScope=per-peer: 채널 없이 agent:<id>:dm:<peer>
+        ["dm", peer @ ..] => ("main".to_string(), Some("dm".to_string()), Some(peer.join(":"))),
+        [channel, chat_type] => (channel.to_string(), Some(chat_type.to_string()), None),
+        [channel, chat_type, peer @ ..] => {
+            // per-account-channel-peer: <channel>:<accountId>:dm:<peer>
+            if peer.len() >= 2 && matches!(peer[0], "dm" | "group" | "channel") {
+                (channel.to_string(), Some(peer[0].to_string()), Some(peer[1..].join(":")))
+            } else {
+                (channel.to_string(), Some(chat_type.to_string()), Some(peer.join(":")))
+            }
+        }
+    }
+}
+
+/// 모든 에이전트의 세션 저장소 로드
+pub(crate) fn load_entries() -> Vec<SessionEntry> {
+    let mut entries = Vec::new();
+    let Ok(agents) = std::fs::read_dir(get_openclaw_dir().join("agents")) else {
+        return entries;
+    };
+
+    for agent in agents.flatten() {
+        let agent_id = agent.file_name().to_string_lossy().to_string();
+        let sessions_dir = agent.path().join("sessions");
+        let Some(store) = std::fs::read_to_string(sessions_dir.join("sessions.json"))
+            .ok()
+            .and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok())
+        else {
+            continue;
+        };
+        let Some(map) = store.as_object() else { continue };
+
+        for (key, meta) in map {
+            let Some(session_id) = meta.get("sessionId").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            entries.push(SessionEntry {
+                key: key.clone(),
+                agent_id: agent_id.clone(),
+                session_id: session_id.to_string(),
+                meta: meta.clone(),
+                sessions_dir: sessions_dir.clone(),
+            });
+        }
+    }
+    entries
+}
+
+fn find_entry(key: &str) -> Result<SessionEntry, String> {
+    load_entries()
+        .into_iter()
+        .find(|e| e.key == key)
+        .ok_or_else(|| format!("세션을 찾을 수 없습니다: {}", key))
+}
+
+/// content 필드에서 텍스트 추출 (문자열 또는 [{type:"text", text}] 배열)
+fn content_text(content: &serde_json::Value) -> String {
+    match content {
+        serde_json::Value::String(s) => s.clone(),
+        serde_json::Value::Array(parts) => parts
+            .iter()
+            .filter(|p| p.get("type").and_then(|t| t.as_str()) == Some("text"))
+            .filter_map(|p| p.get("text").and_then(|t| t.as_str()))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => String::new(),
+    }
+}
+
+/// transcript(jsonl)에서 user/assistant 메시지를 한 줄씩 읽어 f에 전달 (파일 전체를 메모리에 올리지 않음)
+fn for_each_message(path: &Path, mut f: impl FnMut(SessionMessage)) {
+    let Ok(file) = std::fs::File::open(path) else {
+        return;
+    };
+
+    let mut index = 0;
+    for line in BufReader::new(file).lines() {
+        let Ok(line) = line else { break };
+        let Ok(entry) = serde_json::from_str::<serde_json::Value>(&line) else { continue };
+        if entry.get("type").and_then(|v| v.as_str()) != Some("message") {
+            continue;
+        }
+        let Some(msg) = entry.get("message") else { continue };
+        let role = msg.get("role").and_then(|v| v.as_str()).unwrap_or("");
+        if role != "user" && role != "assistant" {
+            continue;
+        }
+        let text = msg.get("content").map(content_text).unwrap_or_default();
+        if text.trim().is_empty() {
+            continue;
+        }
+        f(SessionMessage {
+            index,
+            role: role.to_string(),
+            text,
+            timestamp: entry.get("timestamp").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        });
+        index += 1;
+    }
+}
+
+/// transcript(jsonl)에서 user/assistant 메시지만 읽기
+fn read_messages(path: &Path) -> Vec<SessionMessage> {
+    let mut messages = Vec::new();
+    for_each_message(path, |m| messages.push(m));
+    messages
+}
+
+fn to_summary(entry: &SessionEntry) -> SessionSummary {
+    let (_, chat_type, peer) = parse_session_key(&entry.key);
+    let mut message_count = 0;
+    let mut last_text = None;
+    if let Some(path) = entry.transcript_path() {
+        for_each_message(&path, |m| {
+            message_count += 1;
+            last_text = Some(m.text);
+        });
+    }
+    let str_field = |name: &str| entry.meta.get(name).and_then(|v| v.as_str()).map(|s| s.to_string());
+
+    SessionSummary {
+        key: entry.key.clone(),
+        session_id: entry.session_id.clone(),
+        agent_id: entry.agent_id.clone(),
+        channel: entry.channel(),
+        chat_type,
+        peer,
+        display_name: str_field("displayName").or_else(|| str_field("label")),
+        updated_at: entry.updated_at(),
+        message_count,
+        preview: last_text.map(|t| crate::truncate_message(&t, 80)),
+    }
+}
+
+// ===== Tauri 커맨드 =====
+
+/// 세션 목록 (channel/peer 필터, 최근 순)
+#[tauri::command]
+pub fn list_sessions(channel: Option<String>, peer: Option<String>) -> Result<Vec<SessionSummary>, String> {
+    let mut sessions: Vec<SessionSummary> = load_entries()
+        .iter()
+        .map(to_summary)
+        .filter(|s| channel.as_deref().is_none_or(|c| s.channel == c))
+        .filter(|s| peer.as_deref().is_none_or(|p| s.peer.as_deref() == Some(p)))
+        .collect();
+    sessions.sort_by_key(|s| std::cmp::Reverse(s.updated_at));
+    Ok(sessions)
+}
+
+/// 세션 메시지 페이지 조회
+#[tauri::command]
+pub fn get_session_messages(
+    key: String,
+    offset: Option<usize>,
+    limit: Option<usize>,
+) -> Result<SessionMessagePage, String> {
+    let entry = find_entry(&key)?;
+    let messages = entry.messages();
+    let offset = offset.unwrap_or(0);
+    let limit = limit.unwrap_or(50);
+
+    Ok(SessionMessagePage {
+        key,
+        total: messages.len(),
+        offset,
+        messages: messages.into_iter().skip(offset).take(limit).collect(),
+    })
+}
+
+/// 전체 세션 본문 검색 (대소문자 무시)
+#[tauri::command]
+pub fn search_sessions(query: String) -> Result<Vec<SessionSearchHit>, String> {
+    let needle = query.trim().to_lowercase();
+    if needle.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut hits = Vec::new();
+    for entry in load_entries() {
+        let channel = entry.channel();
+        let peer = entry.peer();
+        for msg in entry.messages() {
+            let lower = msg.text.to_lowercase();
+            let Some(pos) = lower.find(&needle) else { continue };
+
+            // 일치 위치 앞쪽 40자부터 스니펫 생성
+            let char_pos = lower[..pos].chars().count();
+            let start = char_pos.saturating_sub(40);
+            let snippet: String = msg.text.chars().skip(start).collect();
+            hits.push(SessionSearchHit {
+                key: entry.key.clone(),
+                channel: channel.clone(),
+                peer: peer.clone(),
+                index: msg.index,
+                role: msg.role,
+                snippet: crate::truncate_message(&snippet, 160),
+                timestamp: msg.timestamp,
+            });
+            if hits.len() >= MAX_SEARCH_RESULTS {
+                return Ok(hits);
+            }
+        }
+    }
+    Ok(hits)
+}
+
+/// 세션 내보내기 (format: "md" | "json")
+/// output_path가 있으면 파일로 저장 후 경로 반환, 없으면 내용 반환
+#[tauri::command]
+pub fn export_session(key: String, format: String, output_path: Option<String>) -> Result<String, String> {
+    let entry = find_entry(&key)?;
+    let summary = to_summary(&entry);
+    let messages = entry.messages();
+
+    let content = match format.as_str() {
+        "json" => serde_json::to_string_pretty(&serde_json::json!({
+            "session": summary,
+            "messages": messages,
+        }))
+        .map_err(|e| format!("JSON 생성 실패: {}", e))?,
+        "md" | "markdown" => {
+            let mut md = format!("# {}\n\n", summary.display_name.as_deref().unwrap_or(&key));
+            md.push_str(&format!("- 채널: {}\n", summary.channel));
+            if let Some(ref peer) = summary.peer {
+                md.push_str(&format!("- 상대: {}\n", peer));
+            }
+            md.push_str(&format!("- 세션 키: `{}`\n\n---\n\n", key));
+            for msg in &messages {
+                let who = if msg.role == "user" { "사용자" } else { "에이전트" };
+                let when = msg.timestamp.as_deref().map(crate::format_timestamp).unwrap_or_default();
+                md.push_str(&format!("### {} {}\n\n{}\n\n", who, when, msg.text));
+            }
+            md
+        }
+        _ => return Err(format!("지원하지 않는 형식입니다: {}", format)),
+    };
+
+    match output_path {
+        Some(path) => {
+            std::fs::write(&path, content).map_err(|e| format!("파일 저장 실패: {}", e))?;
+            Ok(path)
+        }
+        None => Ok(content),
+    }
+}
+
+/// 세션 저장소 항목과 transcript 파일 삭제
+pub(crate) fn remove_entry(entry: &SessionEntry) -> Result<(), String> {
+    // 저장소를 고치기 전에 transcript 경로부터 확인 (실패해도 저장소는 그대로)
+    let transcript = entry.transcript_path().ok_or_else(|| {
+        format!("세션 디렉토리 밖의 파일은 삭제하지 않습니다: {}", entry.session_id)
+    })?;
+    let store_path = entry.sessions_dir.join("sessions.json");
+
+    let mut store: serde_json::Value = std::fs::read_to_string(&store_path)
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .ok_or("세션 저장소를 읽을 수 없습니다")?;
+    if let Some(map) = store.as_object_mut() {
+        map.remove(&entry.key);
+    }
+    let json = serde_json::to_string_pretty(&store).map_err(|e| format!("JSON 생성 실패: {}", e))?;
+    std::fs::write(&store_path, json).map_err(|e| format!("세션 저장소 저장 실패: {}", e))?;
+
+    if transcript.exists() {
+        std::fs::remove_file(&transcript).map_err(|e| format!("대화 기록 삭제 실패: {}", e))?;
+    }
+    Ok(())
+}
+
+/// 세션 삭제 (저장소 항목 + transcript 파일)
+#[tauri::command]
+pub fn delete_session(key: String) -> Result<(), String> {
+    remove_entry(&find_entry(&key)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn entry(sessions_dir: &Path, key: &str, session_id: &str, meta: serde_json::Value) -> SessionEntry {
+        SessionEntry {
+            key: key.to_string(),
+            agent_id: "main".to_string(),
+            session_id: session_id.to_string(),
+            meta,
+            sessions_dir: sessions_dir.to_path_buf(),
+        }
+    }
+
+    #[test]
+    fn parse_session_key_formats() {
+        let some = |s: &str| Some(s.to_string());
+        assert_eq!(parse_session_key("agent:main:main"), ("main".to_string(), None, None));
+        assert_eq!(parse_session_key("agent:main:telegram:dm:123456"), ("telegram".to_string(), some("dm"), some("123456")));
+        assert_eq!(parse_session_key("agent:main:dm:+821012345678"), ("main".to_string(), some("dm"), some("+821012345678")));
+        assert_eq!(
+            parse_session_key("agent:main:discord:work:channel:1234567890"),
+            ("discord".to_string(), some("channel"), some("1234567890"))
+        );
+        assert_eq!(
+            parse_session_key("agent:main:whatsapp:group:120363@g.us:extra"),
+            ("whatsapp".to_string(), some("group"), some("120363@g.us:extra"))
+        );
+        assert_eq!(parse_session_key("agent:main:slack:channel"), ("slack".to_string(), some("channel"), None));
+    }
+
+    #[test]
+    fn is_inside_dir_resolves_dot_dot() {
+        let dir = tempfile::tempdir().unwrap();
+        let sessions = dir.path().join("sessions");
+        std::fs::create_dir(&sessions).unwrap();
+        assert!(is_inside_dir(&sessions, &sessions.join("a.jsonl")));
+        assert!(!is_inside_dir(&sessions, &sessions.join("../a.jsonl")));
+        assert!(!is_inside_dir(&sessions, &sessions));
+        assert!(!is_inside_dir(&dir.path().join("missing"), &dir.path().join("missing/a.jsonl")));
+    }
+
+    #[test]
+    fn transcript_path_stays_in_sessions_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        let sessions = dir.path().join("sessions");
+        std::fs::create_dir(&sessions).unwrap();
+
+        let plain = entry(&sessions, "agent:main:main", "abc", json!({}));
+        assert_eq!(plain.transcript_path(), Some(sessions.join("abc.jsonl")));
+
+        let custom = entry(&sessions, "agent:main:main", "abc", json!({ "sessionFile": "custom.jsonl" }));
+        assert_eq!(custom.transcript_path(), Some(sessions.join("custom.jsonl")));
+
+        let escaped_file = entry(&sessions, "agent:main:main", "abc", json!({ "sessionFile": "../x.jsonl" }));
+        assert_eq!(escaped_file.transcript_path(), Some(sessions.join("abc.jsonl")));
+
+        let escaped_id = entry(&sessions, "agent:main:main", "../x", json!({}));
+        assert_eq!(escaped_id.transcript_path(), None);
+        assert!(escaped_id.messages().is_empty());
+    }
+
+    #[test]
+    fn remove_entry_keeps_store_when_transcript_is_outside() {
+        let dir = tempfile::tempdir().unwrap();
+        let sessions = dir.path().join("sessions");
+        std::fs::create_dir(&sessions).unwrap();
+        let store = json!({
+            "agent:main:telegram:dm:1": { "sessionId": "../outside" },
+            "agent:main:telegram:dm:2": { "sessionId": "ok" },
+        });
+        std::fs::write(sessions.join("sessions.json"), store.to_string()).unwrap();
+        std::fs::write(dir.path().join("outside.jsonl"), "{}").unwrap();
+        std::fs::write(sessions.join("ok.jsonl"), "{}").unwrap();
+
+        let bad = entry(&sessions, "agent:main:telegram:dm:1", "../outside", json!({}));
+        assert!(remove_entry(&bad).is_err());
+        let saved: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(sessions.join("sessions.json")).unwrap()).unwrap();
+        assert_eq!(saved, store);
+        assert!(dir.path().join("outside.jsonl").exists());
+
+        let good = entry(&sessions, "agent:main:telegram:dm:2", "ok", json!({}));
+        remove_entry(&good).unwrap();
+        let saved: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(sessions.join("sessions.json")).unwrap()).unwrap();
+        assert!(saved.get("agent:main:telegram:dm:2").is_none());
+        assert!(saved.get("agent:main:telegram:dm:1").is_some());
+        assert!(!sessions.join("ok.jsonl").exists());
+    }
+
+    #[test]
+    fn messages_skip_tool_and_empty_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let lines = [
+            json!({ "type": "session", "id": "abc" }),
+            json!({ "type": "message", "timestamp": "2026-10-18T09:00:00Z", "message": { "role": "user", "content": "안녕" } }),
+            json!({ "type": "message", "message": { "role": "toolResult", "content": "ignored" } }),
+            json!({ "type": "message", "message": { "role": "assistant", "content": [
+                { "type": "thinking", "thinking": "..." },
+                { "type": "text", "text": "반가워요" }
+            ] } }),
+            json!({ "type": "message", "message": { "role": "assistant", "content": [] } }),
+        ];
+        let body: Vec<String> = lines.iter().map(|l| l.to_string()).chain(["not json".to_string()]).collect();
+        let path = dir.path().join("abc.jsonl");
+        std::fs::write(&path, body.join("\n")).unwrap();
+
+        let messages = read_messages(&path);
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].role, "user");
+        assert_eq!(messages[0].timestamp.as_deref(), Some("2026-10-18T09:00:00Z"));
+        assert_eq!(messages[1].text, "반가워요");
+        assert_eq!(messages[1].index, 1);
+    }
+}
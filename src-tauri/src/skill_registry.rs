@@ -0,0 +1,524 @@
+// 스킬 레지스트리
+// 번들 레지스트리(resources/skills.json)를 기본으로 하고 아래 순서로 덮어씁니다:
+// 1. 원격 레지스트리: 설정한 URL에서 받은 서명된 파일 (~/.openclaw/moldclaw/skills-remote.json + .sig)
+//    Ed25519 서명이 빌드에 고정된 게시자 공개키로 확인되고 번들보다 새 버전(updatedAt)일 때만 적용
+//    (이미 받은 원격 레지스트리보다 오래된 파일은 받지 않음: 재전송 방지)
+// 2. 사용자 레지스트리: ~/.openclaw/moldclaw/skills.json (서명 불필요)
+// 병합 규칙: 같은 id는 정의 전체를 교체, 새 id는 추가, remove에 있는 id는 제외
+// 레지스트리가 형식 검증에 실패하면 해당 파일 전체를 무시합니다.
+// 스킬 목록은 앱 시작 시 한 번 읽으므로 변경 사항은 재시작 후 적용됩니다.
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use crate::openclaw::get_moldclaw_state_dir;
+use crate::provider_api::http_client;
+use crate::skill_definitions::SKILL_DEFINITIONS;
+use crate::skills::{InstallMethod, SetupRequirement, SkillDefinition};
+
+/// 앱에 포함된 기본 레지스트리
+const BUNDLED_REGISTRY: &str = include_str!("../resources/skills.json");
+/// 지원하는 레지스트리 형식 버전
+const REGISTRY_VERSION: u32 = 1;
+/// 원격 레지스트리 게시자 Ed25519 공개키 (base64, 빌드 시 MOLDCLAW_SKILL_REGISTRY_PUBLIC_KEY로 고정)
+/// 웹뷰에서 바꿀 수 없도록 설정 파일이나 커맨드 인자로는 받지 않음
+const REGISTRY_PUBLIC_KEY: Option<&str> = option_env!("MOLDCLAW_SKILL_REGISTRY_PUBLIC_KEY");
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SkillRegistry {
+    pub version: u32,
+    #[serde(default)]
+    pub updated_at: String,
+    #[serde(default)]
+    pub skills: Vec<SkillDefinition>,
+    /// 덮어쓰기 레지스트리 전용: 제외할 스킬 id
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remove: Vec<String>,
+}
+
+/// 원격 레지스트리 설정 (공개키는 빌드에 고정되어 있어 URL만 저장)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteRegistrySettings {
+    pub url: Option<String>,
+}
+
+/// 레지스트리 출처별 적용 결과
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegistrySource {
+    /// "bundled" | "remote" | "user"
+    pub source: &'static str,
+    pub path: Option<String>,
+    pub updated_at: Option<String>,
+    pub skills: usize,
+    pub applied: bool,
+    pub errors: Vec<String>,
+}
+
+pub struct LoadedRegistry {
+    pub skills: Vec<SkillDefinition>,
+    pub sources: Vec<RegistrySource>,
+}
+
+// ===== 파일 경로 =====
+
+fn user_registry_path() -> PathBuf {
+    get_moldclaw_state_dir().join("skills.json")
+}
+
+fn remote_cache_path() -> PathBuf {
+    get_moldclaw_state_dir().join("skills-remote.json")
+}
+
+fn remote_signature_path() -> PathBuf {
+    get_moldclaw_state_dir().join("skills-remote.json.sig")
+}
+
+fn settings_path() -> PathBuf {
+    get_moldclaw_state_dir().join("skill_registry.json")
+}
+
+fn load_settings() -> RemoteRegistrySettings {
+    std::fs::read_to_string(settings_path())
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save_settings(settings: &RemoteRegistrySettings) -> Result<(), String> {
+    let path = settings_path();
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| format!("디렉토리 생성 실패: {}", e))?;
+    }
+    let content = serde_json::to_string_pretty(settings).map_err(|e| format!("JSON 직렬화 실패: {}", e))?;
+    std::fs::write(&path, content).map_err(|e| format!("{} 저장 실패: {}", path.display(), e))
+}
+
+// ===== 검증 =====
+
+fn is_skill_id(id: &str) -> bool {
+    id.chars().next().is_some_and(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        && id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+fn is_env_var(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| c.is_ascii_uppercase() || c == '_')
+        && name.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+fn validate_skill(skill: &SkillDefinition) -> Vec<String> {
+    let mut errors = Vec::new();
+    let id = &skill.id;
+    if !is_skill_id(id) {
+        errors.push(format!("{}: id는 소문자, 숫자, '-'만 사용할 수 있습니다", id));
+    }
+    if skill.name.trim().is_empty() {
+        errors.push(format!("{}: name이 비어 있습니다", id));
+    }
+    let needs_command = !matches!(skill.install_method, InstallMethod::Builtin | InstallMethod::Manual);
+    if needs_command && skill.install_command.as_deref().is_none_or(|c| c.trim().is_empty()) {
+        errors.push(format!("{}: {:?} 설치에는 install_command가 필요합니다", id, skill.install_method));
+    }
+    if skill.windows_install_method.is_some() != skill.windows_install_command.is_some() {
+        errors.push(format!("{}: windows_install_method와 windows_install_command는 함께 지정해야 합니다", id));
+    }
+    if !(skill.platform.windows || skill.platform.macos || skill.platform.linux) {
+        errors.push(format!("{}: 지원 플랫폼이 하나도 없습니다", id));
+    }
+    match &skill.setup {
+        SetupRequirement::ApiKey { vars } if vars.is_empty() => {
+            errors.push(format!("{}: api_key 설정에는 환경변수가 하나 이상 필요합니다", id));
+        }
+        SetupRequirement::ApiKey { vars } => {
+            if let Some(bad) = vars.iter().find(|v| !is_env_var(v)) {
+                errors.push(format!("{}: 환경변수 이름이 올바르지 않습니다: {}", id, bad));
+            }
+        }
+        SetupRequirement::Login { command } if command.trim().is_empty() => {
+            errors.push(format!("{}: login 명령이 비어 있습니다", id));
+        }
+        SetupRequirement::Config { path } if path.trim().is_empty() => {
+            errors.push(format!("{}: config 경로가 비어 있습니다", id));
+        }
+        _ => {}
+    }
+    if let Some(bad) = skill.disconnect.env_vars.iter().find(|v| !is_env_var(v)) {
+        errors.push(format!("{}: disconnect 환경변수 이름이 올바르지 않습니다: {}", id, bad));
+    }
+    errors
+}
+
+/// updatedAt 파싱 ("2026-10-18" 또는 RFC3339)
+fn parse_updated_at(value: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    let value = value.trim();
+    if let Ok(at) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Some(at.with_timezone(&chrono::Utc));
+    }
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|t| t.and_utc())
+}
+
+/// 레지스트리 형식 검증 (오류 목록, 비어 있으면 통과)
+pub fn validate_registry(registry: &SkillRegistry) -> Vec<String> {
+    let mut errors = Vec::new();
+    if registry.version != REGISTRY_VERSION {
+        errors.push(format!(
+            "지원하지 않는 레지스트리 버전입니다: {} (지원: {})",
+            registry.version, REGISTRY_VERSION
+        ));
+    }
+    if !registry.updated_at.is_empty() && parse_updated_at(&registry.updated_at).is_none() {
+        errors.push(format!(
+            "updatedAt 형식이 올바르지 않습니다: {} (YYYY-MM-DD 또는 RFC3339)",
+            registry.updated_at
+        ));
+    }
+    let mut seen = HashSet::new();
+    for skill in &registry.skills {
+        if !seen.insert(skill.id.as_str()) {
+            errors.push(format!("{}: 중복된 스킬 id입니다", skill.id));
+        }
+        errors.extend(validate_skill(skill));
+    }
+    errors
+}
+
+/// JSON 파싱 + 검증
+fn parse_registry(content: &str) -> Result<SkillRegistry, Vec<String>> {
+    let registry: SkillRegistry =
+        serde_json::from_str(content).map_err(|e| vec![format!("레지스트리 형식 오류: {}", e)])?;
+    let errors = validate_registry(&registry);
+    if errors.is_empty() {
+        Ok(registry)
+    } else {
+        Err(errors)
+    }
+}
+
+fn verifying_key(public_key: &str) -> Result<VerifyingKey, String> {
+    let bytes = STANDARD
+        .decode(public_key.trim())
+        .map_err(|e| format!("공개키가 base64 형식이 아닙니다: {}", e))?;
+    let bytes: [u8; 32] = bytes
+        .try_into()
+        .map_err(|_| "공개키는 32바이트 Ed25519 키여야 합니다".to_string())?;
+    VerifyingKey::from_bytes(&bytes).map_err(|e| format!("공개키 오류: {}", e))
+}
+
+fn pinned_public_key() -> Result<&'static str, String> {
+    REGISTRY_PUBLIC_KEY
+        .filter(|k| !k.trim().is_empty())
+        .ok_or_else(|| "이 빌드에는 원격 레지스트리 게시자 공개키가 포함되어 있지 않습니다".to_string())
+}
+
+/// 원격 레지스트리 서명 확인 (signature: base64 Ed25519 서명, 빌드에 고정된 공개키 사용)
+fn verify_signature(content: &[u8], signature: &str) -> Result<(), String> {
+    let key = verifying_key(pinned_public_key()?)?;
+    let sig = STANDARD
+        .decode(signature.trim())
+        .map_err(|e| format!("서명이 base64 형식이 아닙니다: {}", e))?;
+    let sig = Signature::from_slice(&sig).map_err(|e| format!("서명 형식 오류: {}", e))?;
+    key.verify(content, &sig).map_err(|_| "서명이 일치하지 않습니다".to_string())
+}
+
+// ===== 로드 / 병합 =====
+
+/// 번들 레지스트리 (형식 오류 시 빈 레지스트리 + 오류, 앱은 계속 실행)
+fn bundled_registry() -> (SkillRegistry, Vec<String>) {
+    match serde_json::from_str::<SkillRegistry>(BUNDLED_REGISTRY) {
+        Ok(registry) => {
+            let errors = validate_registry(&registry);
+            (registry, errors)
+        }
+        Err(e) => {
+            let empty = SkillRegistry {
+                version: REGISTRY_VERSION,
+                updated_at: String::new(),
+                skills: Vec::new(),
+                remove: Vec::new(),
+            };
+            (empty, vec![format!("번들 스킬 레지스트리 형식 오류: {}", e)])
+        }
+    }
+}
+
+/// 비교 대상보다 오래된 레지스트리인지 (날짜를 알 수 없는 쪽은 비교하지 않음)
+fn is_older(updated_at: &str, than: &str) -> bool {
+    match (parse_updated_at(updated_at), parse_updated_at(than)) {
+        (Some(a), Some(b)) => a < b,
+        _ => false,
+    }
+}
+
+/// 서명 확인 + 형식 검증 + updatedAt 필수 (원격 레지스트리 공통)
+fn check_remote(content: &[u8], signature: &str) -> Result<SkillRegistry, Vec<String>> {
+    verify_signature(content, signature).map_err(|e| vec![e])?;
+    let registry = parse_registry(&String::from_utf8_lossy(content))?;
+    if parse_updated_at(&registry.updated_at).is_none() {
+        return Err(vec!["원격 레지스트리에는 updatedAt이 필요합니다".to_string()]);
+    }
+    Ok(registry)
+}
+
+/// 캐시된 원격 레지스트리 (서명이 맞지 않으면 None)
+fn cached_remote() -> Option<SkillRegistry> {
+    let content = std::fs::read(remote_cache_path()).ok()?;
+    let signature = std::fs::read_to_string(remote_signature_path()).ok()?;
+    check_remote(&content, &signature).ok()
+}
+
+fn merge(skills: &mut Vec<SkillDefinition>, overlay: SkillRegistry) {
+    skills.retain(|s| !overlay.remove.contains(&s.id));
+    for skill in overlay.skills {
+        match skills.iter_mut().find(|s| s.id == skill.id) {
+            Some(existing) => *existing = skill,
+            None => skills.push(skill),
+        }
+    }
+}
+
+/// 캐시된 원격 레지스트리 (서명 확인 포함)
+fn remote_registry(settings: &RemoteRegistrySettings, bundled_updated_at: &str) -> Option<(Option<SkillRegistry>, RegistrySource)> {
+    let content = std::fs::read(remote_cache_path()).ok()?;
+    let mut source = RegistrySource {
+        source: "remote",
+        path: settings.url.clone(),
+        updated_at: None,
+        skills: 0,
+        applied: false,
+        errors: Vec::new(),
+    };
+    let signature = std::fs::read_to_string(remote_signature_path()).unwrap_or_default();
+    match check_remote(&content, &signature) {
+        Ok(registry) => {
+            source.updated_at = Some(registry.updated_at.clone());
+            source.skills = registry.skills.len();
+            if is_older(&registry.updated_at, bundled_updated_at) {
+                source.errors.push("번들 레지스트리보다 오래된 버전이라 적용하지 않습니다".to_string());
+            } else {
+                source.applied = true;
+            }
+            Some((source.applied.then_some(registry), source))
+        }
+        Err(errors) => {
+            source.errors = errors;
+            Some((None, source))
+        }
+    }
+}
+
+/// 번들 + 원격 + 사용자 레지스트리 병합
+pub fn load_registry() -> LoadedRegistry {
+    let (bundled, bundled_errors) = bundled_registry();
+    for e in &bundled_errors {
+        eprintln!("번들 스킬 레지스트리 검증 오류: {}", e);
+    }
+    let mut sources = vec![RegistrySource {
+        source: "bundled",
+        path: None,
+        updated_at: Some(bundled.updated_at.clone()),
+        skills: bundled.skills.len(),
+        applied: true,
+        errors: bundled_errors,
+    }];
+    let bundled_updated_at = bundled.updated_at.clone();
+    let mut skills = bundled.skills;
+
+    if let Some((registry, source)) = remote_registry(&load_settings(), &bundled_updated_at) {
+        if let Some(registry) = registry {
+            merge(&mut skills, registry);
+        }
+        sources.push(source);
+    }
+
+    let user_path = user_registry_path();
+    if let Ok(content) = std::fs::read_to_string(&user_path) {
+        let mut source = RegistrySource {
+            source: "user",
+            path: Some(user_path.to_string_lossy().to_string()),
+            updated_at: None,
+            skills: 0,
+            applied: false,
+            errors: Vec::new(),
+        };
+        match parse_registry(&content) {
+            Ok(registry) => {
+                source.updated_at = Some(registry.updated_at.clone());
+                source.skills = registry.skills.len();
+                source.applied = true;
+                merge(&mut skills, registry);
+            }
+            Err(errors) => {
+                for e in &errors {
+                    eprintln!("사용자 스킬 레지스트리 무시: {}", e);
+                }
+                source.errors = errors;
+            }
+        }
+        sources.push(source);
+    }
+
+    LoadedRegistry { skills, sources }
+}
+
+// ===== 커맨드 =====
+
+/// 레지스트리 상태: 출처별 적용 결과, 현재 스킬 수, 재시작 필요 여부
+#[tauri::command]
+pub fn get_skill_registry_status() -> Value {
+    let loaded = load_registry();
+    let restart_required = serde_json::to_value(&loaded.skills).ok() != serde_json::to_value(&*SKILL_DEFINITIONS).ok();
+    json!({
+        "sources": loaded.sources,
+        "activeSkills": SKILL_DEFINITIONS.len(),
+        "loadedSkills": loaded.skills.len(),
+        "restartRequired": restart_required,
+        "userRegistryPath": user_registry_path().to_string_lossy(),
+        "remote": load_settings(),
+        "publicKeyPinned": pinned_public_key().is_ok(),
+    })
+}
+
+/// 레지스트리 파일 검증 (사용자 레지스트리 작성용)
+#[tauri::command]
+pub fn validate_skill_registry(file_path: String) -> Result<Value, String> {
+    let path = shellexpand::tilde(file_path.trim()).to_string();
+    let content = std::fs::read_to_string(&path).map_err(|e| format!("파일을 읽을 수 없습니다 ({}): {}", path, e))?;
+    Ok(match parse_registry(&content) {
+        Ok(registry) => json!({ "ok": true, "skills": registry.skills.len(), "errors": [] }),
+        Err(errors) => json!({ "ok": false, "skills": 0, "errors": errors }),
+    })
+}
+
+/// 원격 레지스트리 설정 (url을 비우면 원격 레지스트리 사용 안 함 + 캐시 삭제)
+/// 서명 확인 공개키는 빌드에 고정되어 있어 여기서 바꿀 수 없음
+#[tauri::command]
+pub fn set_skill_registry_remote(url: Option<String>) -> Result<(), String> {
+    let url = url.map(|u| u.trim().to_string()).filter(|u| !u.is_empty());
+    if let Some(u) = &url {
+        verifying_key(pinned_public_key()?)?;
+        let parsed = reqwest::Url::parse(u).map_err(|e| format!("올바른 URL이 아닙니다: {}", e))?;
+        let local = matches!(parsed.host_str(), Some("127.0.0.1") | Some("localhost"));
+        if parsed.scheme() != "https" && !local {
+            return Err("원격 레지스트리는 https 주소만 사용할 수 있습니다".to_string());
+        }
+    } else {
+        let _ = std::fs::remove_file(remote_cache_path());
+        let _ = std::fs::remove_file(remote_signature_path());
+    }
+    save_settings(&RemoteRegistrySettings { url })
+}
+
+/// 원격 레지스트리 다운로드 (<url>과 <url>.sig). 서명·형식 확인 후 캐시에 저장
+/// 새 스킬 목록은 재시작 후 적용
+#[tauri::command]
+pub async fn refresh_skill_registry() -> Result<Value, String> {
+    let settings = load_settings();
+    let url = settings.url.clone().ok_or("원격 레지스트리 URL이 설정되지 않았습니다")?;
+    pinned_public_key()?;
+
+    let client = http_client()?;
+    let fetch = |u: String| {
+        let client = client.clone();
+        async move {
+            let response = client.get(&u).send().await.map_err(|e| format!("다운로드 실패 ({}): {}", u, e))?;
+            if !response.status().is_success() {
+                return Err(format!("다운로드 실패 ({}): HTTP {}", u, response.status().as_u16()));
+            }
+            response.bytes().await.map_err(|e| format!("다운로드 실패 ({}): {}", u, e))
+        }
+    };
+    let content = fetch(url.clone()).await?;
+    let signature = fetch(format!("{}.sig", url)).await?;
+    let signature = String::from_utf8_lossy(&signature).to_string();
+
+    let registry = check_remote(&content, &signature).map_err(|errors| errors.join("\n"))?;
+    let (bundled, _) = bundled_registry();
+    if is_older(&registry.updated_at, &bundled.updated_at) {
+        return Err(format!(
+            "원격 레지스트리({})가 번들 레지스트리({})보다 오래되었습니다",
+            registry.updated_at, bundled.updated_at
+        ));
+    }
+    // 이전에 받은 서명된 파일을 다시 보내 되돌리는 것 방지
+    if let Some(cached) = cached_remote().filter(|c| is_older(&registry.updated_at, &c.updated_at)) {
+        return Err(format!(
+            "원격 레지스트리({})가 이미 받은 버전({})보다 오래되었습니다",
+            registry.updated_at, cached.updated_at
+        ));
+    }
+
+    let cache = remote_cache_path();
+    if let Some(dir) = cache.parent() {
+        std::fs::create_dir_all(dir).map_err(|e| format!("디렉토리 생성 실패: {}", e))?;
+    }
+    std::fs::write(&cache, &content).map_err(|e| format!("{} 저장 실패: {}", cache.display(), e))?;
+    std::fs::write(remote_signature_path(), signature.trim())
+        .map_err(|e| format!("서명 저장 실패: {}", e))?;
+
+    Ok(get_skill_registry_status())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bundled() -> SkillRegistry {
+        serde_json::from_str(BUNDLED_REGISTRY).expect("번들 레지스트리 파싱")
+    }
+
+    #[test]
+    fn bundled_registry_is_valid() {
+        let registry = bundled();
+        assert_eq!(validate_registry(&registry), Vec::<String>::new());
+        assert!(!registry.skills.is_empty());
+        assert!(parse_updated_at(&registry.updated_at).is_some());
+    }
+
+    #[test]
+    fn validate_registry_reports_errors() {
+        let mut registry = bundled();
+        registry.version = REGISTRY_VERSION + 1;
+        registry.updated_at = "18/10/2026".to_string();
+        let duplicate = registry.skills[0].clone();
+        registry.skills.push(duplicate);
+        registry.skills[1].id = "Bad_Id".to_string();
+
+        let errors = validate_registry(&registry);
+        assert_eq!(errors.len(), 4, "{:?}", errors);
+        assert!(errors[0].contains("레지스트리 버전"));
+        assert!(errors[1].contains("updatedAt"));
+        assert!(errors.iter().any(|e| e.starts_with("Bad_Id: id는")));
+        assert!(errors.iter().any(|e| e.contains("중복된 스킬 id")));
+    }
+
+    #[test]
+    fn validate_skill_checks_env_vars() {
+        let mut registry = bundled();
+        let skill = registry.skills.iter_mut().find(|s| matches!(s.setup, SetupRequirement::ApiKey { .. })).unwrap();
+        skill.setup = SetupRequirement::ApiKey { vars: vec!["lower_case".to_string()] };
+        let errors = validate_registry(&registry);
+        assert_eq!(errors.len(), 1, "{:?}", errors);
+        assert!(errors[0].contains("lower_case"));
+    }
+
+    #[test]
+    fn updated_at_formats_and_order() {
+        assert!(parse_updated_at("2026-10-18").is_some());
+        assert!(parse_updated_at("2026-10-18T09:00:00+09:00").is_some());
+        assert!(parse_updated_at("2026/10/18").is_none());
+        assert!(is_older("2026-10-17", "2026-10-18"));
+        assert!(is_older("2026-10-18T08:59:59+09:00", "2026-10-18"));
+        assert!(!is_older("2026-10-18", "2026-10-18"));
+        // 형식을 알 수 없으면 비교하지 않음
+        assert!(!is_older("unknown", "2026-10-18"));
+    }
+}
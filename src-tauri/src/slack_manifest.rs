@@ -0,0 +1,266 @@
+// Slack 앱 매니페스트 (Socket Mode)
+// - generate_slack_manifest: OpenClaw에 필요한 scope / 이벤트 / Socket Mode 설정이 들어간 매니페스트 (YAML 또는 JSON)
+// - validate_slack_tokens: Bot 토큰(xoxb-)과 App 토큰(xapp-) 확인 + 실제 부여된 scope 비교
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::channel_api::{self, TokenStatus, TokenVerification};
+use crate::openclaw::{normalize_account_id, MASKED_SECRET};
+use crate::provider_api::http_client;
+
+const SLACK_NEW_APP_URL: &str = "https://api.slack.com/apps";
+
+/// 봇 동작에 반드시 필요한 scope (메시지 수신/전송, 멘션, 사용자 이름)
+const REQUIRED_BOT_SCOPES: &[&str] = &[
+    "app_mentions:read",
+    "chat:write",
+    "channels:history",
+    "channels:read",
+    "groups:history",
+    "groups:read",
+    "im:history",
+    "im:read",
+    "im:write",
+    "mpim:history",
+    "mpim:read",
+    "users:read",
+];
+
+/// 있으면 좋은 scope (리액션, 파일, 고정 메시지, 슬래시 명령)
+const OPTIONAL_BOT_SCOPES: &[&str] = &[
+    "reactions:read",
+    "reactions:write",
+    "files:read",
+    "files:write",
+    "pins:read",
+    "pins:write",
+    "emoji:read",
+    "commands",
+];
+
+/// 구독할 봇 이벤트
+const BOT_EVENTS: &[&str] = &[
+    "app_mention",
+    "message.channels",
+    "message.groups",
+    "message.im",
+    "message.mpim",
+    "reaction_added",
+    "reaction_removed",
+    "member_joined_channel",
+    "member_left_channel",
+    "channel_rename",
+    "pin_added",
+    "pin_removed",
+];
+
+/// App-Level 토큰에 필요한 scope (Socket Mode 연결)
+const APP_TOKEN_SCOPE: &str = "connections:write";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackManifest {
+    /// "yaml" | "json"
+    pub format: String,
+    /// 그대로 붙여넣을 매니페스트 본문
+    pub manifest: String,
+    /// 매니페스트가 미리 채워진 Slack 앱 생성 페이지
+    pub create_url: String,
+    pub bot_scopes: Vec<&'static str>,
+    pub bot_events: Vec<&'static str>,
+    pub app_token_scope: &'static str,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackTokenCheck {
+    pub bot: TokenVerification,
+    pub app: TokenVerification,
+    /// Bot 토큰에 실제로 부여된 scope (확인 불가 시 빈 목록)
+    pub granted_scopes: Vec<String>,
+    /// 빠진 필수 scope → 매니페스트 갱신 후 앱 재설치 필요
+    pub missing_scopes: Vec<&'static str>,
+    pub missing_optional_scopes: Vec<&'static str>,
+    /// 부여된 scope를 확인하지 못함 (이 경우 ready는 false)
+    pub scopes_unverified: bool,
+    /// 두 토큰 모두 유효하고 필수 scope가 모두 있는 것을 확인했는지
+    pub ready: bool,
+}
+
+/// 매니페스트에 넣을 Bot scope (슬래시 명령을 쓰지 않으면 commands 제외)
+fn bot_scopes(slash_command: bool) -> Vec<&'static str> {
+    REQUIRED_BOT_SCOPES
+        .iter()
+        .chain(OPTIONAL_BOT_SCOPES.iter().filter(|s| slash_command || **s != "commands"))
+        .copied()
+        .collect()
+}
+
+fn manifest_value(app_name: &str, slash_command: bool) -> Value {
+    let mut features = json!({
+        "app_home": {
+            "home_tab_enabled": false,
+            "messages_tab_enabled": true,
+            "messages_tab_read_only_enabled": false,
+        },
+        "bot_user": {
+            "display_name": app_name,
+            "always_online": true,
+        },
+    });
+    if slash_command {
+        features["slash_commands"] = json!([{
+            "command": "/openclaw",
+            "description": "OpenClaw에게 명령 보내기",
+            "should_escape": false,
+        }]);
+    }
+
+    json!({
+        "display_information": {
+            "name": app_name,
+            "description": "OpenClaw AI 어시스턴트",
+        },
+        "features": features,
+        "oauth_config": {
+            "scopes": { "bot": bot_scopes(slash_command) },
+        },
+        "settings": {
+            "event_subscriptions": { "bot_events": BOT_EVENTS },
+            "interactivity": { "is_enabled": true },
+            "org_deploy_enabled": false,
+            "socket_mode_enabled": true,
+            "token_rotation_enabled": false,
+        },
+    })
+}
+
+/// auth.test 응답 헤더(x-oauth-scopes)로 Bot 토큰에 부여된 scope 확인
+async fn granted_scopes(token: &str, base_url: Option<&str>) -> Result<Vec<String>, String> {
+    let client = http_client()?;
+    let url = format!("{}/api/auth.test", channel_api::api_base(base_url, channel_api::SLACK_API_BASE));
+    let response = client
+        .post(url)
+        .bearer_auth(token)
+        .send()
+        .await
+        .map_err(|e| format!("Slack 연결 실패: {}", e))?;
+    let scopes = response
+        .headers()
+        .get("x-oauth-scopes")
+        .and_then(|h| h.to_str().ok())
+        .unwrap_or_default();
+    Ok(scopes
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect())
+}
+
+// ===== 커맨드 =====
+
+/// Slack 앱 매니페스트 생성
+/// format: "yaml"(기본) | "json", slash_command=true면 /openclaw 명령과 commands scope 포함
+#[tauri::command]
+pub fn generate_slack_manifest(
+    app_name: Option<String>,
+    format: Option<String>,
+    slash_command: Option<bool>,
+) -> Result<SlackManifest, String> {
+    let app_name = app_name
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty())
+        .unwrap_or_else(|| "OpenClaw".to_string());
+    // Slack 제한: 앱 이름 35자
+    if app_name.chars().count() > 35 {
+        return Err("Slack 앱 이름은 35자 이하여야 합니다".to_string());
+    }
+    let slash_command = slash_command.unwrap_or(false);
+    let value = manifest_value(&app_name, slash_command);
+
+    let format = format.unwrap_or_else(|| "yaml".to_string()).to_lowercase();
+    let (manifest, param) = match format.as_str() {
+        "yaml" | "yml" => (
+            serde_yaml::to_string(&value).map_err(|e| format!("YAML 변환 실패: {}", e))?,
+            "manifest_yaml",
+        ),
+        "json" => (
+            serde_json::to_string_pretty(&value).map_err(|e| format!("JSON 변환 실패: {}", e))?,
+            "manifest_json",
+        ),
+        other => return Err(format!("지원하지 않는 형식입니다: {} (yaml | json)", other)),
+    };
+    let create_url = reqwest::Url::parse_with_params(
+        SLACK_NEW_APP_URL,
+        &[("new_app", "1"), (param, manifest.as_str())],
+    )
+    .map_err(|e| e.to_string())?
+    .to_string();
+
+    Ok(SlackManifest {
+        format: if param == "manifest_json" { "json" } else { "yaml" }.to_string(),
+        manifest,
+        create_url,
+        bot_scopes: bot_scopes(slash_command),
+        bot_events: BOT_EVENTS.to_vec(),
+        app_token_scope: APP_TOKEN_SCOPE,
+    })
+}
+
+/// 붙여넣은 Bot/App 토큰 확인 (비우거나 마스킹 값이면 저장된 토큰 사용)
+/// base_url을 지정하면 해당 주소로 요청 (테스트용)
+#[tauri::command]
+pub async fn validate_slack_tokens(
+    bot_token: Option<String>,
+    app_token: Option<String>,
+    account_id: Option<String>,
+    base_url: Option<String>,
+) -> Result<SlackTokenCheck, String> {
+    let account_id = normalize_account_id(account_id.as_deref())?;
+    let account = account_id.as_deref();
+    let base_url = base_url.as_deref();
+
+    let bot = channel_api::verify_token("slack", bot_token.as_deref(), Some("bot"), account, base_url).await;
+    let mut app = channel_api::verify_token("slack", app_token.as_deref(), Some("app"), account, base_url).await;
+    if app.status == TokenStatus::InvalidToken && app.http_status.is_some() {
+        // apps.connections.open 실패는 대부분 connections:write 누락
+        app.message.push_str(&format!(" - App-Level 토큰에 {} scope가 있는지 확인하세요", APP_TOKEN_SCOPE));
+    }
+
+    let mut granted = Vec::new();
+    if bot.status == TokenStatus::Valid {
+        let token = bot_token
+            .as_deref()
+            .map(str::trim)
+            .filter(|t| !t.is_empty() && *t != MASKED_SECRET)
+            .map(String::from)
+            .or_else(|| channel_api::stored_token("slack", Some("bot"), account));
+        if let Some(token) = token {
+            granted = granted_scopes(&token, base_url).await.unwrap_or_default();
+        }
+    }
+    let missing = |scopes: &[&'static str]| -> Vec<&'static str> {
+        if granted.is_empty() {
+            // scope를 확인하지 못한 경우 누락으로 보고하지 않음
+            return Vec::new();
+        }
+        scopes.iter().filter(|s| !granted.iter().any(|g| g == *s)).copied().collect()
+    };
+    let missing_scopes = missing(REQUIRED_BOT_SCOPES);
+    let missing_optional_scopes = missing(OPTIONAL_BOT_SCOPES);
+    let scopes_unverified = granted.is_empty();
+
+    Ok(SlackTokenCheck {
+        ready: bot.status == TokenStatus::Valid
+            && app.status == TokenStatus::Valid
+            && !scopes_unverified
+            && missing_scopes.is_empty(),
+        scopes_unverified,
+        bot,
+        app,
+        granted_scopes: granted,
+        missing_scopes,
+        missing_optional_scopes,
+    })
+}
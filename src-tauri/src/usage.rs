@@ -0,0 +1,178 @@
+// 사용량 집계
+// 세션 transcript의 assistant 메시지 usage 필드(토큰/비용)를 모아 통계를 만듭니다.
+// transcript에 비용이 기록되지 않은 경우 모델 카탈로그 가격으로 추정합니다.
+
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+use crate::model_catalog;
+use crate::sessions;
+
+/// assistant 응답 1건의 사용량
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageRecord {
+    pub timestamp_ms: i64,
+    pub session_key: String,
+    pub channel: String,
+    pub provider: String,
+    pub model: String,
+    pub input: u64,
+    pub output: u64,
+    pub cache_read: u64,
+    pub cache_write: u64,
+    pub cost_usd: f64,
+    /// 비용이 카탈로그 가격 기반 추정치인지 여부
+    pub estimated: bool,
+}
+
+impl UsageRecord {
+    pub fn total_tokens(&self) -> u64 {
+        self.input + self.output + self.cache_read + self.cache_write
+    }
+}
+
+#[derive(Debug, Default, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageTotals {
+    pub messages: u64,
+    pub input: u64,
+    pub output: u64,
+    pub cache_read: u64,
+    pub cache_write: u64,
+    pub total_tokens: u64,
+    pub cost_usd: f64,
+}
+
+impl UsageTotals {
+    fn add(&mut self, r: &UsageRecord) {
+        self.messages += 1;
+        self.input += r.input;
+        self.output += r.output;
+        self.cache_read += r.cache_read;
+        self.cache_write += r.cache_write;
+        self.total_tokens += r.total_tokens();
+        self.cost_usd += r.cost_usd;
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageReport {
+    pub since_ms: Option<i64>,
+    pub totals: UsageTotals,
+    /// "provider/model" 별
+    pub by_model: BTreeMap<String, UsageTotals>,
+    pub by_channel: BTreeMap<String, UsageTotals>,
+    /// 로컬 날짜(YYYY-MM-DD) 별
+    pub by_day: BTreeMap<String, UsageTotals>,
+    pub last_active_ms: Option<i64>,
+}
+
+fn parse_timestamp_ms(value: Option<&serde_json::Value>) -> Option<i64> {
+    match value? {
+        serde_json::Value::Number(n) => n.as_i64(),
+        serde_json::Value::String(s) => chrono::DateTime::parse_from_rfc3339(s)
+            .ok()
+            .map(|t| t.timestamp_millis()),
+        _ => None,
+    }
+}
+
+/// 모든 세션의 사용량 레코드 수집 (since_ms 이후만)
+pub fn collect_usage(since_ms: Option<i64>) -> Vec<UsageRecord> {
+    let mut records = Vec::new();
+
+    for entry in sessions::load_entries() {
+        // 오래된 세션은 transcript를 읽지 않음
+        if let (Some(since), Some(updated)) = (since_ms, entry.updated_at()) {
+            if updated < since {
+                continue;
+            }
+        }
+        let Some(Ok(content)) = entry.transcript_path().map(std::fs::read_to_string) else {
+            continue;
+        };
+        let channel = entry.channel();
+
+        for line in content.lines() {
+            let Ok(item) = serde_json::from_str::<serde_json::Value>(line) else { continue };
+            let Some(msg) = item.get("message") else { continue };
+            if msg.get("role").and_then(|v| v.as_str()) != Some("assistant") {
+                continue;
+            }
+            let Some(usage) = msg.get("usage") else { continue };
+
+            let timestamp_ms = parse_timestamp_ms(item.get("timestamp"))
+                .or_else(|| parse_timestamp_ms(msg.get("timestamp")))
+                .unwrap_or(0);
+            if since_ms.is_some_and(|since| timestamp_ms < since) {
+                continue;
+            }
+
+            let tokens = |key: &str| usage.get(key).and_then(|v| v.as_u64()).unwrap_or(0);
+            let provider = msg.get("provider").and_then(|v| v.as_str()).unwrap_or("unknown").to_string();
+            let model = msg.get("model").and_then(|v| v.as_str()).unwrap_or("unknown").to_string();
+            let (input, output, cache_read, cache_write) =
+                (tokens("input"), tokens("output"), tokens("cacheRead"), tokens("cacheWrite"));
+
+            let recorded = usage
+                .pointer("/cost/total")
+                .and_then(|v| v.as_f64())
+                .filter(|c| *c > 0.0);
+            let (cost_usd, estimated) = match recorded {
+                Some(cost) => (cost, false),
+                None => (
+                    model_catalog::estimate_cost(&provider, &model, input, output, cache_read, cache_write)
+                        .unwrap_or(0.0),
+                    true,
+                ),
+            };
+
+            records.push(UsageRecord {
+                timestamp_ms,
+                session_key: entry.key.clone(),
+                channel: channel.clone(),
+                provider,
+                model,
+                input,
+                output,
+                cache_read,
+                cache_write,
+                cost_usd,
+                estimated,
+            });
+        }
+    }
+
+    records.sort_by_key(|r| r.timestamp_ms);
+    records
+}
+
+fn local_day(ms: i64) -> String {
+    chrono::DateTime::from_timestamp_millis(ms)
+        .map(|t| t.with_timezone(&chrono::Local).format("%Y-%m-%d").to_string())
+        .unwrap_or_default()
+}
+
+/// 레코드 목록 집계
+pub fn summarize(records: &[UsageRecord], since_ms: Option<i64>) -> UsageReport {
+    let mut report = UsageReport { since_ms, ..Default::default() };
+    for r in records {
+        report.totals.add(r);
+        report.by_model.entry(format!("{}/{}", r.provider, r.model)).or_default().add(r);
+        report.by_channel.entry(r.channel.clone()).or_default().add(r);
+        report.by_day.entry(local_day(r.timestamp_ms)).or_default().add(r);
+        report.last_active_ms = report.last_active_ms.max(Some(r.timestamp_ms));
+    }
+    report
+}
+
+// ===== Tauri 커맨드 =====
+
+/// 사용량 보고서 (days: 최근 N일, 없으면 전체)
+#[tauri::command]
+pub fn get_usage_report(days: Option<u32>) -> UsageReport {
+    let since_ms = days.map(|d| chrono::Utc::now().timestamp_millis() - d as i64 * 24 * 60 * 60 * 1000);
+    summarize(&collect_usage(since_ms), since_ms)
+}
@@ -0,0 +1,647 @@
+// 앱 내 WhatsApp QR 로그인 (외부 터미널 없이)
+// - openclaw channels login --channel whatsapp 을 파이프로 실행
+// - 출력된 터미널 QR(반블록 문자 / ANSI 배경색)을 모듈 행렬로 복원해 SVG로 변환
+// - 진행 상황은 "whatsapp-login" 이벤트로 전달 (qr, progress, waiting, linked, error, timeout, cancelled)
+// - 세션 점검(연결 번호, 기기, 세션 파일 일시, 게이트웨이 연결 여부), 재연결, 로그아웃
+
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tauri::Emitter;
+
+use crate::openclaw::{
+    self, normalize_account_id, prepare_whatsapp_login, run_openclaw_command, whatsapp_credentials_dir,
+    whatsapp_login_args, DEFAULT_ACCOUNT_ID,
+};
+
+const WHATSAPP_LOGIN_EVENT: &str = "whatsapp-login";
+/// QR 스캔 기본 대기 시간
+const DEFAULT_LOGIN_TIMEOUT_SECS: u64 = 3 * 60;
+/// 대기 시간 상한 (너무 오래 프로세스를 붙잡지 않도록)
+const MAX_LOGIN_TIMEOUT_SECS: u64 = 15 * 60;
+/// 남은 시간 알림 간격
+const WAITING_TICK: Duration = Duration::from_secs(5);
+/// QR 코드 최소 크기 (버전 1 = 21x21 모듈)
+const MIN_QR_MODULES: usize = 21;
+
+/// 진행 중인 WhatsApp 로그인 프로세스
+static WHATSAPP_LOGIN: Mutex<Option<Child>> = Mutex::new(None);
+/// 로그인 시도 번호 (이전 시도의 스레드가 새 시도에 이벤트를 보내지 않도록)
+static WHATSAPP_LOGIN_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+fn is_current(generation: u64) -> bool {
+    WHATSAPP_LOGIN_GENERATION.load(Ordering::SeqCst) == generation
+}
+
+fn emit(app: &tauri::AppHandle, account_id: &Option<String>, status: &str, mut extra: Value) {
+    if let Some(obj) = extra.as_object_mut() {
+        obj.insert("status".to_string(), json!(status));
+        obj.insert("accountId".to_string(), json!(account_id));
+    }
+    let _ = app.emit(WHATSAPP_LOGIN_EVENT, extra);
+}
+
+/// 플랫폼별 openclaw 로그인 명령 (콘솔 창 없이, 출력은 파이프)
+/// Windows는 cmd /C로 한 줄을 만들지 않고 openclaw.cmd에 인자를 따로 넘김 (계정 ID가 그대로 전달되도록)
+fn login_command(args: &[String]) -> Command {
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+        let mut cmd = Command::new("openclaw.cmd");
+        cmd.args(args).creation_flags(CREATE_NO_WINDOW);
+        cmd
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        let mut cmd = Command::new("openclaw");
+        cmd.args(args).env("PATH", crate::openclaw::get_macos_path());
+        cmd
+    }
+
+    #[cfg(all(not(windows), not(target_os = "macos")))]
+    {
+        let mut cmd = Command::new("openclaw");
+        cmd.args(args);
+        cmd
+    }
+}
+
+// ===== 터미널 QR 파싱 =====
+
+/// ANSI 이스케이프 시퀀스 제거
+fn strip_ansi(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            for n in chars.by_ref() {
+                if n.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// 반블록 문자 QR 한 줄 → 두 행 (true = 어두운 모듈)
+/// qrcode-terminal small 모드: █ 둘 다 밝음, ▀ 위만 밝음, ▄ 아래만 밝음, 공백 둘 다 어두움
+fn half_block_rows(line: &str) -> Option<(Vec<bool>, Vec<bool>)> {
+    let line = strip_ansi(line);
+    let line = line.trim_end_matches('\r');
+    if line.chars().count() < MIN_QR_MODULES
+        || !line.chars().all(|c| matches!(c, '█' | '▀' | '▄' | ' '))
+        || !line.contains(['█', '▀', '▄'])
+    {
+        return None;
+    }
+    let (top, bottom) = line
+        .chars()
+        .map(|c| match c {
+            '█' => (false, false),
+            '▀' => (false, true),
+            '▄' => (true, false),
+            _ => (true, true),
+        })
+        .unzip();
+    Some((top, bottom))
+}
+
+/// ANSI 배경색 QR 한 줄 → 한 행 (qrcode-terminal 기본 모드: 모듈당 공백 2칸)
+fn ansi_row(line: &str) -> Option<Vec<bool>> {
+    if !line.contains("\x1b[4") {
+        return None;
+    }
+    let mut row = Vec::new();
+    let mut dark: Option<bool> = None;
+    let mut spaces = 0;
+    let mut chars = line.trim_end_matches('\r').chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\x1b' => {
+                let code: String = chars.by_ref().take_while(|n| !n.is_ascii_alphabetic()).collect();
+                dark = match code.trim_start_matches('[') {
+                    "40" | "100" => Some(true),
+                    "47" | "107" => Some(false),
+                    _ => None,
+                };
+                spaces = 0;
+            }
+            ' ' => {
+                let d = dark?;
+                spaces += 1;
+                if spaces == 2 {
+                    row.push(d);
+                    spaces = 0;
+                }
+            }
+            _ => return None,
+        }
+    }
+    (row.len() >= MIN_QR_MODULES).then_some(row)
+}
+
+/// 여러 줄에 걸친 QR 블록 누적기
+#[derive(Default)]
+struct QrCollector {
+    rows: Vec<Vec<bool>>,
+}
+
+impl QrCollector {
+    /// 한 줄 입력. QR 줄이면 true
+    fn push_line(&mut self, line: &str) -> bool {
+        if let Some((top, bottom)) = half_block_rows(line) {
+            self.rows.push(top);
+            self.rows.push(bottom);
+            true
+        } else if let Some(row) = ansi_row(line) {
+            self.rows.push(row);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// 누적된 블록이 QR 크기면 SVG로 변환해 반환
+    fn take_svg(&mut self) -> Option<String> {
+        let rows = std::mem::take(&mut self.rows);
+        if rows.len() < MIN_QR_MODULES {
+            return None;
+        }
+        Some(matrix_to_svg(rows))
+    }
+}
+
+/// 모듈 행렬 → SVG (테두리 여백은 터미널 출력에 포함되어 있음)
+fn matrix_to_svg(mut rows: Vec<Vec<bool>>) -> String {
+    // 터미널 색이 반전된 경우: 여백(첫 행)은 항상 밝아야 함
+    if rows.first().is_some_and(|r| r.iter().all(|&d| d)) {
+        for row in rows.iter_mut() {
+            for m in row.iter_mut() {
+                *m = !*m;
+            }
+        }
+    }
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let height = rows.len();
+    let mut path = String::new();
+    for (y, row) in rows.iter().enumerate() {
+        for (x, _) in row.iter().enumerate().filter(|(_, &d)| d) {
+            path.push_str(&format!("M{} {}h1v1h-1z", x, y));
+        }
+    }
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {w} {h}\" shape-rendering=\"crispEdges\"><rect width=\"{w}\" height=\"{h}\" fill=\"#fff\"/><path d=\"{p}\" fill=\"#000\"/></svg>",
+        w = width,
+        h = height,
+        p = path
+    )
+}
+
+// ===== 커맨드 =====
+
+/// WhatsApp QR 로그인 시작 (앱 내 표시용)
+/// QR이 갱신될 때마다 "whatsapp-login" 이벤트(status: "qr", svg)를 보내고,
+/// 연결 완료/실패/시간 초과/취소도 같은 이벤트로 전달
+#[tauri::command]
+pub fn start_whatsapp_qr_login(
+    app: tauri::AppHandle,
+    account_id: Option<String>,
+    timeout_secs: Option<u64>,
+) -> Result<(), String> {
+    let account_id = normalize_account_id(account_id.as_deref())?;
+    let timeout = Duration::from_secs(
+        timeout_secs
+            .unwrap_or(DEFAULT_LOGIN_TIMEOUT_SECS)
+            .clamp(30, MAX_LOGIN_TIMEOUT_SECS),
+    );
+
+    // 이전 시도의 스레드가 새 프로세스를 건드리지 않도록 번호를 먼저 올림
+    let generation = WHATSAPP_LOGIN_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    cancel_whatsapp_qr_login()?;
+    prepare_whatsapp_login(account_id.as_deref())?;
+
+    let mut child = login_command(&whatsapp_login_args(account_id.as_deref()))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("WhatsApp 로그인 실행 실패: {}", e))?;
+    let stdout = child.stdout.take().ok_or("openclaw 출력을 읽을 수 없습니다")?;
+    let stderr = child.stderr.take().ok_or("openclaw 오류 출력을 읽을 수 없습니다")?;
+
+    {
+        let mut guard = WHATSAPP_LOGIN.lock().map_err(|e| e.to_string())?;
+        if !is_current(generation) {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Err("새 WhatsApp 로그인 시도로 대체되었습니다".to_string());
+        }
+        *guard = Some(child);
+    }
+    emit(&app, &account_id, "starting", json!({ "timeoutSecs": timeout.as_secs() }));
+
+    // 마지막 오류 메시지 (실패 시 사용자에게 표시)
+    let last_error: Arc<Mutex<Option<String>>> = Arc::new(Mutex::new(None));
+
+    // stdout: QR 블록 + 진행 메시지
+    {
+        let app = app.clone();
+        let account_id = account_id.clone();
+        std::thread::spawn(move || {
+            let mut collector = QrCollector::default();
+            let mut qr_count = 0u32;
+            let mut flush = |collector: &mut QrCollector| {
+                if let Some(svg) = collector.take_svg() {
+                    qr_count += 1;
+                    if is_current(generation) {
+                        emit(&app, &account_id, "qr", json!({ "svg": svg, "refresh": qr_count }));
+                    }
+                }
+            };
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if collector.push_line(&line) {
+                    continue;
+                }
+                flush(&mut collector);
+                let message = strip_ansi(&line).trim().to_string();
+                if !message.is_empty() && is_current(generation) {
+                    emit(&app, &account_id, "progress", json!({ "message": message }));
+                }
+            }
+            flush(&mut collector);
+        });
+    }
+
+    // stderr: 진행/오류 메시지
+    {
+        let app = app.clone();
+        let account_id = account_id.clone();
+        let last_error = last_error.clone();
+        std::thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                let message = strip_ansi(&line).trim().to_string();
+                if message.is_empty() || !is_current(generation) {
+                    continue;
+                }
+                if let Ok(mut last) = last_error.lock() {
+                    *last = Some(message.clone());
+                }
+                emit(&app, &account_id, "progress", json!({ "message": message }));
+            }
+        });
+    }
+
+    // 종료 대기: 연결 완료 / 실패 / 시간 초과 / 취소
+    std::thread::spawn(move || {
+        let started = Instant::now();
+        let mut last_tick = Instant::now();
+        loop {
+            std::thread::sleep(Duration::from_millis(500));
+            let mut guard = match WHATSAPP_LOGIN.lock() {
+                Ok(g) => g,
+                Err(_) => return,
+            };
+            if !is_current(generation) {
+                // 새 로그인 시도로 대체됨
+                return;
+            }
+            let Some(child) = guard.as_mut() else {
+                emit(&app, &account_id, "cancelled", json!({}));
+                return;
+            };
+            let elapsed = started.elapsed();
+            match child.try_wait() {
+                Ok(None) if elapsed > timeout => {
+                    let _ = child.kill();
+                    let _ = child.wait();
+                    *guard = None;
+                    emit(
+                        &app,
+                        &account_id,
+                        "timeout",
+                        json!({ "message": "QR 스캔 대기 시간이 초과되었습니다. 다시 시도하세요." }),
+                    );
+                    return;
+                }
+                Ok(None) => {
+                    if last_tick.elapsed() >= WAITING_TICK {
+                        last_tick = Instant::now();
+                        emit(
+                            &app,
+                            &account_id,
+                            "waiting",
+                            json!({ "remainingSecs": timeout.saturating_sub(elapsed).as_secs() }),
+                        );
+                    }
+                }
+                Ok(Some(status)) => {
+                    *guard = None;
+                    drop(guard);
+                    // creds.json은 QR 스캔 전에도 생기므로 me 항목까지 확인
+                    let linked = read_session(account_id.as_deref()).state == "linked";
+                    if status.success() && linked {
+                        emit(&app, &account_id, "linked", json!({}));
+                    } else {
+                        let message = last_error
+                            .lock()
+                            .ok()
+                            .and_then(|m| m.clone())
+                            .unwrap_or_else(|| "WhatsApp 연결에 실패했습니다".to_string());
+                        emit(&app, &account_id, "error", json!({ "message": message }));
+                    }
+                    return;
+                }
+                Err(e) => {
+                    *guard = None;
+                    emit(&app, &account_id, "error", json!({ "message": e.to_string() }));
+                    return;
+                }
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// 진행 중인 WhatsApp QR 로그인 취소
+#[tauri::command]
+pub fn cancel_whatsapp_qr_login() -> Result<(), String> {
+    if let Some(mut child) = WHATSAPP_LOGIN.lock().map_err(|e| e.to_string())?.take() {
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+    Ok(())
+}
+
+// ===== 세션 점검 / 재연결 / 로그아웃 =====
+
+/// WhatsApp 세션 정보 (credentials/whatsapp/<accountId>/creds.json 기준)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WhatsAppSession {
+    pub account_id: String,
+    /// "linked" | "not_linked" | "logged_out"
+    pub state: &'static str,
+    /// 연결된 전화번호 (+국가번호 포함)
+    pub phone_number: Option<String>,
+    /// WhatsApp 프로필 이름
+    pub push_name: Option<String>,
+    /// 연결 기기 번호 (휴대폰의 "연결된 기기" 목록 순번)
+    pub device_id: Option<String>,
+    pub platform: Option<String>,
+    /// 세션 파일(creds.json) 생성 일시 (RFC3339)
+    /// 파일이 QR 스캔 전에 만들어지거나 복사될 수 있어 실제 연결 일시와 다를 수 있음
+    pub session_created_at: Option<String>,
+    /// 세션 파일 마지막 갱신 일시 (RFC3339)
+    pub last_active_at: Option<String>,
+    /// 게이트웨이가 명시적으로 보고한 연결 여부 (connected / probe.ok, 보고가 없으면 None)
+    pub accepted: Option<bool>,
+    /// 게이트웨이가 보고한 마지막 오류
+    pub last_error: Option<String>,
+}
+
+fn to_rfc3339(time: std::time::SystemTime) -> String {
+    chrono::DateTime::<chrono::Utc>::from(time).to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+/// creds.json의 me.id ("821012345678:5@s.whatsapp.net") → (전화번호, 기기 번호)
+fn parse_jid(jid: &str) -> (Option<String>, Option<String>) {
+    let user = jid.split('@').next().unwrap_or_default();
+    let (number, device) = match user.split_once(':') {
+        Some((n, d)) => (n, Some(d.to_string())),
+        None => (user, None),
+    };
+    let phone = (!number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+        .then(|| format!("+{}", number));
+    (phone, device)
+}
+
+/// 로컬 세션 파일 읽기 (게이트웨이 확인 없이)
+fn read_session(account_id: Option<&str>) -> WhatsAppSession {
+    let mut session = WhatsAppSession {
+        account_id: account_id.unwrap_or(DEFAULT_ACCOUNT_ID).to_string(),
+        state: "not_linked",
+        phone_number: None,
+        push_name: None,
+        device_id: None,
+        platform: None,
+        session_created_at: None,
+        last_active_at: None,
+        accepted: None,
+        last_error: None,
+    };
+    let Some(creds_path) = whatsapp_credentials_dir(account_id).map(|d| d.join("creds.json")) else {
+        return session;
+    };
+    let Some(creds) = fs::read_to_string(&creds_path)
+        .ok()
+        .and_then(|c| serde_json::from_str::<Value>(&c).ok())
+    else {
+        return session;
+    };
+
+    // QR 스캔 전에도 creds.json이 생성되므로 me가 있어야 연결된 것으로 판단
+    let Some(me) = creds.get("me").filter(|me| !me.is_null()) else {
+        return session;
+    };
+    session.state = "linked";
+    if let Some(jid) = me.get("id").and_then(|v| v.as_str()) {
+        (session.phone_number, session.device_id) = parse_jid(jid);
+    }
+    session.push_name = me
+        .get("name")
+        .or_else(|| me.get("notify"))
+        .and_then(|v| v.as_str())
+        .map(String::from);
+    session.platform = creds.get("platform").and_then(|v| v.as_str()).map(String::from);
+    if let Ok(meta) = fs::metadata(&creds_path) {
+        session.session_created_at = meta.created().ok().map(to_rfc3339);
+        session.last_active_at = meta.modified().ok().map(to_rfc3339);
+    }
+    session
+}
+
+/// `openclaw channels status --probe --json` 결과에서 WhatsApp 계정 항목 찾기
+fn find_probe_entry<'a>(status: &'a Value, account_id: &str) -> Option<&'a Value> {
+    let whatsapp = status
+        .pointer("/channels/whatsapp")
+        .or_else(|| status.get("whatsapp"))?;
+    let matches = |v: &&Value| {
+        v.get("accountId")
+            .or_else(|| v.get("id"))
+            .and_then(|id| id.as_str())
+            .unwrap_or(DEFAULT_ACCOUNT_ID)
+            == account_id
+    };
+    match whatsapp.get("accounts") {
+        Some(Value::Array(list)) => list.iter().find(matches),
+        Some(Value::Object(map)) => map.get(account_id),
+        _ if account_id == DEFAULT_ACCOUNT_ID => Some(whatsapp),
+        _ => None,
+    }
+}
+
+/// 게이트웨이 확인 결과 반영 (원격 로그아웃 감지)
+/// 게이트웨이가 명시적으로 보고한 값만 사용: 프로세스 실행 여부(running)나
+/// 오류 메시지 속 상태 코드로 자격 증명 유효 여부를 추정하지 않음
+fn apply_probe(session: &mut WhatsAppSession, entry: &Value) {
+    let error = ["lastError", "error", "probe/error"]
+        .iter()
+        .find_map(|k| entry.pointer(&format!("/{}", k)).and_then(|v| v.as_str()))
+        .filter(|e| !e.is_empty())
+        .map(String::from);
+    let connected = ["connected", "probe/ok"]
+        .iter()
+        .find_map(|k| entry.pointer(&format!("/{}", k)).and_then(|v| v.as_bool()));
+    let logged_out = error.as_deref().is_some_and(|e| {
+        let e = e.to_lowercase();
+        e.contains("logged out") || e.contains("loggedout")
+    }) || entry.get("linked").and_then(|v| v.as_bool()) == Some(false);
+
+    if logged_out && session.state == "linked" {
+        session.state = "logged_out";
+        session.accepted = Some(false);
+    } else if session.state == "linked" {
+        session.accepted = connected;
+    }
+    session.last_error = error;
+}
+
+/// WhatsApp 세션 점검
+/// probe=true면 게이트웨이에 실제 연결 상태를 확인 (원격 로그아웃 감지)
+#[tauri::command]
+pub fn get_whatsapp_session(account_id: Option<String>, probe: Option<bool>) -> Result<WhatsAppSession, String> {
+    let account_id = normalize_account_id(account_id.as_deref())?;
+    let mut session = read_session(account_id.as_deref());
+    if probe.unwrap_or(false) && session.state == "linked" {
+        match run_openclaw_command(&["channels", "status", "--probe", "--json"]) {
+            Ok(output) => {
+                let status: Value = serde_json::from_str(&output)
+                    .map_err(|e| format!("채널 상태 파싱 실패: {}", e))?;
+                if let Some(entry) = find_probe_entry(&status, &session.account_id) {
+                    apply_probe(&mut session, entry);
+                }
+            }
+            // 게이트웨이가 꺼져 있으면 유효 여부는 알 수 없음
+            Err(e) => session.last_error = Some(e),
+        }
+    }
+    Ok(session)
+}
+
+/// 로컬에 세션이 있는 모든 WhatsApp 계정 (게이트웨이 확인 없음)
+#[tauri::command]
+pub fn list_whatsapp_sessions() -> Vec<WhatsAppSession> {
+    let Some(root) = whatsapp_credentials_dir(None).and_then(|d| d.parent().map(|p| p.to_path_buf())) else {
+        return Vec::new();
+    };
+    let mut sessions: Vec<WhatsAppSession> = fs::read_dir(&root)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|e| e.path().join("creds.json").is_file())
+                .filter_map(|e| e.file_name().to_str().map(String::from))
+                .filter_map(|name| normalize_account_id(Some(&name)).ok())
+                .map(|id| read_session(id.as_deref()))
+                .collect()
+        })
+        .unwrap_or_default();
+    sessions.sort_by(|a, b| a.account_id.cmp(&b.account_id));
+    sessions
+}
+
+/// WhatsApp 로그아웃 (게이트웨이 알림 → 세션 삭제)
+/// 반환: { loggedOut, gatewayNotified, warning }
+#[tauri::command]
+pub fn logout_whatsapp(account_id: Option<String>) -> Result<Value, String> {
+    let account_id = normalize_account_id(account_id.as_deref())?;
+    cancel_whatsapp_qr_login()?;
+    let warning = openclaw::logout_whatsapp(account_id.as_deref())?;
+    Ok(json!({
+        "loggedOut": true,
+        "gatewayNotified": warning.is_none(),
+        "warning": warning,
+    }))
+}
+
+/// WhatsApp 재연결: 기존 세션(원격 로그아웃 등)을 정리하고 새 QR 로그인 시작
+/// QR과 진행 상황은 start_whatsapp_qr_login과 같은 "whatsapp-login" 이벤트로 전달
+#[tauri::command]
+pub fn relink_whatsapp(
+    app: tauri::AppHandle,
+    account_id: Option<String>,
+    timeout_secs: Option<u64>,
+) -> Result<(), String> {
+    let normalized = normalize_account_id(account_id.as_deref())?;
+    cancel_whatsapp_qr_login()?;
+    // 남은 세션이 있으면 QR이 뜨지 않고 기존 자격 증명으로 재시도함
+    if let Some(warning) = openclaw::logout_whatsapp(normalized.as_deref())? {
+        emit(&app, &normalized, "progress", json!({ "message": warning }));
+    }
+    start_whatsapp_qr_login(app, account_id, timeout_secs)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn half_block_rows_splits_each_cell() {
+        let line = format!("█▀▄ {}", "█".repeat(MIN_QR_MODULES - 4));
+        let (top, bottom) = half_block_rows(&line).unwrap();
+        assert_eq!(top.len(), MIN_QR_MODULES);
+        assert_eq!(&top[..4], &[false, false, true, true]);
+        assert_eq!(&bottom[..4], &[false, true, false, true]);
+
+        assert_eq!(half_block_rows("█▀▄"), None);
+        assert_eq!(half_block_rows(&" ".repeat(MIN_QR_MODULES)), None);
+        assert_eq!(half_block_rows(&format!("QR {}", "█".repeat(MIN_QR_MODULES))), None);
+    }
+
+    #[test]
+    fn ansi_row_reads_two_spaces_per_module() {
+        let mut line = String::new();
+        for i in 0..MIN_QR_MODULES {
+            line.push_str(if i % 2 == 0 { "\x1b[40m  " } else { "\x1b[47m  " });
+        }
+        line.push_str("\x1b[0m");
+        let row = ansi_row(&line).unwrap();
+        assert_eq!(row.len(), MIN_QR_MODULES);
+        assert!(row[0] && !row[1] && row[2]);
+
+        assert_eq!(ansi_row("\x1b[40m  \x1b[47m  "), None);
+        assert_eq!(ansi_row("plain text"), None);
+    }
+
+    #[test]
+    fn parse_jid_extracts_phone_and_device() {
+        assert_eq!(
+            parse_jid("821012345678:5@s.whatsapp.net"),
+            (Some("+821012345678".to_string()), Some("5".to_string()))
+        );
+        assert_eq!(parse_jid("821012345678@s.whatsapp.net"), (Some("+821012345678".to_string()), None));
+        assert_eq!(parse_jid("abc@lid"), (None, None));
+    }
+
+    #[test]
+    fn login_command_passes_arguments_separately() {
+        let args: Vec<String> = ["channels", "login", "--account", "my work"].iter().map(|s| s.to_string()).collect();
+        let cmd = login_command(&args);
+        let program = if cfg!(windows) { "openclaw.cmd" } else { "openclaw" };
+        assert_eq!(cmd.get_program(), program);
+        let passed: Vec<_> = cmd.get_args().collect();
+        assert_eq!(passed, ["channels", "login", "--account", "my work"]);
+    }
+}
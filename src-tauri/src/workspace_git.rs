@@ -0,0 +1,589 @@
+// 워크스페이스 버전 관리 (git2 기반, 로컬 전용)
+// ~/.openclaw/workspace 변경 이력을 moldClaw 상태 디렉토리의 bare 저장소에 기록합니다.
+// 워크스페이스 안에는 .git 폴더를 만들지 않으며, 시스템 git 설치가 필요 없습니다.
+
+use git2::{
+    build::CheckoutBuilder, DiffFormat, DiffOptions, IndexAddOption, Oid, Repository, Signature,
+    Sort, StatusOptions,
+};
+use serde::{Deserialize, Serialize};
+use std::path::{Component, Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::openclaw::{get_moldclaw_state_dir, get_openclaw_dir, get_workspace_dir};
+
+/// 자동 커밋 주기
+const AUTO_COMMIT_INTERVAL: Duration = Duration::from_secs(30);
+/// 세션 파일이 이 시간 안에 변경되었으면 에이전트 작업 중으로 간주
+const SESSION_QUIET_MS: i64 = 30_000;
+
+const AGENT_NAME: &str = "OpenClaw Agent";
+const AGENT_EMAIL: &str = "agent@openclaw.local";
+const USER_NAME: &str = "moldClaw User";
+const USER_EMAIL: &str = "user@moldclaw.local";
+
+/// 자동 커밋 작성자가 세션 활동으로 추정한 값임을 표시하는 트레일러
+const INFERRED_TRAILER: &str = "Attribution: inferred";
+
+/// 버전 관리에서 제외할 경로 (info/exclude)
+const DEFAULT_EXCLUDES: &str = ".git/\nnode_modules/\n.DS_Store\nThumbs.db\n*.tmp\n*.swp\n";
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VersioningState {
+    enabled: bool,
+    /// 마지막 스캔 시점에 본 세션 파일 최신 mtime (ms)
+    #[serde(default)]
+    last_session_mtime_ms: i64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VersioningStatus {
+    pub enabled: bool,
+    pub repo_path: String,
+    pub workspace_path: String,
+    pub commit_count: usize,
+    pub pending_changes: usize,
+    pub last_commit: Option<WorkspaceCommit>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceCommit {
+    pub id: String,
+    pub short_id: String,
+    pub message: String,
+    pub author: String,
+    /// true면 작성자(에이전트/사용자)는 세션 파일 활동 시점으로 추정한 값
+    /// (에이전트 세션 중 사용자가 직접 편집한 파일도 에이전트 변경으로 기록될 수 있음)
+    pub author_inferred: bool,
+    pub timestamp: i64,
+    pub files: Vec<String>,
+}
+
+fn repo_dir() -> PathBuf {
+    get_moldclaw_state_dir().join("workspace.git")
+}
+
+fn state_path() -> PathBuf {
+    get_moldclaw_state_dir().join("workspace-versioning.json")
+}
+
+fn load_state() -> VersioningState {
+    std::fs::read_to_string(state_path())
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default()
+}
+
+fn save_state(state: &VersioningState) -> Result<(), String> {
+    std::fs::create_dir_all(get_moldclaw_state_dir())
+        .map_err(|e| format!("상태 디렉토리 생성 실패: {}", e))?;
+    let json = serde_json::to_string_pretty(state)
+        .map_err(|e| format!("상태 직렬화 실패: {}", e))?;
+    std::fs::write(state_path(), json).map_err(|e| format!("상태 저장 실패: {}", e))
+}
+
+/// 버전 관리 활성화 여부
+pub fn is_enabled() -> bool {
+    load_state().enabled && repo_dir().exists()
+}
+
+fn require_enabled() -> Result<(), String> {
+    if is_enabled() {
+        Ok(())
+    } else {
+        Err("워크스페이스 버전 관리가 비활성화되어 있습니다".to_string())
+    }
+}
+
+/// bare 저장소를 열고 워크트리를 워크스페이스로 지정 (저장소는 만들지 않음)
+fn open_repo() -> Result<Repository, String> {
+    let dir = repo_dir();
+    if !dir.exists() {
+        return Err("워크스페이스 버전 관리 저장소가 없습니다".to_string());
+    }
+    let repo = Repository::open_bare(&dir).map_err(|e| format!("저장소 열기 실패: {}", e))?;
+    attach_workdir(repo)
+}
+
+/// 저장소 생성 (활성화할 때만 호출)
+fn init_repo() -> Result<Repository, String> {
+    let dir = repo_dir();
+    if dir.exists() {
+        return open_repo();
+    }
+    std::fs::create_dir_all(&dir).map_err(|e| format!("저장소 디렉토리 생성 실패: {}", e))?;
+    let repo = Repository::init_bare(&dir).map_err(|e| format!("저장소 초기화 실패: {}", e))?;
+    let info = dir.join("info");
+    let _ = std::fs::create_dir_all(&info);
+    std::fs::write(info.join("exclude"), DEFAULT_EXCLUDES)
+        .map_err(|e| format!("exclude 파일 생성 실패: {}", e))?;
+    attach_workdir(repo)
+}
+
+fn attach_workdir(repo: Repository) -> Result<Repository, String> {
+    let workspace = get_workspace_dir();
+    std::fs::create_dir_all(&workspace)
+        .map_err(|e| format!("워크스페이스 디렉토리 생성 실패: {}", e))?;
+    // update_gitlink=false: 워크스페이스에 .git 파일을 만들지 않음
+    repo.set_workdir(&workspace, false)
+        .map_err(|e| format!("워크트리 설정 실패: {}", e))?;
+    Ok(repo)
+}
+
+/// 커밋되지 않은 변경 파일 목록
+fn pending_files(repo: &Repository) -> Result<Vec<String>, String> {
+    let mut opts = StatusOptions::new();
+    opts.include_untracked(true)
+        .recurse_untracked_dirs(true)
+        .include_ignored(false);
+    let statuses = repo
+        .statuses(Some(&mut opts))
+        .map_err(|e| format!("변경 사항 조회 실패: {}", e))?;
+    Ok(statuses
+        .iter()
+        .filter_map(|s| s.path().map(|p| p.to_string()))
+        .collect())
+}
+
+/// 변경 사항 전체를 스테이징 후 커밋. 변경이 없으면 None
+/// inferred=true면 작성자를 추정했다는 트레일러를 메시지 끝에 추가
+fn commit_all(
+    repo: &Repository,
+    author: (&str, &str),
+    summary: &str,
+    inferred: bool,
+) -> Result<Option<Oid>, String> {
+    let files = pending_files(repo)?;
+    if files.is_empty() {
+        return Ok(None);
+    }
+
+    let mut index = repo.index().map_err(|e| format!("인덱스 열기 실패: {}", e))?;
+    index
+        .add_all(["*"].iter(), IndexAddOption::DEFAULT, None)
+        .map_err(|e| format!("스테이징 실패: {}", e))?;
+    // 삭제된 파일 반영
+    index
+        .update_all(["*"].iter(), None)
+        .map_err(|e| format!("스테이징 실패: {}", e))?;
+    index.write().map_err(|e| format!("인덱스 저장 실패: {}", e))?;
+    let tree_id = index.write_tree().map_err(|e| format!("트리 생성 실패: {}", e))?;
+    let tree = repo.find_tree(tree_id).map_err(|e| format!("트리 조회 실패: {}", e))?;
+
+    let parent = repo.head().ok().and_then(|h| h.peel_to_commit().ok());
+    if let Some(ref p) = parent {
+        if p.tree_id() == tree_id {
+            return Ok(None);
+        }
+    }
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+
+    let sig = Signature::now(author.0, author.1).map_err(|e| format!("서명 생성 실패: {}", e))?;
+    let mut message = format!("{}\n\n", summary);
+    for f in &files {
+        message.push_str(&format!("- {}\n", f));
+    }
+    if inferred {
+        message.push_str(&format!("\n{}\n", INFERRED_TRAILER));
+    }
+
+    let oid = repo
+        .commit(Some("HEAD"), &sig, &sig, &message, &tree, &parents)
+        .map_err(|e| format!("커밋 실패: {}", e))?;
+    Ok(Some(oid))
+}
+
+/// 에이전트 세션 파일 중 가장 최근 mtime (ms)
+fn latest_session_mtime_ms() -> i64 {
+    let agents_dir = get_openclaw_dir().join("agents");
+    let mut latest = 0i64;
+    let Ok(agents) = std::fs::read_dir(&agents_dir) else {
+        return 0;
+    };
+    for agent in agents.flatten() {
+        let Ok(files) = std::fs::read_dir(agent.path().join("sessions")) else {
+            continue;
+        };
+        for f in files.flatten() {
+            if let Some(ms) = f
+                .metadata()
+                .ok()
+                .and_then(|m| m.modified().ok())
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map(|d| d.as_millis() as i64)
+            {
+                latest = latest.max(ms);
+            }
+        }
+    }
+    latest
+}
+
+fn now_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
+
+/// 커밋 작성자, 요약, 추정 여부
+/// 사용자가 직접 요청한 커밋만 확정, 세션 활동 여부로 나눈 자동 커밋은 추정
+fn commit_attribution(force_user: bool, agent_activity: bool) -> ((&'static str, &'static str), &'static str, bool) {
+    if force_user {
+        ((USER_NAME, USER_EMAIL), "사용자 편집", false)
+    } else if agent_activity {
+        ((AGENT_NAME, AGENT_EMAIL), "에이전트 세션 변경 사항 (추정)", true)
+    } else {
+        ((USER_NAME, USER_EMAIL), "사용자 편집 (추정)", true)
+    }
+}
+
+/// 자동 커밋 1회 실행
+/// - 에이전트 세션이 진행 중이면 건너뜀
+/// - 마지막 스캔 이후 세션 활동이 있었으면 에이전트 변경, 아니면 사용자 변경으로 기록
+///   (파일 단위로 구분하지 않는 추정이므로 커밋에 INFERRED_TRAILER를 남김)
+fn auto_commit_once(force_user: bool) -> Result<Option<Oid>, String> {
+    let mut state = load_state();
+    if !state.enabled || !repo_dir().exists() {
+        return Ok(None);
+    }
+
+    let session_mtime = latest_session_mtime_ms();
+    if !force_user && session_mtime > 0 && now_ms() - session_mtime < SESSION_QUIET_MS {
+        return Ok(None);
+    }
+
+    let agent_activity = !force_user && session_mtime > state.last_session_mtime_ms;
+    let repo = open_repo()?;
+    let (author, summary, inferred) = commit_attribution(force_user, agent_activity);
+    let result = commit_all(&repo, author, summary, inferred)?;
+
+    if session_mtime != state.last_session_mtime_ms {
+        state.last_session_mtime_ms = session_mtime;
+        save_state(&state)?;
+    }
+    Ok(result)
+}
+
+/// 백그라운드 자동 커밋 루프 시작 (앱 setup에서 호출)
+pub fn start_auto_commit_loop() {
+    std::thread::spawn(|| loop {
+        std::thread::sleep(AUTO_COMMIT_INTERVAL);
+        if let Err(e) = auto_commit_once(false) {
+            eprintln!("워크스페이스 자동 커밋 실패: {}", e);
+        }
+    });
+}
+
+/// 워크스페이스 파일을 편집기로 열기 전 현재 상태 스냅샷
+/// 이후 변경은 다음 자동 커밋에서 사용자 편집으로 기록됨
+pub fn snapshot_before_edit(path: &str) {
+    if !is_enabled() || !Path::new(path).starts_with(get_workspace_dir()) {
+        return;
+    }
+    if let Err(e) = auto_commit_once(false) {
+        eprintln!("편집 전 스냅샷 실패: {}", e);
+    }
+}
+
+/// 워크스페이스 상대 경로 검증 (.. 및 절대 경로 거부)
+fn validate_relative_path(path: &str) -> Result<PathBuf, String> {
+    let p = PathBuf::from(path.replace('\\', "/"));
+    if p.as_os_str().is_empty()
+        || p.components().any(|c| !matches!(c, Component::Normal(_)))
+    {
+        return Err(format!("잘못된 파일 경로입니다: {}", path));
+    }
+    Ok(p)
+}
+
+fn to_commit_info(repo: &Repository, commit: &git2::Commit) -> WorkspaceCommit {
+    let id = commit.id().to_string();
+    let files = changed_files(repo, commit).unwrap_or_default();
+    WorkspaceCommit {
+        short_id: id.chars().take(8).collect(),
+        id,
+        message: commit.summary().unwrap_or("").to_string(),
+        author: commit.author().name().unwrap_or("").to_string(),
+        author_inferred: commit
+            .message()
+            .is_some_and(|m| m.lines().any(|l| l.trim() == INFERRED_TRAILER)),
+        timestamp: commit.time().seconds() * 1000,
+        files,
+    }
+}
+
+/// 커밋에서 변경된 파일 목록 (부모 대비)
+fn changed_files(repo: &Repository, commit: &git2::Commit) -> Result<Vec<String>, git2::Error> {
+    let tree = commit.tree()?;
+    let parent_tree = commit.parent(0).ok().and_then(|p| p.tree().ok());
+    let diff = repo.diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)?;
+    Ok(diff
+        .deltas()
+        .filter_map(|d| {
+            d.new_file()
+                .path()
+                .or_else(|| d.old_file().path())
+                .map(|p| p.to_string_lossy().to_string())
+        })
+        .collect())
+}
+
+fn find_commit<'r>(repo: &'r Repository, commit_id: &str) -> Result<git2::Commit<'r>, String> {
+    repo.revparse_single(commit_id)
+        .and_then(|o| o.peel_to_commit())
+        .map_err(|e| format!("커밋을 찾을 수 없습니다 ({}): {}", commit_id, e))
+}
+
+// ===== Tauri 커맨드 =====
+
+/// 버전 관리 상태 조회
+#[tauri::command]
+pub fn get_workspace_versioning_status() -> Result<VersioningStatus, String> {
+    let enabled = is_enabled();
+    let mut status = VersioningStatus {
+        enabled,
+        repo_path: repo_dir().to_string_lossy().to_string(),
+        workspace_path: get_workspace_dir().to_string_lossy().to_string(),
+        commit_count: 0,
+        pending_changes: 0,
+        last_commit: None,
+    };
+    if !enabled {
+        return Ok(status);
+    }
+
+    let repo = open_repo()?;
+    status.pending_changes = pending_files(&repo)?.len();
+    if let Ok(head) = repo.head().and_then(|h| h.peel_to_commit()) {
+        status.last_commit = Some(to_commit_info(&repo, &head));
+        if let Ok(mut walk) = repo.revwalk() {
+            if walk.push_head().is_ok() {
+                status.commit_count = walk.count();
+            }
+        }
+    }
+    Ok(status)
+}
+
+/// 버전 관리 활성화 (저장소 생성 + 초기 커밋)
+#[tauri::command]
+pub fn enable_workspace_versioning() -> Result<VersioningStatus, String> {
+    let repo = init_repo()?;
+    commit_all(&repo, (USER_NAME, USER_EMAIL), "워크스페이스 버전 관리 시작", false)?;
+    save_state(&VersioningState {
+        enabled: true,
+        last_session_mtime_ms: latest_session_mtime_ms(),
+    })?;
+    get_workspace_versioning_status()
+}
+
+/// 버전 관리 비활성화 (delete_history=true면 저장소 삭제)
+#[tauri::command]
+pub fn disable_workspace_versioning(delete_history: Option<bool>) -> Result<(), String> {
+    let mut state = load_state();
+    state.enabled = false;
+    save_state(&state)?;
+    if delete_history.unwrap_or(false) && repo_dir().exists() {
+        std::fs::remove_dir_all(repo_dir()).map_err(|e| format!("이력 삭제 실패: {}", e))?;
+    }
+    Ok(())
+}
+
+/// 현재 변경 사항 수동 커밋
+#[tauri::command]
+pub fn commit_workspace_changes(message: Option<String>) -> Result<Option<WorkspaceCommit>, String> {
+    require_enabled()?;
+    let repo = open_repo()?;
+    let summary = message
+        .filter(|m| !m.trim().is_empty())
+        .unwrap_or_else(|| "사용자 편집".to_string());
+    let Some(oid) = commit_all(&repo, (USER_NAME, USER_EMAIL), &summary, false)? else {
+        return Ok(None);
+    };
+    let commit = repo.find_commit(oid).map_err(|e| format!("커밋 조회 실패: {}", e))?;
+    Ok(Some(to_commit_info(&repo, &commit)))
+}
+
+/// 변경 이력 조회 (path 지정 시 해당 파일을 변경한 커밋만)
+#[tauri::command]
+pub fn get_workspace_history(
+    path: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<WorkspaceCommit>, String> {
+    if !is_enabled() {
+        return Ok(vec![]);
+    }
+    let repo = open_repo()?;
+    if repo.head().is_err() {
+        return Ok(vec![]);
+    }
+    let filter = path.as_deref().map(validate_relative_path).transpose()?;
+    let limit = limit.unwrap_or(100);
+
+    let mut walk = repo.revwalk().map_err(|e| format!("이력 조회 실패: {}", e))?;
+    walk.set_sorting(Sort::TIME).map_err(|e| format!("이력 조회 실패: {}", e))?;
+    walk.push_head().map_err(|e| format!("이력 조회 실패: {}", e))?;
+
+    let mut result = Vec::new();
+    for oid in walk.flatten() {
+        if result.len() >= limit {
+            break;
+        }
+        let Ok(commit) = repo.find_commit(oid) else { continue };
+
+        if let Some(ref file) = filter {
+            let current = commit.tree().ok().and_then(|t| t.get_path(file).ok()).map(|e| e.id());
+            let previous = commit
+                .parent(0)
+                .ok()
+                .and_then(|p| p.tree().ok())
+                .and_then(|t| t.get_path(file).ok())
+                .map(|e| e.id());
+            if current == previous {
+                continue;
+            }
+        }
+        result.push(to_commit_info(&repo, &commit));
+    }
+    Ok(result)
+}
+
+/// 커밋의 변경 내용 (unified diff). path 지정 시 해당 파일만
+#[tauri::command]
+pub fn get_workspace_diff(commit_id: String, path: Option<String>) -> Result<String, String> {
+    require_enabled()?;
+    let repo = open_repo()?;
+    let commit = find_commit(&repo, &commit_id)?;
+    let tree = commit.tree().map_err(|e| format!("트리 조회 실패: {}", e))?;
+    let parent_tree = commit.parent(0).ok().and_then(|p| p.tree().ok());
+
+    let mut opts = DiffOptions::new();
+    if let Some(ref p) = path {
+        opts.pathspec(validate_relative_path(p)?);
+    }
+    let diff = repo
+        .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), Some(&mut opts))
+        .map_err(|e| format!("diff 생성 실패: {}", e))?;
+
+    let mut out = String::new();
+    diff.print(DiffFormat::Patch, |_delta, _hunk, line| {
+        if matches!(line.origin(), '+' | '-' | ' ') {
+            out.push(line.origin());
+        }
+        out.push_str(&String::from_utf8_lossy(line.content()));
+        true
+    })
+    .map_err(|e| format!("diff 출력 실패: {}", e))?;
+    Ok(out)
+}
+
+/// 특정 커밋 시점의 파일 하나 복원 (복원 결과도 새 커밋으로 기록)
+#[tauri::command]
+pub fn restore_workspace_file(commit_id: String, path: String) -> Result<(), String> {
+    require_enabled()?;
+    let relative = validate_relative_path(&path)?;
+    let repo = open_repo()?;
+    // 복원 전 현재 상태 보존
+    commit_all(&repo, (USER_NAME, USER_EMAIL), "복원 전 스냅샷", false)?;
+
+    let commit = find_commit(&repo, &commit_id)?;
+    let tree = commit.tree().map_err(|e| format!("트리 조회 실패: {}", e))?;
+    let target = get_workspace_dir().join(&relative);
+
+    match tree.get_path(&relative) {
+        Ok(entry) => {
+            let blob = repo
+                .find_blob(entry.id())
+                .map_err(|e| format!("파일 내용 조회 실패: {}", e))?;
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent).map_err(|e| format!("디렉토리 생성 실패: {}", e))?;
+            }
+            std::fs::write(&target, blob.content()).map_err(|e| format!("파일 복원 실패: {}", e))?;
+        }
+        // 해당 시점에 없던 파일이면 삭제
+        Err(_) => {
+            if target.exists() {
+                std::fs::remove_file(&target).map_err(|e| format!("파일 삭제 실패: {}", e))?;
+            }
+        }
+    }
+
+    let short: String = commit.id().to_string().chars().take(8).collect();
+    commit_all(&repo, (USER_NAME, USER_EMAIL), &format!("{} 복원 ({})", path, short), false)?;
+    Ok(())
+}
+
+/// 워크스페이스 전체를 특정 커밋 시점으로 복원 (이력은 유지, 새 커밋 생성)
+#[tauri::command]
+pub fn restore_workspace_version(commit_id: String) -> Result<(), String> {
+    require_enabled()?;
+    let repo = open_repo()?;
+    commit_all(&repo, (USER_NAME, USER_EMAIL), "복원 전 스냅샷", false)?;
+
+    let commit = find_commit(&repo, &commit_id)?;
+    let mut checkout = CheckoutBuilder::new();
+    checkout.force().remove_untracked(true);
+    repo.checkout_tree(commit.as_object(), Some(&mut checkout))
+        .map_err(|e| format!("워크스페이스 복원 실패: {}", e))?;
+
+    let short: String = commit.id().to_string().chars().take(8).collect();
+    commit_all(&repo, (USER_NAME, USER_EMAIL), &format!("워크스페이스 복원 ({})", short), false)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn attribution_marks_automatic_commits_inferred() {
+        assert_eq!(commit_attribution(true, false), ((USER_NAME, USER_EMAIL), "사용자 편집", false));
+        let (author, _, inferred) = commit_attribution(false, true);
+        assert_eq!(author, (AGENT_NAME, AGENT_EMAIL));
+        assert!(inferred);
+        let (author, _, inferred) = commit_attribution(false, false);
+        assert_eq!(author, (USER_NAME, USER_EMAIL));
+        assert!(inferred);
+    }
+
+    #[test]
+    fn relative_paths_stay_inside_workspace() {
+        assert_eq!(validate_relative_path("notes/today.md").unwrap(), PathBuf::from("notes/today.md"));
+        assert_eq!(validate_relative_path("notes\\today.md").unwrap(), PathBuf::from("notes/today.md"));
+        assert!(validate_relative_path("").is_err());
+        assert!(validate_relative_path("../secrets").is_err());
+        assert!(validate_relative_path("notes/../../x").is_err());
+        assert!(validate_relative_path("/etc/passwd").is_err());
+        assert!(validate_relative_path("./AGENTS.md").is_err());
+    }
+
+    #[test]
+    fn commit_all_records_files_and_inferred_trailer() {
+        let git_dir = tempfile::tempdir().unwrap();
+        let workspace = tempfile::tempdir().unwrap();
+        let repo = Repository::init_bare(git_dir.path()).unwrap();
+        repo.set_workdir(workspace.path(), false).unwrap();
+
+        assert_eq!(commit_all(&repo, (USER_NAME, USER_EMAIL), "비어 있음", false).unwrap(), None);
+
+        std::fs::write(workspace.path().join("AGENTS.md"), "# agents\n").unwrap();
+        let oid = commit_all(&repo, (AGENT_NAME, AGENT_EMAIL), "에이전트", true).unwrap().unwrap();
+        let info = to_commit_info(&repo, &repo.find_commit(oid).unwrap());
+        assert_eq!(info.author, AGENT_NAME);
+        assert!(info.author_inferred);
+        assert_eq!(info.files, vec!["AGENTS.md".to_string()]);
+
+        std::fs::remove_file(workspace.path().join("AGENTS.md")).unwrap();
+        let oid = commit_all(&repo, (USER_NAME, USER_EMAIL), "사용자 편집", false).unwrap().unwrap();
+        let info = to_commit_info(&repo, &repo.find_commit(oid).unwrap());
+        assert!(!info.author_inferred);
+        assert_eq!(info.files, vec!["AGENTS.md".to_string()]);
+
+        // 변경이 없으면 커밋하지 않음
+        assert_eq!(commit_all(&repo, (USER_NAME, USER_EMAIL), "다시", false).unwrap(), None);
+    }
+}